@@ -1,5 +1,8 @@
 //! About modal
-use crate::prelude::{use_backdrop, use_random_id, Button, ButtonVariant, Icon};
+use crate::prelude::{
+    use_backdrop, use_backdrop_topmost, use_focus_trap, use_random_id, Button, ButtonVariant, Icon,
+    InitialFocus,
+};
 use yew::prelude::*;
 use yew_hooks::{use_click_away, use_event_with_window};
 
@@ -34,6 +37,12 @@ pub struct AboutModalProperties {
     /// Disable closing the modal when the user clicks outside the modal
     #[prop_or_default]
     pub disable_close_click_outside: bool,
+    /// Disable trapping the focus inside the modal
+    #[prop_or_default]
+    pub disable_focus_trap: bool,
+    /// The element to focus when the modal opens
+    #[prop_or_default]
+    pub initial_focus: InitialFocus,
     /// Id of the outermost element
     #[prop_or_default]
     pub id: Option<AttrValue>,
@@ -57,6 +66,11 @@ pub struct AboutModalProperties {
 /// `onclose` callback is set, then it will automatically close the backdrop when the modal dialog
 /// gets closed.
 ///
+/// ## Focus
+///
+/// While open, the keyboard focus is trapped inside the modal. When closed, the focus returns to
+/// the element which was focused before. See [`crate::prelude::FocusTrap`].
+///
 #[function_component(AboutModal)]
 pub fn about_modal(props: &AboutModalProperties) -> Html {
     let backdrop = use_backdrop();
@@ -76,9 +90,12 @@ pub fn about_modal(props: &AboutModalProperties) -> Html {
         },
     );
 
+    // only the topmost modal handles closing
+    let topmost = use_backdrop_topmost();
+
     // escape key
     {
-        let disabled = props.disable_close_escape;
+        let disabled = props.disable_close_escape || !topmost;
         let onclose = onclose.clone();
         use_event_with_window("keydown", move |e: KeyboardEvent| {
             if !disabled && e.key() == "Escape" {
@@ -91,7 +108,7 @@ pub fn about_modal(props: &AboutModalProperties) -> Html {
     let node_ref = use_node_ref();
 
     {
-        let disabled = props.disable_close_click_outside;
+        let disabled = props.disable_close_click_outside || !topmost;
         let onclose = onclose.clone();
         use_click_away(node_ref.clone(), move |_: Event| {
             if !disabled {
@@ -100,6 +117,12 @@ pub fn about_modal(props: &AboutModalProperties) -> Html {
         });
     }
 
+    use_focus_trap(
+        node_ref.clone(),
+        !props.disable_focus_trap,
+        props.initial_focus.clone(),
+    );
+
     let style = if props.background_image_src.is_empty() {
         None
     } else {
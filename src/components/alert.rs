@@ -178,6 +178,13 @@ pub struct GroupProperties {
     pub children: ChildrenWithProps<Alert>,
     #[prop_or_default]
     pub toast: bool,
+
+    /// A message, shown after the alerts, indicating that more alerts are available.
+    #[prop_or_default]
+    pub overflow_message: Option<String>,
+    /// Called when the user clicks on the overflow message.
+    #[prop_or_default]
+    pub onoverflow: Callback<()>,
 }
 
 #[function_component(AlertGroup)]
@@ -193,6 +200,16 @@ pub fn view(props: &GroupProperties) -> Html {
             { for props.children.iter().map(|child|
                 wrapper_elt_with_attributes(child.to_html(), "li", &[("class", "pf-v5-c-alert-group__item", ApplyAttributeAs::Attribute)])
             )}
+            if let Some(overflow_message) = &props.overflow_message {
+                <li class="pf-v5-c-alert-group__item">
+                    <button
+                        class="pf-v5-c-alert-group__overflow-button"
+                        onclick={props.onoverflow.reform(|_|())}
+                    >
+                        { overflow_message }
+                    </button>
+                </li>
+            }
         </ul>
     )
 }
@@ -0,0 +1,90 @@
+//! Application launcher
+use crate::prelude::{Dropdown, Icon, MenuChildVariant, MenuToggleVariant, Position};
+use yew::{html::ChildrenRenderer, prelude::*};
+
+/// Properties for [`AppLauncher`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct AppLauncherProperties {
+    /// The applications, typically [`crate::prelude::MenuLink`]s with an icon, grouped by
+    /// [`crate::prelude::MenuGroup`]s.
+    #[prop_or_default]
+    pub children: ChildrenRenderer<MenuChildVariant>,
+
+    #[prop_or(AttrValue::Static("Application launcher"))]
+    pub aria_label: AttrValue,
+
+    #[prop_or(Position::Right)]
+    pub position: Position,
+
+    /// Show the favorite applications in a separate group with this title.
+    #[prop_or_default]
+    pub favorites: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// Application launcher component
+///
+/// > An **application launcher** is a menu of applications, which can be launched from the
+/// > masthead.
+///
+/// A plain dropdown with a grid icon, made to be used as part of the `tools` of a
+/// [`crate::prelude::Page`].
+///
+/// ## Properties
+///
+/// Defined by [`AppLauncherProperties`].
+///
+/// ## Children
+///
+/// The same children as a [`crate::prelude::Menu`]: applications are [`crate::prelude::MenuLink`]s
+/// (which can be `external`) or [`crate::prelude::MenuAction`]s, grouped with
+/// [`crate::prelude::MenuGroup`]s and separated by [`crate::prelude::ListDivider`]s. A
+/// [`crate::prelude::MenuSearch`] adds a search input to the top of the launcher.
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let search = use_state_eq(String::new);
+///   let onchange = use_callback(search.clone(), |value, search| search.set(value));
+///
+///   let apps = [("Console", "/console"), ("Docs", "https://www.patternfly.org")];
+///
+///   html!(
+///     <AppLauncher>
+///       <MenuSearch value={(*search).clone()} {onchange} />
+///       <MenuGroup title="Applications">
+///         { for apps.into_iter()
+///             .filter(|(name, _)| name.to_lowercase().contains(&search.to_lowercase()))
+///             .map(|(name, href)| html_nested!(
+///               <MenuLink {href} external={href.starts_with("https://")} icon={Icon::Cube}>
+///                 { name }
+///               </MenuLink>
+///             ))
+///         }
+///       </MenuGroup>
+///     </AppLauncher>
+///   )
+/// }
+/// ```
+#[function_component(AppLauncher)]
+pub fn app_launcher(props: &AppLauncherProperties) -> Html {
+    html!(
+        <Dropdown
+            variant={MenuToggleVariant::Plain}
+            icon={html!({ Icon::Th })}
+            position={props.position}
+            aria_label={&props.aria_label}
+            favorites={props.favorites.clone()}
+            disabled={props.disabled}
+        >
+            { props.children.clone() }
+        </Dropdown>
+    )
+}
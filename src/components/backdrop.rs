@@ -1,5 +1,8 @@
 //! Backdrop visual
+use crate::prelude::Id;
+use crate::utils::track_focus;
 use gloo_utils::document;
+use std::cell::Cell;
 use std::rc::Rc;
 use wasm_bindgen::JsValue;
 use yew::prelude::*;
@@ -7,7 +10,14 @@ use yew::prelude::*;
 /// Backdrop overlay the main content and show some new content, until it gets closed.
 ///
 /// New content can be sent to the backdrop viewer using the [`Backdropper::open`] call. It can be
-/// closed using the [`Backdropper::close`] call.
+/// closed using the [`Backdropper::close`] call, or the [`BackdropHandle`] returned by
+/// [`Backdropper::open_with_handle`].
+///
+/// Backdrops can be stacked, for example when a modal dialog opens another (confirmation) modal
+/// dialog. Closing a backdrop from inside its content only closes that backdrop, even if other
+/// backdrops were opened on top of it in the meantime. Components
+/// like the [`Modal`](crate::prelude::Modal) only react to the escape key or outside clicks when
+/// they are part of the topmost backdrop, see [`use_backdrop_topmost`].
 ///
 /// ## Contexts
 ///
@@ -79,23 +89,48 @@ impl From<Html> for Backdrop {
 }
 
 /// A context for displaying backdrops.
+///
+/// Inside the content of a backdrop, the context belongs to that backdrop.
 #[derive(Clone, PartialEq)]
 pub struct Backdropper {
     callback: Callback<Msg>,
+    layer: Option<Id>,
 }
 
 impl Backdropper {
     /// Request a backdrop from the backdrop agent.
+    ///
+    /// If a backdrop is already open, the new one will be stacked on top of it.
     pub fn open<B>(&self, backdrop: B)
     where
         B: Into<Backdrop>,
     {
-        self.callback.emit(Msg::Open(Rc::new(backdrop.into())));
+        self.open_with_handle(backdrop);
     }
 
-    /// Close the current backdrop.
+    /// Request a backdrop from the backdrop agent, returning a handle to close it.
+    pub fn open_with_handle<B>(&self, backdrop: B) -> BackdropHandle
+    where
+        B: Into<Backdrop>,
+    {
+        let id = Id::new();
+        self.callback.emit(Msg::Open(id, Rc::new(backdrop.into())));
+        BackdropHandle {
+            id,
+            callback: self.callback.clone(),
+        }
+    }
+
+    /// Close the backdrop this context belongs to.
+    ///
+    /// Outside the content of a backdrop, this closes the topmost backdrop.
     pub fn close(&self) {
-        self.callback.emit(Msg::Close);
+        self.callback.emit(Msg::Close(self.layer));
+    }
+
+    /// Close all backdrops.
+    pub fn close_all(&self) {
+        self.callback.emit(Msg::CloseAll);
     }
 }
 
@@ -105,51 +140,134 @@ pub struct BackdropProperties {
     pub children: Html,
 }
 
+/// A handle to a backdrop, created by [`Backdropper::open_with_handle`].
+///
+/// Once the backdrop was closed, closing it again is a no-op.
+#[derive(Clone, PartialEq)]
+pub struct BackdropHandle {
+    id: Id,
+    callback: Callback<Msg>,
+}
+
+impl BackdropHandle {
+    /// Close the backdrop, keeping all others.
+    pub fn close(&self) {
+        self.callback.emit(Msg::Close(Some(self.id)));
+    }
+}
+
 #[doc(hidden)]
 enum Msg {
-    Open(Rc<Backdrop>),
-    Close,
+    Open(Id, Rc<Backdrop>),
+    /// Close a backdrop, or the topmost one.
+    Close(Option<Id>),
+    CloseAll,
+}
+
+/// The stack of open backdrops.
+#[derive(Clone, Default)]
+struct BackdropStack {
+    backdrops: Vec<(Id, Rc<Backdrop>)>,
+}
+
+impl Reducible for BackdropStack {
+    type Action = Msg;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut stack = (*self).clone();
+        match action {
+            Msg::Open(id, backdrop) => stack.backdrops.push((id, backdrop)),
+            Msg::Close(Some(id)) => stack.backdrops.retain(|(layer, _)| *layer != id),
+            Msg::Close(None) => {
+                stack.backdrops.pop();
+            }
+            Msg::CloseAll => stack.backdrops.clear(),
+        }
+        Rc::new(stack)
+    }
+}
+
+/// The layer of a backdrop in the stack.
+#[derive(Clone, PartialEq)]
+struct BackdropLayerContext {
+    top: bool,
 }
 
 #[function_component(BackdropViewer)]
 pub fn backdrop_viewer(props: &BackdropProperties) -> Html {
-    // hold the state of the current backdrop
-    let open = use_state::<Option<Rc<Backdrop>>, _>(|| None);
+    // hold the stack of the currently open backdrops
+    let stack = use_reducer(BackdropStack::default);
 
     // create the context, only once
     let ctx = {
-        let open = open.clone();
+        let dispatcher = stack.dispatcher();
         use_memo((), |()| Backdropper {
-            callback: Callback::from(move |msg| match msg {
-                Msg::Open(backdrop) => open.set(Some(backdrop)),
-                Msg::Close => open.set(None),
-            }),
+            callback: Callback::from(move |msg| dispatcher.dispatch(msg)),
+            layer: None,
         })
     };
 
-    // when the open state changes, change the overlay
-    use_effect_with(open.is_some(), |open| {
-        match open {
-            true => body_open(),
-            false => body_close(),
-        }
-        body_close
-    });
+    // track the focus, so that modals can restore it when being closed
+    use_effect_with((), |()| track_focus());
+
+    let len = stack.backdrops.len();
 
     // render
     html!(
         <ContextProvider<Backdropper> context={(*ctx).clone()}>
-            if let Some(open) = &*open {
-                <div class="pf-v5-c-backdrop">
-                    { open.content.clone() }
-                </div>
-            }
+            { for stack.backdrops.iter().enumerate().map(|(n, (id, backdrop))| html!(
+                <BackdropLayer
+                    key={id.to_string()}
+                    top={n + 1 == len}
+                    backdropper={Backdropper { layer: Some(*id), ..(*ctx).clone() }}
+                >
+                    { backdrop.content.clone() }
+                </BackdropLayer>
+            )) }
             { props.children.clone() }
         </ContextProvider<Backdropper>>
     )
 }
 
+#[derive(Clone, PartialEq, Properties)]
+struct BackdropLayerProperties {
+    top: bool,
+    /// The backdropper, scoped to this layer.
+    backdropper: Backdropper,
+    children: Html,
+}
+
+#[function_component(BackdropLayer)]
+fn backdrop_layer(props: &BackdropLayerProperties) -> Html {
+    // while the layer exists, the body is in the "open" state
+    use_effect_with((), |()| {
+        body_open();
+        body_close
+    });
+
+    let context = BackdropLayerContext { top: props.top };
+
+    html!(
+        <ContextProvider<BackdropLayerContext> {context}>
+            <ContextProvider<Backdropper> context={props.backdropper.clone()}>
+                <div class="pf-v5-c-backdrop">
+                    { props.children.clone() }
+                </div>
+            </ContextProvider<Backdropper>>
+        </ContextProvider<BackdropLayerContext>>
+    )
+}
+
+thread_local! {
+    /// The number of currently open backdrops.
+    static OPEN: Cell<usize> = const { Cell::new(0) };
+}
+
 fn body_open() {
+    if OPEN.with(|open| open.replace(open.get() + 1)) > 0 {
+        return;
+    }
+
     if let Some(body) = document().body() {
         let classes = js_sys::Array::of1(&JsValue::from_str("pf-v5-c-backdrop__open"));
         body.class_list().add(&classes).ok();
@@ -157,6 +275,15 @@ fn body_open() {
 }
 
 fn body_close() {
+    let open = OPEN.with(|open| {
+        let value = open.get().saturating_sub(1);
+        open.set(value);
+        value
+    });
+    if open > 0 {
+        return;
+    }
+
     if let Some(body) = document().body() {
         let classes = js_sys::Array::of1(&JsValue::from_str("pf-v5-c-backdrop__open"));
         body.class_list().remove(&classes).ok();
@@ -168,3 +295,42 @@ fn body_close() {
 pub fn use_backdrop() -> Option<Backdropper> {
     use_context::<Backdropper>()
 }
+
+/// Check if the component is in the topmost backdrop.
+///
+/// This is also `true` if the component isn't part of a backdrop at all. It can be used to only
+/// handle events (like pressing the escape key) in the topmost modal dialog.
+#[hook]
+pub fn use_backdrop_topmost() -> bool {
+    use_context::<BackdropLayerContext>()
+        .map(|layer| layer.top)
+        .unwrap_or(true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ids(stack: &BackdropStack) -> Vec<Id> {
+        stack.backdrops.iter().map(|(id, _)| *id).collect()
+    }
+
+    #[test]
+    fn test_close() {
+        let [a, b, c] = [Id::new(), Id::new(), Id::new()];
+        let mut stack = Rc::new(BackdropStack::default());
+        for id in [a, b, c] {
+            stack = stack.reduce(Msg::Open(id, Default::default()));
+        }
+
+        // closing a lower layer keeps the ones above
+        stack = stack.reduce(Msg::Close(Some(b)));
+        assert_eq!(ids(&stack), vec![a, c]);
+        // closing again is a no-op
+        stack = stack.reduce(Msg::Close(Some(b)));
+        assert_eq!(ids(&stack), vec![a, c]);
+
+        stack = stack.reduce(Msg::Close(None));
+        assert_eq!(ids(&stack), vec![a]);
+    }
+}
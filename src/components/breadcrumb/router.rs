@@ -1,4 +1,5 @@
-use super::variant::BreadcrumbChild;
+use super::variant::{BreadcrumbChild, BreadcrumbItemVariant};
+use super::{Breadcrumb, BreadcrumbItem};
 use std::rc::Rc;
 use yew::{prelude::*, virtual_dom::VComp};
 use yew_nested_router::{components::Link, prelude::*};
@@ -47,3 +48,179 @@ pub fn breadcrumb_router_item<T: Target>(props: &BreadcrumbRouterItemProperties<
         </Link<T>>
     )
 }
+
+/// A router target, which can be shown in a [`RouterBreadcrumb`].
+pub trait BreadcrumbTarget: Target {
+    /// The label of this level, [`None`] to skip it.
+    fn label(&self) -> Option<Html>;
+
+    /// Whether the crumb of this level links to its target.
+    fn linkable(&self) -> bool {
+        true
+    }
+}
+
+/// Properties for [`RouterBreadcrumb`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct RouterBreadcrumbProperties {
+    /// Show a crumb for the root level too, the target of an empty path.
+    #[prop_or(true)]
+    pub root: bool,
+}
+
+/// A breadcrumb, generated from the active target of the [`yew_nested_router`].
+///
+/// Each prefix of the path of the active target, which is a valid target itself, forms a
+/// level. The labels of the levels are provided by the [`BreadcrumbTarget`] implementation.
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use yew_nested_router::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Target)]
+/// enum AppRoute {
+///   #[target(index)]
+///   Index,
+///   Users(UsersRoute),
+/// }
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Target)]
+/// enum UsersRoute {
+///   #[target(index)]
+///   Index,
+///   Details { id: String },
+/// }
+///
+/// impl BreadcrumbTarget for AppRoute {
+///   fn label(&self) -> Option<Html> {
+///     Some(match self {
+///       Self::Index => html!("Home"),
+///       Self::Users(UsersRoute::Index) => html!("Users"),
+///       Self::Users(UsersRoute::Details { id }) => html!(id),
+///     })
+///   }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   html!(
+///     <Router<AppRoute>>
+///       <RouterBreadcrumb<AppRoute> />
+///     </Router<AppRoute>>
+///   )
+/// }
+/// ```
+#[function_component(RouterBreadcrumb)]
+pub fn router_breadcrumb<T: BreadcrumbTarget>(props: &RouterBreadcrumbProperties) -> Html {
+    let router = use_router::<T>().expect("Requires a Router or Nested router");
+
+    let Some(active) = router.active() else {
+        return html!();
+    };
+
+    let items = levels(active)
+        .into_iter()
+        .filter(|(path, _)| props.root || !path.is_empty())
+        .filter_map(|(_, target)| target.label().map(|label| (target, label)))
+        .map(|(target, label)| match target.linkable() {
+            true => html_nested!(
+                <BreadcrumbRouterItem<T> to={target}>{ label }</BreadcrumbRouterItem<T>>
+            )
+            .into(),
+            false => html_nested!(<BreadcrumbItem>{ label }</BreadcrumbItem>).into(),
+        })
+        .collect::<Vec<BreadcrumbItemVariant>>();
+
+    if items.is_empty() {
+        return html!();
+    }
+
+    html!(
+        <Breadcrumb>
+            { for items }
+        </Breadcrumb>
+    )
+}
+
+/// The levels of a target, the targets of all prefixes of its path, along with the prefix.
+///
+/// A prefix which is not a target itself may still lead to the index of a nested target. That
+/// is the case when the index parses, but an arbitrary segment doesn't, as otherwise the segment
+/// would be a value captured by the target.
+fn levels<T: Target>(target: &T) -> Vec<(Vec<String>, T)> {
+    let path = target.render_path();
+    let mut levels = Vec::<(Vec<String>, T)>::new();
+
+    for len in 0..=path.len() {
+        let prefix = path[..len].iter().map(String::as_str).collect::<Vec<_>>();
+        let level = match len == path.len() {
+            true => Some(target.clone()),
+            false => T::parse_path(&prefix).or_else(|| {
+                T::parse_path(&[&prefix[..], &["\0"]].concat())
+                    .is_none()
+                    .then(|| T::parse_path(&[&prefix[..], &[""]].concat()))
+                    .flatten()
+            }),
+        };
+        if let Some(level) = level {
+            // a prefix and its index resolve to the same target
+            if levels.last().map(|(_, last)| last) != Some(&level) {
+                levels.push((path[..len].to_vec(), level));
+            }
+        }
+    }
+
+    levels
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Target)]
+    enum AppRoute {
+        #[target(index)]
+        Index,
+        Users(UsersRoute),
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Target)]
+    enum UsersRoute {
+        #[target(index)]
+        Index,
+        Details {
+            id: String,
+        },
+    }
+
+    #[test]
+    fn test_levels() {
+        let details = AppRoute::Users(UsersRoute::Details { id: "foo".into() });
+        assert_eq!(
+            levels(&details)
+                .into_iter()
+                .map(|(_, target)| target)
+                .collect::<Vec<_>>(),
+            vec![
+                AppRoute::Index,
+                AppRoute::Users(UsersRoute::Index),
+                details.clone()
+            ]
+        );
+
+        assert_eq!(levels(&AppRoute::Index), vec![(vec![], AppRoute::Index)]);
+        assert_eq!(
+            levels(&AppRoute::Users(UsersRoute::Index)),
+            vec![
+                (vec![], AppRoute::Index),
+                (
+                    vec!["users".to_string()],
+                    AppRoute::Users(UsersRoute::Index)
+                )
+            ]
+        );
+    }
+}
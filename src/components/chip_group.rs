@@ -1,7 +1,7 @@
 //! Chip Group
 
 use crate::prelude::wrap::wrapper_elt_with_attributes;
-use crate::prelude::{use_prop_id, Chip};
+use crate::prelude::{use_prop_id, Button, ButtonVariant, Chip, Icon};
 use yew::prelude::*;
 use yew::virtual_dom::ApplyAttributeAs;
 
@@ -18,6 +18,14 @@ pub struct ChipGroupProperties {
 
     #[prop_or("Chip group list".into())]
     pub aria_label: AttrValue,
+
+    /// Called when the user clicks the close button of the group. No close button is shown if
+    /// unset.
+    #[prop_or_default]
+    pub onclose: Option<Callback<()>>,
+
+    #[prop_or("Close chip group".into())]
+    pub close_aria_label: AttrValue,
 }
 
 #[function_component(ChipGroup)]
@@ -58,6 +66,16 @@ pub fn chip_group(props: &ChipGroupProperties) -> Html {
                     })}
                 </ul>
             </div>
+            if let Some(onclose) = &props.onclose {
+                <div class="pf-v5-c-chip-group__close">
+                    <Button
+                        variant={ButtonVariant::Plain}
+                        icon={Icon::TimesCircle}
+                        aria_label={&props.close_aria_label}
+                        onclick={onclose.reform(|_| ())}
+                    />
+                </div>
+            }
         </div>
     )
 }
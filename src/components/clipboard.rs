@@ -2,8 +2,7 @@
 use crate::icon::*;
 use crate::prelude::TextInput;
 use crate::prelude::*;
-use gloo_timers::callback::Timeout;
-use wasm_bindgen::prelude::*;
+use std::cell::RefCell;
 use web_sys::{Element, HtmlInputElement};
 use yew::prelude::*;
 
@@ -22,6 +21,19 @@ pub struct ClipboardProperties {
     pub name: String,
     #[prop_or_default]
     pub id: String,
+
+    /// The tooltip of the copy button.
+    #[prop_or(AttrValue::Static("Copy to clipboard"))]
+    pub text: AttrValue,
+    /// The tooltip after copying succeeded.
+    #[prop_or(AttrValue::Static("Copied!"))]
+    pub copied_text: AttrValue,
+    /// The tooltip after copying failed.
+    #[prop_or(AttrValue::Static("Failed to copy"))]
+    pub failed_text: AttrValue,
+    /// Called after copying, with the error message when copying failed.
+    #[prop_or_default]
+    pub oncopy: Callback<Result<(), String>>,
 }
 
 #[derive(Clone, Default, PartialEq, Eq, Debug)]
@@ -47,22 +59,6 @@ impl ClipboardVariant {
     }
 }
 
-#[doc(hidden)]
-#[derive(Clone, Debug)]
-pub enum Msg {
-    Copy,
-    Copied,
-    Failed(&'static str),
-    Reset,
-    ToggleExpand,
-    /// Sync the content from the
-    Sync,
-}
-
-const DEFAULT_MESSAGE: &str = "Copy to clipboard";
-const FAILED_MESSAGE: &str = "Failed to copy";
-const OK_MESSAGE: &str = "Copied!";
-
 /// Clipboard copy component
 ///
 /// > The **clipboard copy** component allows users to quickly and easily copy content to their clipboard.
@@ -72,245 +68,193 @@ const OK_MESSAGE: &str = "Copied!";
 /// ## Properties
 ///
 /// Defined by [`ClipboardProperties`].
-pub struct Clipboard {
-    message: &'static str,
-    task: Option<Timeout>,
-    expanded: bool,
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let oncopy = Callback::from(|result: Result<(), String>| {
+///     if let Err(err) = result {
+///       log::warn!("Failed to copy: {err}");
+///     }
+///   });
+///
+///   html!(
+///     <Clipboard value="some value" readonly=true {oncopy} />
+///   )
+/// }
+/// ```
+#[function_component(Clipboard)]
+pub fn clipboard(props: &ClipboardProperties) -> Html {
+    let expanded = use_state_eq(|| matches!(props.variant, ClipboardVariant::Expanded));
     // the value, when overridden by the user
-    value: Option<String>,
-    text_ref: NodeRef,
-    details_ref: NodeRef,
-}
-
-impl Component for Clipboard {
-    type Message = Msg;
-    type Properties = ClipboardProperties;
-
-    fn create(ctx: &Context<Self>) -> Self {
-        let expanded = matches!(ctx.props().variant, ClipboardVariant::Expanded);
-
-        Self {
-            message: DEFAULT_MESSAGE,
-            task: None,
-            expanded,
-            value: None,
-            text_ref: NodeRef::default(),
-            details_ref: NodeRef::default(),
-        }
+    let value = use_mut_ref(|| None::<String>);
+    let text_ref = use_node_ref();
+    let details_ref = use_node_ref();
+
+    let clipboard = use_clipboard(ClipboardOptions {
+        text: props.text.clone(),
+        copied_text: props.copied_text.clone(),
+        failed_text: props.failed_text.clone(),
+        oncopy: props.oncopy.clone(),
+        ..Default::default()
+    });
+
+    let current = value
+        .borrow()
+        .clone()
+        .unwrap_or_else(|| props.value.clone());
+
+    let oncopy = {
+        let clipboard = clipboard.clone();
+        let value = value.clone();
+        let default = props.value.clone();
+        Callback::from(move |_: MouseEvent| {
+            let value = value.borrow().clone().unwrap_or_else(|| default.clone());
+            clipboard.copy(value);
+        })
+    };
+
+    let ontoggle = use_callback(expanded.clone(), |_: MouseEvent, expanded| {
+        expanded.set(!**expanded)
+    });
+
+    let onsync = {
+        let props = props.clone();
+        let expanded = *expanded;
+        let text_ref = text_ref.clone();
+        let details_ref = details_ref.clone();
+        Callback::from(move |_: InputEvent| {
+            sync_from_edit(&props, expanded, &text_ref, &details_ref, &value)
+        })
+    };
+
+    let mut classes = Classes::from("pf-v5-c-clipboard-copy");
+
+    if *expanded {
+        classes.push("pf-m-expanded");
     }
-
-    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        match msg {
-            Msg::Copy => {
-                self.do_copy(ctx);
-            }
-            Msg::Copied => {
-                self.trigger_message(ctx, OK_MESSAGE);
-            }
-            Msg::Failed(msg) => {
-                self.trigger_message(ctx, msg);
-            }
-            Msg::Reset => {
-                self.message = DEFAULT_MESSAGE;
-                self.task.take();
-            }
-            Msg::ToggleExpand => {
-                self.expanded = !self.expanded;
-            }
-            Msg::Sync => {
-                self.sync_from_edit(ctx);
-                return false;
-            }
-        }
-        true
+    if props.variant.is_inline() {
+        classes.push("pf-m-inline");
     }
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let mut classes = Classes::from("pf-v5-c-clipboard-copy");
-
-        if self.expanded {
-            classes.push("pf-m-expanded");
-        }
-        if ctx.props().variant.is_inline() {
-            classes.push("pf-m-inline");
-        }
-
-        let value = self.value(ctx);
-
-        html! {
-            <div class={classes}>
-                { match ctx.props().variant {
-                    ClipboardVariant::Inline => {
-                        html!{
-                            <>
-                            if ctx.props().code {
-                                <code name={ctx.props().name.clone()} id={ctx.props().id.clone()} class="pf-v5-c-clipboard-copy__text pf-m-code">{value}</code>
-                            } else {
-                                <span name={ctx.props().name.clone()} id={ctx.props().id.clone()} class="pf-v5-c-clipboard-copy__text">{value}</span>
-                            }
-                            <span class="pf-v5-c-clipboard-copy__actions">
-                                <span class="pf-v5-c-clipboard-copy__actions-item">
-                                    <Tooltip text={self.message}>
-                                        <Button aria_label="Copy to clipboard" variant={ButtonVariant::Plain} icon={Icon::Copy} onclick={ctx.link().callback(|_|Msg::Copy)}/>
-                                    </Tooltip>
-                                </span>
-                            </span>
-                            </>
+    let message = clipboard.message.to_string();
+
+    html! {
+        <div class={classes}>
+            { match props.variant {
+                ClipboardVariant::Inline => {
+                    html!{
+                        <>
+                        if props.code {
+                            <code name={props.name.clone()} id={props.id.clone()} class="pf-v5-c-clipboard-copy__text pf-m-code">{current}</code>
+                        } else {
+                            <span name={props.name.clone()} id={props.id.clone()} class="pf-v5-c-clipboard-copy__text">{current}</span>
                         }
-                    },
-                    _ => {
-                        html!{
-                            <>
-                            <div class="pf-v5-c-clipboard-copy__group">
-                                { self.expander(ctx) }
-                                <TextInput
-                                    r#ref={self.text_ref.clone()}
-                                    readonly={ctx.props().readonly | self.expanded}
-                                    value={value}
-                                    name={ctx.props().name.clone()}
-                                    id={ctx.props().id.clone()}
-                                    oninput={ctx.link().callback(|_|Msg::Sync)}
-                                />
-                                <Tooltip text={self.message}>
-                                    <Button aria_label="Copy to clipboard" variant={ButtonVariant::Control} icon={Icon::Copy} onclick={ctx.link().callback(|_|Msg::Copy)}/>
+                        <span class="pf-v5-c-clipboard-copy__actions">
+                            <span class="pf-v5-c-clipboard-copy__actions-item">
+                                <Tooltip text={message}>
+                                    <Button aria_label={props.text.clone()} variant={ButtonVariant::Plain} icon={Icon::Copy} onclick={oncopy}/>
                                 </Tooltip>
+                            </span>
+                        </span>
+                        </>
+                    }
+                },
+                _ => {
+                    html!{
+                        <>
+                        <div class="pf-v5-c-clipboard-copy__group">
+                            if props.variant.is_expandable() {
+                                <Button
+                                    expanded={*expanded}
+                                    variant={ButtonVariant::Control}
+                                    onclick={ontoggle}>
+                                    <div class="pf-v5-c-clipboard-copy__toggle-icon">
+                                        { Icon::AngleRight }
+                                    </div>
+                                </Button>
+                            }
+                            <TextInput
+                                r#ref={text_ref}
+                                readonly={props.readonly | *expanded}
+                                value={current.clone()}
+                                name={props.name.clone()}
+                                id={props.id.clone()}
+                                oninput={onsync.clone()}
+                            />
+                            <Tooltip text={message}>
+                                <Button aria_label={props.text.clone()} variant={ButtonVariant::Control} icon={Icon::Copy} onclick={oncopy}/>
+                            </Tooltip>
+                        </div>
+                        if *expanded {
+                            <div
+                                ref={details_ref}
+                                class="pf-v5-c-clipboard-copy__expandable-content"
+                                contenteditable={(!props.readonly).to_string()}
+                                oninput={onsync}
+                            >
+
+                                if props.code {
+                                    <pre>{ current }</pre>
+                                } else {
+                                    { current }
+                                }
+
                             </div>
-                            { self.expanded(ctx) }
-                            </>
                         }
+                        </>
                     }
-                }}
-            </div>
-        }
+                }
+            }}
+        </div>
     }
 }
 
-impl Clipboard {
-    fn value(&self, ctx: &Context<Self>) -> String {
-        self.value
-            .clone()
-            .unwrap_or_else(|| ctx.props().value.clone())
-    }
-
-    fn trigger_message(&mut self, ctx: &Context<Self>, msg: &'static str) {
-        self.message = msg;
-        self.task = Some({
-            let link = ctx.link().clone();
-            Timeout::new(2_000, move || {
-                link.send_message(Msg::Reset);
-            })
-        });
-    }
-
-    fn do_copy(&self, ctx: &Context<Self>) {
-        let s = self.value(ctx);
-
-        let ok: Callback<()> = ctx.link().callback(|_| Msg::Copied);
-        let err: Callback<&'static str> = ctx.link().callback(Msg::Failed);
-
-        wasm_bindgen_futures::spawn_local(async move {
-            match copy_to_clipboard(s).await {
-                Ok(_) => ok.emit(()),
-                Err(_) => err.emit(FAILED_MESSAGE),
-            };
-        });
-    }
-
-    fn expander(&self, ctx: &Context<Self>) -> Html {
-        if !ctx.props().variant.is_expandable() {
-            return Default::default();
-        }
-
-        let onclick = ctx.link().callback(|_| Msg::ToggleExpand);
-
-        html! (
-            <Button
-                expanded={self.expanded}
-                variant={ButtonVariant::Control}
-                onclick={onclick}>
-                <div class="pf-v5-c-clipboard-copy__toggle-icon">
-                    { Icon::AngleRight }
-                </div>
-            </Button>
-        )
-    }
-
-    fn expanded(&self, ctx: &Context<Self>) -> Html {
-        if !self.expanded {
-            return Default::default();
-        }
-
-        let value = self.value(ctx);
-
-        html! {
-            <div
-                ref={self.details_ref.clone()}
-                class="pf-v5-c-clipboard-copy__expandable-content"
-                contenteditable={(!ctx.props().readonly).to_string()}
-                oninput={ctx.link().callback(|_|Msg::Sync)}
-            >
-
-                if ctx.props().code {
-                    <pre>{ value }</pre>
-                } else {
-                    { value }
-                }
-
-            </div>
-        }
+/// Sync the value between internal, text field or details.
+fn sync_from_edit(
+    props: &ClipboardProperties,
+    expanded: bool,
+    text_ref: &NodeRef,
+    details_ref: &NodeRef,
+    state: &RefCell<Option<String>>,
+) {
+    if props.readonly || props.variant.is_inline() {
+        return;
     }
 
-    /// Sync the value between internal, text field or details.
-    fn sync_from_edit(&mut self, ctx: &Context<Self>) {
-        if ctx.props().readonly || ctx.props().variant.is_inline() {
-            return;
-        }
-
-        let value = if self.expanded {
-            // from div to input
-            let ele: Option<Element> = self.details_ref.cast::<Element>();
-            ele.and_then(|ele| ele.text_content())
-                .unwrap_or_else(|| "".into())
-        } else {
-            // from input to div
-            let ele: Option<HtmlInputElement> = self.text_ref.cast::<HtmlInputElement>();
-            ele.map(|ele| ele.value()).unwrap_or_else(|| "".into())
-        };
-
-        log::debug!("New value: {}", value);
-
-        // sync back
-        match self.expanded {
-            true => {
-                if let Some(ele) = self.text_ref.cast::<HtmlInputElement>() {
-                    ele.set_value(&value);
-                }
+    let value = if expanded {
+        // from div to input
+        let ele: Option<Element> = details_ref.cast::<Element>();
+        ele.and_then(|ele| ele.text_content())
+            .unwrap_or_else(|| "".into())
+    } else {
+        // from input to div
+        let ele: Option<HtmlInputElement> = text_ref.cast::<HtmlInputElement>();
+        ele.map(|ele| ele.value()).unwrap_or_else(|| "".into())
+    };
+
+    log::debug!("New value: {}", value);
+
+    // sync back
+    match expanded {
+        true => {
+            if let Some(ele) = text_ref.cast::<HtmlInputElement>() {
+                ele.set_value(&value);
             }
-            false => {
-                if let Some(ele) = self.details_ref.cast::<Element>() {
-                    ele.set_text_content(Some(&value));
-                }
+        }
+        false => {
+            if let Some(ele) = details_ref.cast::<Element>() {
+                ele.set_text_content(Some(&value));
             }
         }
-
-        // sync to internal state
-
-        self.value = Some(value);
     }
-}
 
-#[wasm_bindgen(inline_js=r#"
-export function copy_to_clipboard(value) {
-    try {
-        return window.navigator.clipboard.writeText(value);
-    } catch(e) {
-        console.log(e);
-        return Promise.reject(e)
-    }
-}
-"#)]
-#[rustfmt::skip] // required to keep the "async" keyword
-extern "C" {
-    #[wasm_bindgen(catch)]
-    async fn copy_to_clipboard(value: String) -> Result<(), JsValue>;
+    // sync to internal state
+
+    *state.borrow_mut() = Some(value);
 }
@@ -0,0 +1,352 @@
+//! Code block
+use crate::icon::Icon;
+use crate::prelude::{use_clipboard, Button, ButtonVariant, ClipboardOptions, Tooltip};
+use gloo_timers::callback::Timeout;
+use std::ops::RangeInclusive;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+use yew::prelude::*;
+
+mod tokenizer;
+
+pub use tokenizer::*;
+
+/// Properties for [`CodeBlock`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CodeBlockProperties {
+    #[prop_or_default]
+    pub expandable: bool,
+    #[prop_or_default]
+    pub children: Html,
+    #[prop_or_default]
+    pub actions: ChildrenWithProps<CodeBlockAction>,
+
+    /// The code to show, rendered by a [`CodeBlockCode`] before the children.
+    ///
+    /// Required for the built-in copy and download actions.
+    #[prop_or_default]
+    pub code: Option<AttrValue>,
+    /// Show line numbers, see [`CodeBlockCodeProperties::line_numbers`].
+    #[prop_or_default]
+    pub line_numbers: bool,
+    /// Highlighted lines, see [`CodeBlockCodeProperties::highlight`].
+    #[prop_or_default]
+    pub highlight: Vec<RangeInclusive<usize>>,
+    /// The tokenizer used for syntax highlighting.
+    #[prop_or_default]
+    pub tokenizer: Option<CodeBlockTokenizer>,
+
+    /// Add an action, copying the code to the clipboard.
+    #[prop_or_default]
+    pub copy: bool,
+    /// Add an action, downloading the code as a file with this name.
+    #[prop_or_default]
+    pub download: Option<AttrValue>,
+
+    #[prop_or(AttrValue::Static("Copy to clipboard"))]
+    pub copy_text: AttrValue,
+    #[prop_or(AttrValue::Static("Copied!"))]
+    pub copied_text: AttrValue,
+    #[prop_or(AttrValue::Static("Failed to copy"))]
+    pub copy_failed_text: AttrValue,
+    #[prop_or(AttrValue::Static("Download"))]
+    pub download_text: AttrValue,
+
+    /// Called after copying the code, with the error message when copying failed.
+    #[prop_or_default]
+    pub oncopy: Callback<Result<(), String>>,
+}
+
+/// Code Block component
+///
+/// > A **code block** is a component that contains 2 or more lines of read-only code. The code in a code block can be copied to the clipboard.
+///
+/// See: <https://www.patternfly.org/components/code-block>
+///
+/// ## Properties
+///
+/// Defined by [`CodeBlockProperties`].
+///
+/// ## Children
+///
+/// A code block can contain any children, but is expected to contain a [`CodeBlockCode`] component.
+///
+/// It may also be wrapped with a detached [`crate::prelude::ExpandableSection`] component. The
+/// [`crate::prelude::ExpandableSectionToggle`] would then be a child of this component, but stay outside the nested
+/// code component.
+///
+/// ## Example
+///
+/// A simple example would be:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   html!(
+///     <CodeBlock>
+///       <CodeBlockCode>{r#"some code"#}</CodeBlockCode>
+///     </CodeBlock>
+///   )
+/// }
+/// ```
+///
+/// The code block can also render the code itself, with line numbers, syntax highlighting and
+/// actions for copying and downloading it:
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   html!(
+///     <CodeBlock
+///       code={r#"{ "foo": "bar" }"#}
+///       line_numbers=true
+///       highlight={vec![1..=1]}
+///       tokenizer={CodeBlockTokenizer::new(JsonTokenizer)}
+///       copy=true
+///       download="example.json"
+///     />
+///   )
+/// }
+/// ```
+#[function_component(CodeBlock)]
+pub fn code_block(props: &CodeBlockProperties) -> Html {
+    let code = props.code.clone().unwrap_or_default();
+
+    let clipboard = use_clipboard(ClipboardOptions {
+        text: props.copy_text.clone(),
+        copied_text: props.copied_text.clone(),
+        failed_text: props.copy_failed_text.clone(),
+        oncopy: props.oncopy.clone(),
+        ..Default::default()
+    });
+    let message = clipboard.message.to_string();
+    let oncopy = {
+        let code = code.clone();
+        Callback::from(move |_: MouseEvent| clipboard.copy(code.clone()))
+    };
+
+    let ondownload = use_callback(
+        (code.clone(), props.download.clone()),
+        |_: MouseEvent, (code, name)| {
+            if let Some(name) = name {
+                if let Err(err) = download(name, code) {
+                    log::warn!("Failed to download code: {err:?}");
+                }
+            }
+        },
+    );
+
+    let copy = props.copy && props.code.is_some();
+    let download = props.download.is_some() && props.code.is_some();
+
+    html!(
+        <div class="pf-v5-c-code-block">
+            if !props.actions.is_empty() || copy || download {
+                <div class="pf-v5-c-code-block__header">
+                    <div class="pf-v5-c-code-block__actions">
+                        { for props.actions.iter() }
+                        if copy {
+                            <CodeBlockAction>
+                                <Tooltip text={message}>
+                                    <Button
+                                        variant={ButtonVariant::Plain}
+                                        icon={Icon::Copy}
+                                        aria_label={props.copy_text.clone()}
+                                        onclick={oncopy}
+                                    />
+                                </Tooltip>
+                            </CodeBlockAction>
+                        }
+                        if download {
+                            <CodeBlockAction>
+                                <Tooltip text={props.download_text.to_string()}>
+                                    <Button
+                                        variant={ButtonVariant::Plain}
+                                        icon={Icon::Download}
+                                        aria_label={props.download_text.clone()}
+                                        onclick={ondownload}
+                                    />
+                                </Tooltip>
+                            </CodeBlockAction>
+                        }
+                    </div>
+                </div>
+            }
+
+            <div class="pf-v5-c-code-block__content">
+                if props.code.is_some() {
+                    <CodeBlockCode
+                        {code}
+                        line_numbers={props.line_numbers}
+                        highlight={props.highlight.clone()}
+                        tokenizer={props.tokenizer.clone()}
+                    />
+                }
+                { props.children.clone() }
+            </div>
+        </div>
+    )
+}
+
+/// Let the browser download the text as a file.
+fn download(name: &str, text: &str) -> Result<(), JsValue> {
+    let options = BlobPropertyBag::new();
+    options.set_type("text/plain");
+    let blob = Blob::new_with_str_sequence_and_options(
+        &js_sys::Array::of1(&JsValue::from_str(text)),
+        &options,
+    )?;
+
+    let url = Url::create_object_url_with_blob(&blob)?;
+    let anchor = gloo_utils::document()
+        .create_element("a")?
+        .dyn_into::<HtmlAnchorElement>()?;
+    anchor.set_href(&url);
+    anchor.set_download(name);
+    anchor.click();
+
+    // the browser may start the download asynchronously, so keep the URL valid until then
+    Timeout::new(0, move || {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+
+    Ok(())
+}
+
+/// Properties for [`CodeBlockCode`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CodeBlockCodeProperties {
+    #[prop_or_default]
+    pub children: Html,
+
+    /// The code to show, rendered line by line before the children.
+    #[prop_or_default]
+    pub code: Option<AttrValue>,
+    /// Show line numbers, starting at 1.
+    #[prop_or_default]
+    pub line_numbers: bool,
+    /// Highlighted lines, as ranges of line numbers.
+    #[prop_or_default]
+    pub highlight: Vec<RangeInclusive<usize>>,
+    /// The tokenizer used for syntax highlighting.
+    #[prop_or_default]
+    pub tokenizer: Option<CodeBlockTokenizer>,
+}
+
+const LINE_STYLE: &str = "display: block;";
+const HIGHLIGHTED_LINE_STYLE: &str =
+    "display: block; background-color: var(--pf-v5-global--palette--gold-50);";
+
+/// The actual code component of the Code Block component.
+///
+/// ## Properties
+///
+/// Defined by [`CodeBlockCodeProperties`].
+#[function_component(CodeBlockCode)]
+pub fn code_block_code(props: &CodeBlockCodeProperties) -> Html {
+    let code = props.code.as_deref().map(|code| {
+        let lines = lines(code).collect::<Vec<_>>();
+        // width of the line number column, in characters
+        let width = lines.len().to_string().len();
+
+        lines
+            .into_iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let number = index + 1;
+                let style = match props.highlight.iter().any(|range| range.contains(&number)) {
+                    true => HIGHLIGHTED_LINE_STYLE,
+                    false => LINE_STYLE,
+                };
+
+                html!(
+                    <span {style}>
+                        if props.line_numbers {
+                            <span
+                                aria-hidden="true"
+                                style={format!(
+                                    "display: inline-block; min-width: {width}ch; \
+                                    margin-right: var(--pf-v5-global--spacer--md); \
+                                    text-align: right; user-select: none; \
+                                    color: var(--pf-v5-global--Color--200);"
+                                )}
+                            >
+                                { number }
+                            </span>
+                        }
+                        { tokens(line, props.tokenizer.as_ref()) }
+                    </span>
+                )
+            })
+            .collect::<Html>()
+    });
+
+    html!(
+        <pre class="pf-v5-c-code-block__pre">
+            <code class="pf-v5-c-code-block__code">
+                { code }
+                { props.children.clone() }
+            </code>
+        </pre>
+    )
+}
+
+/// The lines of the code, ignoring a final line break.
+fn lines(code: &str) -> impl Iterator<Item = &str> {
+    code.strip_suffix('\n')
+        .unwrap_or(code)
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+fn tokens(line: &str, tokenizer: Option<&CodeBlockTokenizer>) -> Html {
+    let Some(tokenizer) = tokenizer else {
+        return html!({ line });
+    };
+
+    tokenizer
+        .tokenize(line)
+        .into_iter()
+        .map(|token| match token.kind {
+            Some(kind) => html!(<span style={kind.style()}>{ token.text }</span>),
+            None => html!({ token.text }),
+        })
+        .collect()
+}
+
+/// Properties for [`CodeBlockAction`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct CodeBlockActionProperties {
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// An action of a [`CodeBlock`]
+#[function_component(CodeBlockAction)]
+pub fn code_block_action(props: &CodeBlockActionProperties) -> Html {
+    html!(
+        <div class="pf-v5-c-code-block__actions-item">
+            { props.children.clone() }
+        </div>
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(lines("").collect::<Vec<_>>(), vec![""]);
+        assert_eq!(lines("a\nb").collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(lines("a\r\nb\r\n").collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(lines("a\n\n").collect::<Vec<_>>(), vec!["a", ""]);
+    }
+}
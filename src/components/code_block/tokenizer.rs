@@ -0,0 +1,361 @@
+//! Tokenizing code for syntax highlighting.
+
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+/// The kind of a token, deciding how it is highlighted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CodeTokenKind {
+    Key,
+    String,
+    Number,
+    /// Literals like `true`, `false` or `null`.
+    Literal,
+    Comment,
+    Punctuation,
+}
+
+impl CodeTokenKind {
+    /// The inline style used for highlighting the token.
+    pub fn style(&self) -> &'static str {
+        match self {
+            Self::Key => "color: var(--pf-v5-global--palette--blue-500);",
+            Self::String => "color: var(--pf-v5-global--palette--green-600);",
+            Self::Number => "color: var(--pf-v5-global--palette--orange-400);",
+            Self::Literal => "color: var(--pf-v5-global--palette--purple-500);",
+            Self::Comment => "color: var(--pf-v5-global--Color--200); font-style: italic;",
+            Self::Punctuation => "color: var(--pf-v5-global--Color--200);",
+        }
+    }
+}
+
+/// A part of a line of code.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CodeToken<'a> {
+    pub text: &'a str,
+    /// The kind of the token, [`None`] for text which isn't highlighted.
+    pub kind: Option<CodeTokenKind>,
+}
+
+/// Split code into tokens, for syntax highlighting.
+///
+/// Code is tokenized line by line, so constructs spanning multiple lines are not supported.
+pub trait CodeTokenizer {
+    /// Split a line into tokens. Joining the text of all tokens must result in the line.
+    fn tokenize<'a>(&self, line: &'a str) -> Vec<CodeToken<'a>>;
+}
+
+/// A tokenizer, used by a [`super::CodeBlock`].
+///
+/// Tokenizers are equal if they are the same instance.
+#[derive(Clone)]
+pub struct CodeBlockTokenizer(Rc<dyn CodeTokenizer>);
+
+impl CodeBlockTokenizer {
+    pub fn new<T: CodeTokenizer + 'static>(tokenizer: T) -> Self {
+        Self(Rc::new(tokenizer))
+    }
+
+    pub fn tokenize<'a>(&self, line: &'a str) -> Vec<CodeToken<'a>> {
+        self.0.tokenize(line)
+    }
+}
+
+impl PartialEq for CodeBlockTokenizer {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Debug for CodeBlockTokenizer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CodeBlockTokenizer")
+    }
+}
+
+impl<T: CodeTokenizer + 'static> From<T> for CodeBlockTokenizer {
+    fn from(tokenizer: T) -> Self {
+        Self::new(tokenizer)
+    }
+}
+
+/// A simple tokenizer for JSON.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct JsonTokenizer;
+
+impl CodeTokenizer for JsonTokenizer {
+    fn tokenize<'a>(&self, line: &'a str) -> Vec<CodeToken<'a>> {
+        let mut tokens = Vec::new();
+        let mut rest = line;
+
+        while let Some(c) = rest.chars().next() {
+            let (len, kind) = match c {
+                '"' => {
+                    let len = string_len(rest);
+                    let kind = match rest[len..].trim_start().starts_with(':') {
+                        true => CodeTokenKind::Key,
+                        false => CodeTokenKind::String,
+                    };
+                    (len, Some(kind))
+                }
+                '-' | '0'..='9' => (
+                    prefix_len(rest, |c| {
+                        c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.')
+                    }),
+                    Some(CodeTokenKind::Number),
+                ),
+                '{' | '}' | '[' | ']' | ',' | ':' => (1, Some(CodeTokenKind::Punctuation)),
+                c if c.is_alphabetic() => {
+                    let len = prefix_len(rest, char::is_alphanumeric);
+                    let kind = matches!(&rest[..len], "true" | "false" | "null")
+                        .then_some(CodeTokenKind::Literal);
+                    (len, kind)
+                }
+                c => (
+                    prefix_len(rest, char::is_whitespace).max(c.len_utf8()),
+                    None,
+                ),
+            };
+
+            push(&mut tokens, &rest[..len], kind);
+            rest = &rest[len..];
+        }
+
+        tokens
+    }
+}
+
+/// A simple tokenizer for YAML.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct YamlTokenizer;
+
+impl CodeTokenizer for YamlTokenizer {
+    fn tokenize<'a>(&self, line: &'a str) -> Vec<CodeToken<'a>> {
+        let mut tokens = Vec::new();
+
+        let indent = prefix_len(line, char::is_whitespace);
+        push(&mut tokens, &line[..indent], None);
+        let mut rest = &line[indent..];
+
+        if matches!(rest.trim_end(), "---" | "...") {
+            push(&mut tokens, rest, Some(CodeTokenKind::Punctuation));
+            return tokens;
+        }
+
+        // sequence entries
+        while let Some(after) = rest
+            .strip_prefix('-')
+            .filter(|after| after.is_empty() || after.starts_with(char::is_whitespace))
+        {
+            push(&mut tokens, &rest[..1], Some(CodeTokenKind::Punctuation));
+            let space = prefix_len(after, char::is_whitespace);
+            push(&mut tokens, &after[..space], None);
+            rest = &after[space..];
+        }
+
+        if let Some(len) = yaml_key_len(rest) {
+            push(&mut tokens, &rest[..len], Some(CodeTokenKind::Key));
+            push(
+                &mut tokens,
+                &rest[len..len + 1],
+                Some(CodeTokenKind::Punctuation),
+            );
+            rest = &rest[len + 1..];
+        }
+
+        while !rest.is_empty() {
+            let space = prefix_len(rest, char::is_whitespace);
+            push(&mut tokens, &rest[..space], None);
+            rest = &rest[space..];
+
+            if rest.is_empty() {
+                break;
+            }
+
+            if rest.starts_with('#') {
+                push(&mut tokens, rest, Some(CodeTokenKind::Comment));
+                break;
+            }
+
+            let (len, kind) = if rest.starts_with(['"', '\'']) {
+                (string_len(rest), CodeTokenKind::String)
+            } else {
+                // a plain scalar, up to a comment
+                let scalar = rest[..rest.find(" #").unwrap_or(rest.len())].trim_end();
+                (scalar.len(), yaml_scalar_kind(scalar))
+            };
+
+            push(&mut tokens, &rest[..len], Some(kind));
+            rest = &rest[len..];
+        }
+
+        tokens
+    }
+}
+
+/// The length of the key of a `key: value` line, not including the colon.
+fn yaml_key_len(s: &str) -> Option<usize> {
+    let len = if s.starts_with(['"', '\'']) {
+        string_len(s)
+    } else {
+        let mut chars = s.char_indices().peekable();
+        loop {
+            let (index, c) = chars.next()?;
+            match c {
+                '#' if index == 0 || s[..index].ends_with(char::is_whitespace) => return None,
+                ':' if chars.peek().map_or(true, |(_, c)| c.is_whitespace()) => break index,
+                _ => {}
+            }
+        }
+    };
+
+    (len > 0 && s[len..].starts_with(':')).then_some(len)
+}
+
+fn yaml_scalar_kind(scalar: &str) -> CodeTokenKind {
+    match scalar {
+        "true" | "false" | "null" | "~" => CodeTokenKind::Literal,
+        "|" | "|-" | "|+" | ">" | ">-" | ">+" => CodeTokenKind::Punctuation,
+        _ if scalar.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.'))
+            && scalar.parse::<f64>().is_ok() =>
+        {
+            CodeTokenKind::Number
+        }
+        _ => CodeTokenKind::String,
+    }
+}
+
+/// The length of the quoted string at the start of `s`, including the quotes.
+///
+/// Unterminated strings extend to the end of `s`.
+fn string_len(s: &str) -> usize {
+    let mut chars = s.char_indices();
+    let Some((_, quote)) = chars.next() else {
+        return 0;
+    };
+
+    let mut escaped = false;
+    for (index, c) in chars {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == quote => return index + c.len_utf8(),
+            _ => {}
+        }
+    }
+
+    s.len()
+}
+
+/// The length of the prefix of `s` matching the predicate.
+fn prefix_len(s: &str, f: impl Fn(char) -> bool) -> usize {
+    s.find(|c| !f(c)).unwrap_or(s.len())
+}
+
+fn push<'a>(tokens: &mut Vec<CodeToken<'a>>, text: &'a str, kind: Option<CodeTokenKind>) {
+    if !text.is_empty() {
+        tokens.push(CodeToken { text, kind });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use CodeTokenKind::*;
+
+    fn tokens(tokenizer: impl CodeTokenizer, line: &str) -> Vec<(&str, Option<CodeTokenKind>)> {
+        let tokens = tokenizer.tokenize(line);
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|t| t.text)
+                .collect::<std::string::String>(),
+            line
+        );
+        tokens.into_iter().map(|t| (t.text, t.kind)).collect()
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            tokens(JsonTokenizer, r#"  "a\"b": [1.5e3, true, "x"],"#),
+            vec![
+                ("  ", None),
+                (r#""a\"b""#, Some(Key)),
+                (":", Some(Punctuation)),
+                (" ", None),
+                ("[", Some(Punctuation)),
+                ("1.5e3", Some(Number)),
+                (",", Some(Punctuation)),
+                (" ", None),
+                ("true", Some(Literal)),
+                (",", Some(Punctuation)),
+                (" ", None),
+                (r#""x""#, Some(String)),
+                ("]", Some(Punctuation)),
+                (",", Some(Punctuation)),
+            ]
+        );
+        assert_eq!(
+            tokens(JsonTokenizer, r#""unterminated"#),
+            vec![(r#""unterminated"#, Some(String))]
+        );
+    }
+
+    #[test]
+    fn test_yaml() {
+        assert_eq!(
+            tokens(YamlTokenizer, "  - name: foo # comment"),
+            vec![
+                ("  ", None),
+                ("-", Some(Punctuation)),
+                (" ", None),
+                ("name", Some(Key)),
+                (":", Some(Punctuation)),
+                (" ", None),
+                ("foo", Some(String)),
+                (" ", None),
+                ("# comment", Some(Comment)),
+            ]
+        );
+        assert_eq!(
+            tokens(YamlTokenizer, r#""a: b": -1.5"#),
+            vec![
+                (r#""a: b""#, Some(Key)),
+                (":", Some(Punctuation)),
+                (" ", None),
+                ("-1.5", Some(Number)),
+            ]
+        );
+        assert_eq!(
+            tokens(YamlTokenizer, "url: http://example.com"),
+            vec![
+                ("url", Some(Key)),
+                (":", Some(Punctuation)),
+                (" ", None),
+                ("http://example.com", Some(String)),
+            ]
+        );
+        assert_eq!(
+            tokens(YamlTokenizer, "- ~"),
+            vec![("-", Some(Punctuation)), (" ", None), ("~", Some(Literal))]
+        );
+        assert_eq!(
+            tokens(YamlTokenizer, "# key: value"),
+            vec![("# key: value", Some(Comment))]
+        );
+        assert_eq!(
+            tokens(YamlTokenizer, "---"),
+            vec![("---", Some(Punctuation))]
+        );
+        assert_eq!(
+            tokens(YamlTokenizer, "text: |"),
+            vec![
+                ("text", Some(Key)),
+                (":", Some(Punctuation)),
+                (" ", None),
+                ("|", Some(Punctuation)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,533 @@
+//! Data list
+mod model;
+
+pub use model::*;
+
+use crate::prelude::{
+    use_reorder, AsClasses, Breakpoint, ExtendClasses, Icon, Reorder, ReorderHandle, ReorderItem,
+};
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DataListSelection {
+    #[default]
+    None,
+    /// Select a single entry by clicking on it.
+    Single,
+    /// Select any number of entries using checkboxes.
+    Multiple,
+}
+
+/// When the cells of an entry get stacked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataListGridMode {
+    /// Always stack the cells.
+    Always,
+    /// Never stack the cells.
+    Never,
+    /// Stack the cells below the breakpoint.
+    Breakpoint(Breakpoint),
+}
+
+impl Default for DataListGridMode {
+    fn default() -> Self {
+        Self::Breakpoint(Breakpoint::Medium)
+    }
+}
+
+impl AsClasses for DataListGridMode {
+    fn extend_classes(&self, classes: &mut Classes) {
+        classes.push(match self {
+            Self::Always => "pf-m-grid",
+            Self::Never | Self::Breakpoint(Breakpoint::None) => "pf-m-grid-none",
+            Self::Breakpoint(Breakpoint::Small) => "pf-m-grid-sm",
+            Self::Breakpoint(Breakpoint::Medium) => "pf-m-grid-md",
+            Self::Breakpoint(Breakpoint::Large) => "pf-m-grid-lg",
+            Self::Breakpoint(Breakpoint::XLarge) => "pf-m-grid-xl",
+            Self::Breakpoint(Breakpoint::XXLarge) => "pf-m-grid-2xl",
+        });
+    }
+}
+
+/// Properties for [`DataList`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct DataListProperties<M>
+where
+    M: DataListModel + PartialEq + 'static,
+{
+    pub entries: M,
+
+    #[prop_or(AttrValue::Static("Data list"))]
+    pub aria_label: AttrValue,
+
+    #[prop_or_default]
+    pub compact: bool,
+
+    #[prop_or_default]
+    pub grid: DataListGridMode,
+
+    #[prop_or_default]
+    pub selection: DataListSelection,
+
+    /// The keys of the selected entries.
+    #[prop_or_default]
+    pub selected: Vec<M::Key>,
+
+    /// Called with the new selection, when the user selects or deselects an entry.
+    #[prop_or_default]
+    pub onselect: Callback<Vec<M::Key>>,
+
+    /// Make the entries reorderable using drag and drop.
+    ///
+    /// Called with the [`Reorder`] to apply to the entries, once the user dropped an entry.
+    #[prop_or_default]
+    pub onreorder: Option<Callback<Reorder>>,
+
+    #[prop_or(AttrValue::Static("Details"))]
+    pub toggle_aria_label: AttrValue,
+
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Data list component
+///
+/// > A **data list** is used to display large data sets when you need a flexible layout or need
+/// > to include interactive content like charts.
+///
+/// See: <https://www.patternfly.org/components/data-list>
+///
+/// ## Properties
+///
+/// Defined by [`DataListProperties`].
+///
+/// ## Usage
+///
+/// Similar to the [`crate::prelude::Table`], the data list renders a model:
+///
+/// * Let the item type implement [`DataListEntryRenderer`].
+/// * Provide the entries as a [`DataListModel`]. A `Vec` (or `Rc<Vec<_>>`) of items is a model,
+///   keyed by the index of the items. A model can also be the current page of a larger set of
+///   entries, e.g. when using [`crate::prelude::use_pagination`].
+///
+/// Entries which have expandable content can be expanded by the user. Setting `onreorder` lets
+/// the user reorder the entries using drag and drop, see [`crate::prelude::use_reorder`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+/// use std::rc::Rc;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Server {
+///   name: String,
+///   status: String,
+/// }
+///
+/// impl DataListEntryRenderer for Server {
+///   fn render_cells(&self) -> Html {
+///     html!(
+///       <>
+///         <DataListCell>{ &self.name }</DataListCell>
+///         <DataListCell>{ &self.status }</DataListCell>
+///       </>
+///     )
+///   }
+///
+///   fn render_expansion(&self) -> Option<Html> {
+///     Some(html!(format!("Details of {}", self.name)))
+///   }
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let servers = use_memo((), |()| (0..25)
+///     .map(|n| Server { name: format!("server-{n}"), status: "Running".into() })
+///     .collect::<Vec<_>>()
+///   );
+///
+///   let total = servers.len();
+///   let pagination = use_pagination(Some(total), Default::default);
+///   let entries = use_memo((servers, pagination.state.range()), |(servers, range)| {
+///     servers[range.clone()].to_vec()
+///   });
+///
+///   let selected = use_state_eq(Vec::new);
+///   let onselect = use_callback(selected.clone(), |new, selected| selected.set(new));
+///
+///   html!(
+///     <>
+///       <SimplePagination pagination={pagination.clone()} total={total} />
+///       <DataList<Rc<Vec<Server>>>
+///         {entries}
+///         selection={DataListSelection::Multiple}
+///         selected={(*selected).clone()}
+///         {onselect}
+///       />
+///     </>
+///   )
+/// }
+/// ```
+#[function_component(DataList)]
+pub fn data_list<M>(props: &DataListProperties<M>) -> Html
+where
+    M: DataListModel + PartialEq + 'static,
+{
+    let expanded = use_state_eq(Vec::<M::Key>::new);
+    let reorder = use_reorder(
+        props.entries.len(),
+        props.onreorder.clone().unwrap_or_default(),
+    );
+    let draggable = props.onreorder.is_some();
+
+    let mut class = classes!("pf-v5-c-data-list");
+
+    if props.compact {
+        class.push(classes!("pf-m-compact"));
+    }
+
+    class.extend_from(&props.grid);
+    class.extend(props.class.clone());
+
+    // while dragging, show the entries in their new order
+    let mut entries = props.entries.iter().map(Some).collect::<Vec<_>>();
+    let order = match draggable {
+        true => reorder.order(),
+        false => (0..entries.len()).collect(),
+    };
+    let entries = order
+        .into_iter()
+        .filter_map(|index| entries.get_mut(index).and_then(Option::take));
+
+    let entries = entries.enumerate().map(|(position, (key, entry))| {
+        let is_selected = props.selected.contains(&key);
+        let is_expanded = expanded.contains(&key);
+
+        let (onclick, oncheck) = match props.selection {
+            DataListSelection::None => (None, None),
+            DataListSelection::Single => {
+                let key = key.clone();
+                let onselect = props.onselect.clone();
+                (
+                    Some(Callback::from(move |()| onselect.emit(vec![key.clone()]))),
+                    None,
+                )
+            }
+            DataListSelection::Multiple => {
+                let key = key.clone();
+                let selected = props.selected.clone();
+                let onselect = props.onselect.clone();
+                let oncheck = Callback::from(move |checked| {
+                    let mut selected = selected.clone();
+                    selected.retain(|k| k != &key);
+                    if checked {
+                        selected.push(key.clone());
+                    }
+                    onselect.emit(selected);
+                });
+                (None, Some(oncheck))
+            }
+        };
+
+        let expansion = entry.render_expansion();
+        let ontoggle = expansion.is_some().then(|| {
+            let key = key.clone();
+            let expanded = expanded.clone();
+            Callback::from(move |()| {
+                let mut new_expanded = (*expanded).clone();
+                if new_expanded.contains(&key) {
+                    new_expanded.retain(|k| k != &key);
+                } else {
+                    new_expanded.push(key.clone());
+                }
+                expanded.set(new_expanded);
+            })
+        });
+
+        html!(
+            <DataListItem
+                key={key.into()}
+                selected={is_selected}
+                {onclick}
+                {oncheck}
+                expanded={is_expanded}
+                {ontoggle}
+                {expansion}
+                actions={entry.render_actions()}
+                toggle_aria_label={&props.toggle_aria_label}
+                reorder={draggable.then(|| reorder.item(position))}
+            >
+                { entry.render_cells() }
+            </DataListItem>
+        )
+    });
+
+    html!(
+        <>
+            <ul {class} role="list" aria-label={&props.aria_label}>
+                { for entries }
+            </ul>
+            if draggable {
+                { reorder.live_region() }
+            }
+        </>
+    )
+}
+
+/// Properties for [`DataListItem`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct DataListItemProperties {
+    /// The cells of the item.
+    #[prop_or_default]
+    pub children: Html,
+
+    /// The actions of the item.
+    #[prop_or_default]
+    pub actions: Html,
+
+    /// The expandable content.
+    #[prop_or_default]
+    pub expansion: Option<Html>,
+
+    #[prop_or_default]
+    pub expanded: bool,
+
+    #[prop_or_default]
+    pub ontoggle: Option<Callback<()>>,
+
+    #[prop_or_default]
+    pub selected: bool,
+
+    /// Makes the item clickable.
+    #[prop_or_default]
+    pub onclick: Option<Callback<()>>,
+
+    /// Shows a checkbox, reporting the new state.
+    #[prop_or_default]
+    pub oncheck: Option<Callback<bool>>,
+
+    #[prop_or(AttrValue::Static("Details"))]
+    pub toggle_aria_label: AttrValue,
+
+    /// Make the item reorderable, see [`crate::prelude::use_reorder`].
+    #[prop_or_default]
+    pub reorder: Option<ReorderItem>,
+}
+
+/// An item of a [`DataList`], rendered from an entry of the model.
+#[function_component(DataListItem)]
+pub fn data_list_item(props: &DataListItemProperties) -> Html {
+    let mut class = classes!("pf-v5-c-data-list__item");
+
+    if props.expanded {
+        class.push(classes!("pf-m-expanded"));
+    }
+
+    if props.onclick.is_some() {
+        class.push(classes!("pf-m-clickable"));
+    }
+
+    if props.selected {
+        class.push(classes!("pf-m-selected"));
+    }
+
+    if props.reorder.as_ref().is_some_and(|item| item.dragging) {
+        class.push(classes!("pf-m-ghost-row"));
+    }
+
+    let onclick = props
+        .onclick
+        .as_ref()
+        .map(|onclick| onclick.reform(|_: MouseEvent| ()));
+
+    let onkeydown = props.onclick.as_ref().map(|onclick| {
+        let onclick = onclick.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            // ignore events of the controls inside the item, like buttons
+            let item = e
+                .current_target()
+                .and_then(|t| t.dyn_into::<Element>().ok());
+            let target = e.target().and_then(|t| t.dyn_into::<Element>().ok());
+            if item.is_none() || item != target {
+                return;
+            }
+            if e.key() == "Enter" || e.key() == " " {
+                e.prevent_default();
+                onclick.emit(());
+            }
+        })
+    });
+
+    let tabindex = props.onclick.as_ref().map(|_| "0");
+
+    let controls = props.ontoggle.is_some() || props.oncheck.is_some() || props.reorder.is_some();
+    let reorder = props.reorder.as_ref();
+
+    html!(
+        <li
+            {class}
+            {tabindex}
+            {onclick}
+            {onkeydown}
+            aria-selected={props.onclick.as_ref().map(|_| props.selected.to_string())}
+            draggable={reorder.map(|_| "true")}
+            data-reorder-position={reorder.map(|item| item.position.to_string())}
+            ondragstart={reorder.map(|item| item.ondragstart.clone())}
+            ondragover={reorder.map(|item| item.ondragover.clone())}
+            ondrop={reorder.map(|item| item.ondrop.clone())}
+            ondragend={reorder.map(|item| item.ondragend.clone())}
+        >
+            <div class="pf-v5-c-data-list__item-row">
+                if controls {
+                    <div class="pf-v5-c-data-list__item-control">
+                        if let Some(item) = reorder {
+                            <div class="pf-v5-c-data-list__item-draggable">
+                                <ReorderHandle item={item.clone()} />
+                            </div>
+                        }
+                        if let Some(ontoggle) = &props.ontoggle {
+                            <div class="pf-v5-c-data-list__toggle">
+                                <button
+                                    class="pf-v5-c-button pf-m-plain"
+                                    type="button"
+                                    aria-expanded={props.expanded.to_string()}
+                                    aria-label={&props.toggle_aria_label}
+                                    onclick={ontoggle.reform(|e: MouseEvent| e.stop_propagation())}
+                                >
+                                    <div class="pf-v5-c-data-list__toggle-icon">{ Icon::AngleRight }</div>
+                                </button>
+                            </div>
+                        }
+                        if let Some(oncheck) = &props.oncheck {
+                            <div class="pf-v5-c-data-list__check">
+                                <input
+                                    type="checkbox"
+                                    checked={props.selected}
+                                    onchange={oncheck.reform({
+                                        let selected = props.selected;
+                                        move |_| !selected
+                                    })}
+                                />
+                            </div>
+                        }
+                    </div>
+                }
+                <div class="pf-v5-c-data-list__item-content">
+                    { props.children.clone() }
+                </div>
+                { props.actions.clone() }
+            </div>
+            if let Some(expansion) = &props.expansion {
+                <section class="pf-v5-c-data-list__expandable-content" hidden={!props.expanded}>
+                    <div class="pf-v5-c-data-list__expandable-content-body">
+                        { expansion.clone() }
+                    </div>
+                </section>
+            }
+        </li>
+    )
+}
+
+/// The width of a [`DataListCell`], relative to the other cells.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DataListCellWidth {
+    #[default]
+    Default,
+    Flex2,
+    Flex3,
+    Flex4,
+    Flex5,
+}
+
+impl AsClasses for DataListCellWidth {
+    fn extend_classes(&self, classes: &mut Classes) {
+        match self {
+            Self::Default => {}
+            Self::Flex2 => classes.push("pf-m-flex-2"),
+            Self::Flex3 => classes.push("pf-m-flex-3"),
+            Self::Flex4 => classes.push("pf-m-flex-4"),
+            Self::Flex5 => classes.push("pf-m-flex-5"),
+        }
+    }
+}
+
+/// Properties for [`DataListCell`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct DataListCellProperties {
+    #[prop_or_default]
+    pub children: Html,
+
+    #[prop_or_default]
+    pub width: DataListCellWidth,
+
+    /// The cell contains an icon.
+    #[prop_or_default]
+    pub icon: bool,
+
+    /// Align the cell to the right.
+    #[prop_or_default]
+    pub align_right: bool,
+
+    /// Don't let the cell fill the available space.
+    #[prop_or_default]
+    pub no_fill: bool,
+
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// A cell of a [`DataListItem`].
+#[function_component(DataListCell)]
+pub fn data_list_cell(props: &DataListCellProperties) -> Html {
+    let mut class = classes!("pf-v5-c-data-list__cell");
+
+    class.extend_from(&props.width);
+
+    if props.icon {
+        class.push(classes!("pf-m-icon"));
+    }
+
+    if props.align_right {
+        class.push(classes!("pf-m-align-right"));
+    }
+
+    if props.no_fill {
+        class.push(classes!("pf-m-no-fill"));
+    }
+
+    class.extend(props.class.clone());
+
+    html!(
+        <div {class}>
+            { props.children.clone() }
+        </div>
+    )
+}
+
+/// Properties for [`DataListAction`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct DataListActionProperties {
+    #[prop_or_default]
+    pub children: Html,
+
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// The actions of a [`DataListItem`].
+///
+/// Clicking on an action doesn't select a clickable item.
+#[function_component(DataListAction)]
+pub fn data_list_action(props: &DataListActionProperties) -> Html {
+    let class = classes!("pf-v5-c-data-list__item-action", props.class.clone());
+
+    html!(
+        <div {class} onclick={|e: MouseEvent| e.stop_propagation()}>
+            { props.children.clone() }
+        </div>
+    )
+}
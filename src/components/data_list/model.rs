@@ -0,0 +1,97 @@
+use std::fmt::Debug;
+use std::iter::Enumerate;
+use std::rc::Rc;
+use yew::prelude::*;
+use yew::virtual_dom::Key;
+
+/// Render an entry of a [`super::DataList`].
+pub trait DataListEntryRenderer {
+    /// Render the cells of the entry, typically a list of [`super::DataListCell`]s.
+    fn render_cells(&self) -> Html;
+
+    /// Render the actions of the entry, typically [`super::DataListAction`]s.
+    fn render_actions(&self) -> Html {
+        html!()
+    }
+
+    /// Render the expandable content of the entry.
+    ///
+    /// Entries without expandable content can't be expanded.
+    fn render_expansion(&self) -> Option<Html> {
+        None
+    }
+}
+
+/// A model providing data for a data list.
+pub trait DataListModel {
+    type Iterator<'i>: Iterator<Item = (Self::Key, &'i Self::Item)>
+    where
+        Self: 'i;
+    type Item: DataListEntryRenderer + Clone + 'static;
+    type Key: Into<Key> + Clone + Debug + Eq + 'static;
+
+    /// Get the number of items
+    fn len(&self) -> usize;
+
+    /// Test if the model is empty
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Test if the model contains the key
+    fn contains(&self, key: &Self::Key) -> bool;
+
+    /// Iterate over all the items
+    fn iter(&self) -> Self::Iterator<'_>;
+}
+
+impl<M> DataListModel for Rc<M>
+where
+    M: DataListModel + 'static,
+{
+    type Iterator<'i> = M::Iterator<'i>;
+    type Item = M::Item;
+    type Key = M::Key;
+
+    fn len(&self) -> usize {
+        self.as_ref().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.as_ref().is_empty()
+    }
+
+    fn contains(&self, key: &Self::Key) -> bool {
+        self.as_ref().contains(key)
+    }
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.as_ref().iter()
+    }
+}
+
+/// A plain list of entries, keyed by their index.
+impl<T> DataListModel for Vec<T>
+where
+    T: DataListEntryRenderer + Clone + 'static,
+{
+    type Iterator<'i> = Enumerate<core::slice::Iter<'i, Self::Item>>;
+    type Item = T;
+    type Key = usize;
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    fn contains(&self, key: &Self::Key) -> bool {
+        *key < self.as_slice().len()
+    }
+
+    fn iter(&self) -> Self::Iterator<'_> {
+        self.as_slice().iter().enumerate()
+    }
+}
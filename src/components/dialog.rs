@@ -0,0 +1,369 @@
+//! Dialog service
+use crate::prelude::{
+    use_backdrop, use_random_id, BackdropHandle, Backdropper, Bullseye, Button, ButtonType,
+    ButtonVariant, Form, FormGroup, InitialFocus, InputState, Modal, ModalVariant, TextInput,
+    ValidationContext, ValidationResult, Validator,
+};
+use futures::channel::oneshot;
+use std::cell::RefCell;
+use std::future::Future;
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// A handle to resolve a dialog, opened by [`Dialogs::open`].
+///
+/// Resolving or cancelling the dialog will close the backdrop. If the dialog gets closed in any
+/// other way, it is treated as cancelled.
+pub struct DialogResolver<T> {
+    sender: Rc<RefCell<Option<oneshot::Sender<T>>>>,
+    /// The backdrop of the dialog, set once it was opened.
+    backdrop: Rc<RefCell<Option<BackdropHandle>>>,
+}
+
+impl<T> Clone for DialogResolver<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            backdrop: self.backdrop.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for DialogResolver<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.sender, &other.sender)
+    }
+}
+
+impl<T: 'static> DialogResolver<T> {
+    /// Close the dialog with a result.
+    pub fn resolve(&self, value: T) {
+        if let Some(sender) = self.sender.borrow_mut().take() {
+            let _ = sender.send(value);
+        }
+        self.close();
+    }
+
+    /// Close the dialog without a result.
+    pub fn cancel(&self) {
+        self.sender.borrow_mut().take();
+        self.close();
+    }
+
+    fn close(&self) {
+        if let Some(backdrop) = &*self.backdrop.borrow() {
+            backdrop.close();
+        }
+    }
+
+    /// Create a callback, resolving the dialog with the result of the function.
+    pub fn callback<IN, F>(&self, f: F) -> Callback<IN>
+    where
+        F: Fn(IN) -> T + 'static,
+    {
+        let resolver = self.clone();
+        Callback::from(move |input| resolver.resolve(f(input)))
+    }
+}
+
+/// Settings for a confirmation dialog, opened by [`Dialogs::confirm_with`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfirmDialog {
+    pub title: String,
+    pub body: Html,
+    pub variant: ModalVariant,
+    /// Use a danger styled confirm button, for destructive actions.
+    pub danger: bool,
+    pub confirm_label: String,
+    pub cancel_label: String,
+}
+
+impl Default for ConfirmDialog {
+    fn default() -> Self {
+        Self {
+            title: Default::default(),
+            body: Default::default(),
+            variant: ModalVariant::Small,
+            danger: false,
+            confirm_label: "Confirm".to_string(),
+            cancel_label: "Cancel".to_string(),
+        }
+    }
+}
+
+impl ConfirmDialog {
+    pub fn new(title: impl Into<String>, body: impl Into<Html>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Settings for a prompt dialog, opened by [`Dialogs::prompt_with`].
+#[derive(Clone, PartialEq)]
+pub struct PromptDialog {
+    pub title: String,
+    /// The label of the input field.
+    pub label: String,
+    /// The initial value of the input field.
+    pub value: String,
+    pub placeholder: Option<AttrValue>,
+    /// Validation of the value. The dialog can't be confirmed when the value has an error.
+    pub validator: Validator<String, ValidationResult>,
+    pub variant: ModalVariant,
+    pub confirm_label: String,
+    pub cancel_label: String,
+}
+
+impl Default for PromptDialog {
+    fn default() -> Self {
+        Self {
+            title: Default::default(),
+            label: Default::default(),
+            value: Default::default(),
+            placeholder: None,
+            validator: Default::default(),
+            variant: ModalVariant::Small,
+            confirm_label: "Confirm".to_string(),
+            cancel_label: "Cancel".to_string(),
+        }
+    }
+}
+
+impl PromptDialog {
+    pub fn new(
+        title: impl Into<String>,
+        validator: impl Into<Validator<String, ValidationResult>>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            validator: validator.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A service for showing modal dialogs, and awaiting their result.
+///
+/// Acquired through [`use_dialog`].
+///
+/// ## Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use patternfly_yew::prelude::*;
+/// #[function_component(View)]
+/// fn view() -> Html {
+///   let dialogs = use_dialog().expect("Must be nested under a BackdropViewer component");
+///   let onclick = Callback::from(move |_| {
+///     let dialogs = dialogs.clone();
+///     yew::platform::spawn_local(async move {
+///       let dialog = ConfirmDialog {
+///         danger: true,
+///         confirm_label: "Delete".into(),
+///         ..ConfirmDialog::new("Delete item?", html!("This can't be undone."))
+///       };
+///       if dialogs.confirm_with(dialog).await {
+///         log::info!("Deleted");
+///       }
+///     });
+///   });
+///   html!(<Button {onclick}>{ "Delete" }</Button>)
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Dialogs {
+    backdrop: Backdropper,
+}
+
+impl Dialogs {
+    /// Open a custom dialog.
+    ///
+    /// The function gets a [`DialogResolver`] and must return the content of the backdrop,
+    /// typically a [`Modal`] wrapped in a [`Bullseye`]. The future completes with the resolved
+    /// value, or [`None`] if the dialog was cancelled or closed otherwise.
+    pub fn open<T, F>(&self, f: F) -> impl Future<Output = Option<T>>
+    where
+        T: 'static,
+        F: FnOnce(DialogResolver<T>) -> Html,
+    {
+        let (sender, receiver) = oneshot::channel();
+        let resolver = DialogResolver {
+            sender: Rc::new(RefCell::new(Some(sender))),
+            backdrop: Default::default(),
+        };
+
+        let backdrop = resolver.backdrop.clone();
+        *backdrop.borrow_mut() = Some(self.backdrop.open_with_handle(f(resolver)));
+
+        async move { receiver.await.ok() }
+    }
+
+    /// Ask the user to confirm something.
+    ///
+    /// Completes with `true` if the user confirmed, `false` otherwise.
+    pub fn confirm(
+        &self,
+        title: impl Into<String>,
+        body: impl Into<Html>,
+    ) -> impl Future<Output = bool> {
+        self.confirm_with(ConfirmDialog::new(title, body))
+    }
+
+    /// Ask the user to confirm something, using the provided settings.
+    pub fn confirm_with(&self, dialog: ConfirmDialog) -> impl Future<Output = bool> {
+        let result = self.open(move |resolver: DialogResolver<bool>| {
+            let confirm_variant = match dialog.danger {
+                true => ButtonVariant::Danger,
+                false => ButtonVariant::Primary,
+            };
+
+            let footer = html!(
+                <>
+                    <Button
+                        variant={confirm_variant}
+                        label={dialog.confirm_label}
+                        onclick={resolver.callback(|_| true)}
+                    />
+                    <Button
+                        variant={ButtonVariant::Link}
+                        label={dialog.cancel_label}
+                        onclick={resolver.callback(|_| false)}
+                    />
+                </>
+            );
+
+            html!(
+                <Bullseye>
+                    <Modal
+                        title={dialog.title}
+                        variant={dialog.variant}
+                        onclose={resolver.callback(|()| false)}
+                        {footer}
+                    >
+                        { dialog.body }
+                    </Modal>
+                </Bullseye>
+            )
+        });
+
+        async move { result.await.unwrap_or_default() }
+    }
+
+    /// Ask the user to enter a value.
+    ///
+    /// Completes with the entered value if the user confirmed, [`None`] otherwise.
+    pub fn prompt(
+        &self,
+        title: impl Into<String>,
+        validator: impl Into<Validator<String, ValidationResult>>,
+    ) -> impl Future<Output = Option<String>> {
+        self.prompt_with(PromptDialog::new(title, validator))
+    }
+
+    /// Ask the user to enter a value, using the provided settings.
+    pub fn prompt_with(&self, dialog: PromptDialog) -> impl Future<Output = Option<String>> {
+        self.open(move |resolver| {
+            html!(
+                <Bullseye>
+                    <PromptDialogContent {dialog} {resolver} />
+                </Bullseye>
+            )
+        })
+    }
+}
+
+/// Interact with modal dialogs through [`Dialogs`].
+///
+/// This requires a wrapping [`crate::prelude::BackdropViewer`].
+#[hook]
+pub fn use_dialog() -> Option<Dialogs> {
+    use_backdrop().map(|backdrop| Dialogs { backdrop })
+}
+
+#[derive(Clone, PartialEq, Properties)]
+struct PromptDialogContentProperties {
+    dialog: PromptDialog,
+    resolver: DialogResolver<String>,
+}
+
+#[function_component(PromptDialogContent)]
+fn prompt_dialog_content(props: &PromptDialogContentProperties) -> Html {
+    let value = use_state_eq(|| props.dialog.value.clone());
+    let form_id = use_random_id().to_string();
+    let input_ref = use_node_ref();
+
+    let result = use_memo(
+        ((*value).clone(), props.dialog.validator.clone()),
+        |(value, validator)| {
+            validator.run(ValidationContext {
+                value: value.clone(),
+                initial: false,
+            })
+        },
+    );
+    let state = (*result).as_ref().map(|r| r.state).unwrap_or_default();
+    let valid = state != InputState::Error;
+
+    let onchange = use_callback(value.clone(), |new_value, value| value.set(new_value));
+
+    let onconfirm = use_callback(
+        ((*value).clone(), props.resolver.clone(), valid),
+        |(), (value, resolver, valid)| {
+            if *valid {
+                resolver.resolve(value.clone());
+            }
+        },
+    );
+
+    let onsubmit = use_callback(onconfirm.clone(), |e: SubmitEvent, onconfirm| {
+        e.prevent_default();
+        onconfirm.emit(());
+    });
+
+    let oncancel = use_callback(props.resolver.clone(), |(), resolver| resolver.cancel());
+
+    let footer = html!(
+        <>
+            <Button
+                variant={ButtonVariant::Primary}
+                r#type={ButtonType::Submit}
+                form={form_id.clone()}
+                label={props.dialog.confirm_label.clone()}
+                disabled={!valid}
+            />
+            <Button
+                variant={ButtonVariant::Link}
+                label={props.dialog.cancel_label.clone()}
+                onclick={oncancel.reform(|_| ())}
+            />
+        </>
+    );
+
+    html!(
+        <Modal
+            title={props.dialog.title.clone()}
+            variant={props.dialog.variant}
+            onclose={oncancel}
+            {footer}
+            initial_focus={InitialFocus::Ref(input_ref.clone())}
+        >
+            <Form id={form_id} {onsubmit}>
+                <FormGroup
+                    label={props.dialog.label.clone()}
+                    helper_text={(*result).clone().and_then(|r| r.into())}
+                >
+                    <TextInput
+                        value={(*value).clone()}
+                        placeholder={props.dialog.placeholder.clone()}
+                        {state}
+                        {onchange}
+                        r#ref={input_ref}
+                    />
+                </FormGroup>
+            </Form>
+        </Modal>
+    )
+}
@@ -30,6 +30,14 @@ pub struct DropdownProperties {
 
     #[prop_or_default]
     pub position: Position,
+
+    /// Drill into submenus, instead of showing them as flyouts.
+    #[prop_or_default]
+    pub drilldown: bool,
+
+    /// Show the favorite items in a separate group with this title, see [`Menu`].
+    #[prop_or_default]
+    pub favorites: Option<AttrValue>,
 }
 
 /// Dropdown menu component
@@ -126,6 +134,8 @@ pub fn drop_down(props: &DropdownProperties) -> Html {
                         <Menu
                             r#ref={menu_ref}
                             style={&(*style)}
+                            drilldown={props.drilldown}
+                            favorites={props.favorites.clone()}
                         >
                             { props.children.clone() }
                         </Menu>
@@ -0,0 +1,123 @@
+//! Filtering and sorting the options of a dual list selector pane.
+
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use crate::prelude::{text_content, Order};
+
+use super::DualListSelectorItemRenderer as ItemRenderer;
+
+type FilterFn<T> = dyn Fn(&T, &str) -> bool;
+
+/// A predicate, deciding if an option matches the search of a pane.
+///
+/// By default, the text of the rendered option must contain the search, ignoring the case.
+/// An empty search matches all options.
+#[derive(Clone, Default)]
+pub enum DualListSelectorFilter<T> {
+    #[default]
+    Label,
+    Custom(Rc<FilterFn<T>>),
+}
+
+impl<T: ItemRenderer> DualListSelectorFilter<T> {
+    /// Check if the option matches the search.
+    pub fn matches(&self, option: &T, search: &str) -> bool {
+        if search.is_empty() {
+            return true;
+        }
+        match self {
+            Self::Label => label(option).contains(&search.to_lowercase()),
+            Self::Custom(filter) => filter(option, search),
+        }
+    }
+}
+
+/// Filters are equal if they are both the default, or the same custom filter.
+impl<T> PartialEq for DualListSelectorFilter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Label, Self::Label) => true,
+            (Self::Custom(a), Self::Custom(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl<T> Debug for DualListSelectorFilter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Label => f.write_str("Label"),
+            Self::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+impl<F, T> From<F> for DualListSelectorFilter<T>
+where
+    F: Fn(&T, &str) -> bool + 'static,
+{
+    fn from(filter: F) -> Self {
+        Self::Custom(Rc::new(filter))
+    }
+}
+
+/// The lowercase text of the rendered option.
+fn label<T: ItemRenderer>(option: &T) -> String {
+    text_content(&option.to_html()).to_lowercase()
+}
+
+/// The indices of the options matching the search, sorted by their label if requested.
+pub(super) fn visible_options<T: ItemRenderer>(
+    options: &[T],
+    filter: &DualListSelectorFilter<T>,
+    search: &str,
+    order: Option<Order>,
+) -> Vec<usize> {
+    let mut result = options
+        .iter()
+        .enumerate()
+        .filter(|(_, option)| filter.matches(option, search))
+        .map(|(index, option)| (index, order.map(|_| label(option))))
+        .collect::<Vec<_>>();
+
+    match order {
+        Some(Order::Ascending) => result.sort_by(|(_, a), (_, b)| a.cmp(b)),
+        Some(Order::Descending) => result.sort_by(|(_, a), (_, b)| b.cmp(a)),
+        None => {}
+    }
+
+    result.into_iter().map(|(index, _)| index).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_visible_options() {
+        let options = vec!["Banana", "apple", "Cherry", "Pineapple"];
+        let filter = DualListSelectorFilter::default();
+
+        assert_eq!(
+            visible_options(&options, &filter, "", None),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            visible_options(&options, &filter, "APPLE", None),
+            vec![1, 3]
+        );
+        assert_eq!(
+            visible_options(&options, &filter, "", Some(Order::Ascending)),
+            vec![1, 0, 2, 3]
+        );
+        assert_eq!(
+            visible_options(&options, &filter, "a", Some(Order::Descending)),
+            vec![3, 0, 1]
+        );
+
+        let filter =
+            DualListSelectorFilter::from(|option: &&str, search: &str| option.starts_with(search));
+        assert_eq!(visible_options(&options, &filter, "C", None), vec![2]);
+    }
+}
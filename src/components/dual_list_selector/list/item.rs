@@ -1,4 +1,5 @@
 /// The DualListSelectorItem component
+use crate::prelude::{ReorderHandle, ReorderItem};
 use yew::prelude::*;
 
 /// Creates an individual option that can be selected and moved between the dual list selector panes.
@@ -22,6 +23,10 @@ pub struct DualListSelectorItemProps {
     /// Flag indicating if the dual list selector is in a disabled state.
     #[prop_or_default]
     pub disabled: bool,
+
+    /// Make the item reorderable, see [`crate::prelude::use_reorder`].
+    #[prop_or_default]
+    pub reorder: Option<ReorderItem>,
 }
 
 #[function_component(DualListSelectorListItem)]
@@ -37,17 +42,48 @@ pub fn list_item(props: &DualListSelectorItemProps) -> Html {
     if props.disabled {
         item_class.push("pf-m-disabled")
     }
-    html! {
-        <li class={item_class} onclick={props.onoptionselect.clone()} tabindex="-1">
-            <div class={row_class}>
-                <span class="pf-v5-c-dual-list-selector__item">
-                    <span class="pf-v5-c-dual-list-selector__item-main">
-                        <span class="pf-v5-c-dual-list-selector__item-text">
-                            { props.children.clone() }
-                        </span>
-                    </span>
+    let item = html! {
+        <span class="pf-v5-c-dual-list-selector__item">
+            <span class="pf-v5-c-dual-list-selector__item-main">
+                <span class="pf-v5-c-dual-list-selector__item-text">
+                    { props.children.clone() }
                 </span>
-            </div>
-        </li>
+            </span>
+        </span>
+    };
+    match &props.reorder {
+        Some(reorder) => {
+            item_class.push("pf-m-draggable");
+            if reorder.dragging {
+                row_class.push("pf-m-ghost-row");
+            }
+            html! {
+                <li
+                    class={item_class}
+                    onclick={props.onoptionselect.clone()}
+                    tabindex="-1"
+                    draggable="true"
+                    data-reorder-position={reorder.position.to_string()}
+                    ondragstart={reorder.ondragstart.clone()}
+                    ondragover={reorder.ondragover.clone()}
+                    ondrop={reorder.ondrop.clone()}
+                    ondragend={reorder.ondragend.clone()}
+                >
+                    <div class={row_class}>
+                        <span class="pf-v5-c-dual-list-selector__draggable">
+                            <ReorderHandle item={reorder.clone()} />
+                        </span>
+                        { item }
+                    </div>
+                </li>
+            }
+        }
+        None => html! {
+            <li class={item_class} onclick={props.onoptionselect.clone()} tabindex="-1">
+                <div class={row_class}>
+                    { item }
+                </div>
+            </li>
+        },
     }
 }
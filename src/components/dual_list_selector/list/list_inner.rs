@@ -2,6 +2,8 @@
 
 use yew::prelude::*;
 
+use crate::prelude::use_reorder;
+
 use super::{
     super::DualListSelectorItemRenderer as ItemRenderer,
     super::{OnOptionSelectArgsNoChosen, OnOptionSelectEvent},
@@ -19,31 +21,48 @@ pub struct DualListSelectorListProps {
 #[function_component(DualListSelectorList)]
 pub fn list<T: ItemRenderer>(props: &DualListSelectorListProps) -> Html {
     let context = use_context::<Context<T>>().unwrap();
+    let reorder = use_reorder(
+        context.options.len(),
+        context.onreorder.clone().unwrap_or_default(),
+    );
+    let draggable = context.onreorder.is_some() && !context.disabled && context.visible.is_none();
+    let order = match (&context.visible, draggable) {
+        (Some(visible), _) => visible.clone(),
+        (None, true) => reorder.order(),
+        (None, false) => (0..context.options.len()).collect(),
+    };
     html! {
-        <ul class="pf-v5-c-dual-list-selector__list">
-            if context.options.is_empty() {
-                { props.children.clone() }
-            } else {
-                { for context.options.iter().enumerate().map(|(key, option)| {
-                    let onoptionselect = {
-                        let onoptionselect = context.onoptionselect.clone();
-                        Callback::from(move |e: MouseEvent| {
-                            let e: OnOptionSelectEvent = e.into();
-                            let args = OnOptionSelectArgsNoChosen {
-                                event: e,
-                                index: key,
-                            };
-                            onoptionselect.emit(args)
+        <>
+            <ul class="pf-v5-c-dual-list-selector__list">
+                if context.options.is_empty() {
+                    { props.children.clone() }
+                } else {
+                    { for order.into_iter().enumerate().filter_map(|(position, key)| {
+                        let option = context.options.get(key)?;
+                        let onoptionselect = {
+                            let onoptionselect = context.onoptionselect.clone();
+                            Callback::from(move |e: MouseEvent| {
+                                let e: OnOptionSelectEvent = e.into();
+                                let args = OnOptionSelectArgsNoChosen {
+                                    event: e,
+                                    index: key,
+                                };
+                                onoptionselect.emit(args)
+                            })
+                        };
+                        let is_selected = context.selected_options.contains(&key);
+                        let reorder = draggable.then(|| reorder.item(position));
+                        Some(html_nested! {
+                            <DualListSelectorListItem key={key} {onoptionselect} {is_selected} disabled={context.disabled} {reorder}>
+                                { option.to_html() }
+                            </DualListSelectorListItem>
                         })
-                    };
-                    let is_selected = context.selected_options.contains(&key);
-                    html_nested! {
-                        <DualListSelectorListItem key={key} {onoptionselect} {is_selected} disabled={context.disabled}>
-                            { option.to_html() }
-                        </DualListSelectorListItem>
-                    }
-                })}
+                    })}
+                }
+            </ul>
+            if draggable {
+                { reorder.live_region() }
             }
-        </ul>
+        </>
     }
 }
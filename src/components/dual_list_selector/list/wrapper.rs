@@ -3,6 +3,7 @@
 use yew::prelude::*;
 
 use crate::components::dual_list_selector::OnOptionSelectArgsNoChosen;
+use crate::prelude::Reorder;
 
 use super::{super::DualListSelectorItemRenderer as ItemRenderer, DualListSelectorList};
 
@@ -27,6 +28,15 @@ pub struct DualListSelectorListWrapperProps<T: ItemRenderer> {
     #[prop_or_default]
     pub disabled: bool,
 
+    /// Callback for reordering the options. The options can only be reordered when set.
+    #[prop_or_default]
+    pub onreorder: Option<Callback<Reorder>>,
+
+    /// The indices of the options to show, in the order to show them. Shows all options when
+    /// not set.
+    #[prop_or_default]
+    pub visible: Option<Vec<usize>>,
+
     /// Anything that can be rendered inside of the list.
     #[prop_or_default]
     pub children: Children,
@@ -38,6 +48,8 @@ pub struct DualListSelectorListContext<T: ItemRenderer> {
     pub selected_options: Vec<usize>,
     pub onoptionselect: Callback<OnOptionSelectArgsNoChosen>,
     pub disabled: bool,
+    pub onreorder: Option<Callback<Reorder>>,
+    pub visible: Option<Vec<usize>>,
 }
 
 #[function_component(DualListSelectorListWrapper)]
@@ -47,6 +59,8 @@ pub fn wrapper<T: ItemRenderer>(props: &DualListSelectorListWrapperProps<T>) ->
         selected_options: props.selected_options.clone(),
         onoptionselect: props.onoptionselect.clone(),
         disabled: props.disabled,
+        onreorder: props.onreorder.clone(),
+        visible: props.visible.clone(),
     };
     html! {
         <div class={classes!["pf-v5-c-dual-list-selector__menu", props.class.clone()]} tabindex=0>
@@ -1,17 +1,21 @@
 //! The dynamic and composable [dual list selector](https://www.patternfly.org/components/dual-list-selector)
 
-use crate::{components::tooltip::TooltipProperties, icon::Icon};
+use crate::{components::tooltip::TooltipProperties, core::Order, icon::Icon, utils::Reorder};
 use yew::prelude::*;
 
 mod control;
+mod filter;
 mod item_renderer;
 mod list;
 mod pane;
+mod tree;
 
 pub use control::*;
+pub use filter::*;
 pub use item_renderer::*;
 pub use list::*;
 pub use pane::*;
+pub use tree::*;
 
 /// The inputs of the onlistchanged event. Has the corresponding mouse event of the
 /// button press, as well as the available and chosen options after the change.
@@ -134,6 +138,31 @@ pub struct DualListSelectorProps<T: DualListSelectorItemRenderer> {
     /// Optional callback fired when a dynamically built option is selected.
     #[prop_or_default]
     pub onoptionselect: Option<Callback<OnOptionSelectArgs>>,
+    /// Optional callback fired when a dynamically built chosen option was reordered.
+    ///
+    /// The chosen options can be reordered by dragging them only when this is set.
+    #[prop_or_default]
+    pub onreorder: Option<Callback<Reorder>>,
+
+    /// Show a search input in the dynamically built panes.
+    ///
+    /// The add all and remove all buttons only move the options matching the search.
+    #[prop_or_default]
+    pub searchable: bool,
+    /// The predicate matching the dynamically built options against the search of their pane.
+    #[prop_or_default]
+    pub filter: DualListSelectorFilter<T>,
+    /// Aria label of the search input of the dynamically built available options pane.
+    #[prop_or(AttrValue::Static("Search available options"))]
+    pub available_search_aria_label: AttrValue,
+    /// Aria label of the search input of the dynamically built chosen options pane.
+    #[prop_or(AttrValue::Static("Search chosen options"))]
+    pub chosen_search_aria_label: AttrValue,
+
+    /// Show a sort action in the dynamically built panes, sorting the options by their label, or
+    /// restoring their original order.
+    #[prop_or_default]
+    pub sortable: bool,
 
     /// Flag indicating if the dual list selector is in a disabled state
     #[prop_or_default]
@@ -161,6 +190,10 @@ struct State<T: DualListSelectorItemRenderer> {
     add_all: Option<Callback<(Vec<T>, Vec<T>)>>,
     remove_all: Option<Callback<(Vec<T>, Vec<T>)>>,
     remove_selected: Option<Callback<(Vec<T>, Vec<T>)>>,
+    available_search: String,
+    available_order: Option<Order>,
+    chosen_search: String,
+    chosen_order: Option<Order>,
 }
 
 impl<T: DualListSelectorItemRenderer> State<T> {
@@ -172,8 +205,15 @@ impl<T: DualListSelectorItemRenderer> State<T> {
         Self::toggle_option(&mut self.available_options_selected, index);
     }
 
-    pub fn add_all_visible(&mut self, e: MouseEvent) {
-        Self::move_all(
+    pub fn add_all_visible(&mut self, e: MouseEvent, filter: &DualListSelectorFilter<T>) {
+        let visible = visible_options(
+            &self.available_options,
+            filter,
+            &self.available_search,
+            None,
+        );
+        Self::move_options(
+            &visible,
             &mut self.available_options_selected,
             &mut self.available_options,
             &mut self.chosen_options,
@@ -202,8 +242,10 @@ impl<T: DualListSelectorItemRenderer> State<T> {
         self.emit_callback(&self.remove_selected);
     }
 
-    pub fn remove_all_visible(&mut self, e: MouseEvent) {
-        Self::move_all(
+    pub fn remove_all_visible(&mut self, e: MouseEvent, filter: &DualListSelectorFilter<T>) {
+        let visible = visible_options(&self.chosen_options, filter, &self.chosen_search, None);
+        Self::move_options(
+            &visible,
             &mut self.chosen_options_selected,
             &mut self.chosen_options,
             &mut self.available_options,
@@ -212,10 +254,34 @@ impl<T: DualListSelectorItemRenderer> State<T> {
         self.emit_callback(&self.remove_all);
     }
 
-    fn move_all(src_selected: &mut Vec<usize>, src_options: &mut Vec<T>, dst_options: &mut Vec<T>) {
-        dst_options.extend_from_slice(src_options);
-        src_options.clear();
-        src_selected.clear();
+    pub fn reorder_chosen(&mut self, reorder: Reorder) {
+        reorder.apply(&mut self.chosen_options);
+        for index in &mut self.chosen_options_selected {
+            *index = reorder.position(*index);
+        }
+    }
+
+    /// Move the options at the indices, keeping the selection of the remaining ones.
+    fn move_options(
+        indices: &[usize],
+        src_selected: &mut Vec<usize>,
+        src_options: &mut Vec<T>,
+        dst_options: &mut Vec<T>,
+    ) {
+        let mut remaining = Vec::with_capacity(src_options.len());
+        let mut selected = Vec::new();
+        for (index, option) in src_options.drain(..).enumerate() {
+            if indices.contains(&index) {
+                dst_options.push(option);
+            } else {
+                if src_selected.contains(&index) {
+                    selected.push(remaining.len());
+                }
+                remaining.push(option);
+            }
+        }
+        *src_options = remaining;
+        *src_selected = selected;
     }
 
     fn move_selected(
@@ -274,6 +340,10 @@ pub fn dual_list_selector<T: DualListSelectorItemRenderer>(
         available_options_selected: Vec::new(),
         chosen_options: props.chosen.clone(),
         chosen_options_selected: Vec::new(),
+        available_search: String::new(),
+        available_order: None,
+        chosen_search: String::new(),
+        chosen_order: None,
     });
     let onoptionselect = {
         let state = state.clone();
@@ -292,9 +362,18 @@ pub fn dual_list_selector<T: DualListSelectorItemRenderer>(
             }
         })
     };
+    let onreorder = props.onreorder.clone().map(|onreorder| {
+        let state = state.clone();
+        Callback::from(move |reorder: Reorder| {
+            let mut new_state = (*state).clone();
+            new_state.reorder_chosen(reorder);
+            state.set(new_state);
+            onreorder.emit(reorder);
+        })
+    });
     let available_options_status = props.available_options_status.clone().unwrap_or_else(|| {
         format!(
-            "{} of {} item selected",
+            "{} of {} options selected",
             state.available_options_selected.len(),
             state.available_options.len()
         )
@@ -302,17 +381,44 @@ pub fn dual_list_selector<T: DualListSelectorItemRenderer>(
     });
     let chosen_options_status = props.chosen_options_status.clone().unwrap_or_else(|| {
         format!(
-            "{} of {} item selected",
+            "{} of {} options selected",
             state.chosen_options_selected.len(),
             state.chosen_options.len()
         )
         .into()
     });
-    let control_option = |f: fn(&mut State<T>, MouseEvent)| {
+    let onsearch = |chosen: bool| {
+        props.searchable.then(|| {
+            let state = state.clone();
+            Callback::from(move |search: String| {
+                let mut new_state = (*state).clone();
+                match chosen {
+                    true => new_state.chosen_search = search,
+                    false => new_state.available_search = search,
+                }
+                state.set(new_state);
+            })
+        })
+    };
+    let onsort = |chosen: bool| {
+        props.sortable.then(|| {
+            let state = state.clone();
+            Callback::from(move |order: Option<Order>| {
+                let mut new_state = (*state).clone();
+                match chosen {
+                    true => new_state.chosen_order = order,
+                    false => new_state.available_order = order,
+                }
+                state.set(new_state);
+            })
+        })
+    };
+    let control_option = |f: fn(&mut State<T>, MouseEvent, &DualListSelectorFilter<T>)| {
         let state = state.clone();
+        let filter = props.filter.clone();
         Callback::from(move |e| {
             let mut new_state = (*state).clone();
-            f(&mut new_state, e);
+            f(&mut new_state, e, &filter);
             state.set(new_state);
         })
     };
@@ -331,12 +437,18 @@ pub fn dual_list_selector<T: DualListSelectorItemRenderer>(
                 }
                 selected_options={state.available_options_selected.clone()}
                 disabled={props.disabled}
+                search={state.available_search.clone()}
+                onsearch={onsearch(false)}
+                filter={props.filter.clone()}
+                search_aria_label={props.available_search_aria_label.clone()}
+                order={state.available_order}
+                onsort={onsort(false)}
             />
             <DualListSelectorControlsWrapper>
                 <DualListSelectorControl
                     tooltip={props.add_selected_tooltip.clone()}
                     disabled={props.disabled}
-                    onclick={control_option(State::add_selected)}
+                    onclick={control_option(|state, e, _| state.add_selected(e))}
                     tooltip_props={props.add_selected_tooltip_props.clone()}
                 >
                     { Icon::AngleRight.with_style("width:1em;display:block;") }
@@ -360,7 +472,7 @@ pub fn dual_list_selector<T: DualListSelectorItemRenderer>(
                 <DualListSelectorControl
                     tooltip={props.remove_selected_tooltip.clone()}
                     disabled={props.disabled}
-                    onclick={control_option(State::remove_selected)}
+                    onclick={control_option(|state, e, _| state.remove_selected(e))}
                     tooltip_props={props.remove_selected_tooltip_props.clone()}
                 >
                     { Icon::AngleLeft.with_style("width:1em;display:block;") }
@@ -377,8 +489,33 @@ pub fn dual_list_selector<T: DualListSelectorItemRenderer>(
                 }
                 selected_options={state.chosen_options_selected.clone()}
                 disabled={props.disabled}
+                {onreorder}
+                search={state.chosen_search.clone()}
+                onsearch={onsearch(true)}
+                filter={props.filter.clone()}
+                search_aria_label={props.chosen_search_aria_label.clone()}
+                order={state.chosen_order}
+                onsort={onsort(true)}
             />
         }
       </div>
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_move_options() {
+        let mut src = vec!["a", "b", "c", "d"];
+        let mut selected = vec![3, 1];
+        let mut dst = vec!["x"];
+
+        State::move_options(&[0, 1], &mut selected, &mut src, &mut dst);
+
+        assert_eq!(src, vec!["c", "d"]);
+        assert_eq!(selected, vec![1]);
+        assert_eq!(dst, vec!["x", "a", "b"]);
+    }
+}
@@ -2,7 +2,12 @@
 
 use yew::prelude::*;
 
-use super::{DualListSelectorItemRenderer, DualListSelectorList, DualListSelectorListWrapper};
+use crate::prelude::{Button, ButtonVariant, Icon, Order, Reorder, SearchInput};
+
+use super::{
+    visible_options, DualListSelectorFilter, DualListSelectorItemRenderer, DualListSelectorList,
+    DualListSelectorListWrapper,
+};
 
 /// Acts as the container for a list of options that are either available or chosen,
 /// depending on the pane type (available or chosen).
@@ -39,6 +44,34 @@ pub struct DualListSelectorPaneProps<T: DualListSelectorItemRenderer> {
     #[prop_or_default]
     pub disabled: bool,
 
+    /// Callback for reordering the options. The options can only be reordered when set, and
+    /// while they are neither filtered nor sorted.
+    #[prop_or_default]
+    pub onreorder: Option<Callback<Reorder>>,
+
+    /// The current search, filtering the options.
+    #[prop_or_default]
+    pub search: String,
+    /// Callback for when the search changes. The search input is only shown when set.
+    #[prop_or_default]
+    pub onsearch: Option<Callback<String>>,
+    /// The predicate matching the options against the search.
+    #[prop_or_default]
+    pub filter: DualListSelectorFilter<T>,
+    #[prop_or(AttrValue::Static("Search"))]
+    pub search_aria_label: AttrValue,
+
+    /// The order of the options, sorted by their label.
+    #[prop_or_default]
+    pub order: Option<Order>,
+    /// Callback for when the user changes the order. The sort action is only shown when set.
+    ///
+    /// The sort action cycles through ascending, descending and the original order ([`None`]).
+    #[prop_or_default]
+    pub onsort: Option<Callback<Option<Order>>>,
+    #[prop_or(AttrValue::Static("Sort"))]
+    pub sort_aria_label: AttrValue,
+
     /// A dual list selector list to be rendered in the pane.
     #[prop_or_default]
     pub children: ChildrenWithProps<DualListSelectorList<T>>,
@@ -52,40 +85,116 @@ pub fn pane<T: DualListSelectorItemRenderer>(props: &DualListSelectorPaneProps<T
     } else {
         class.push("pf-m-available")
     }
-    let title = match &props.title {
-        None => html! {},
-        Some(title) => html! {
-            <div class={classes!["pf-v5-c-dual-list-selector__header"]}>
-                <div class={classes!["pf-v5-c-dual-list-selector__title"]}>
-                    <div class={classes!["pf-v5-c-dual-list-selector__title-text"]}>
-                        { title }
-                    </div>
-                </div>
-            </div>
+
+    let filter = match &props.onsearch {
+        Some(onsearch) => html! {
+            <SearchInput
+                value={props.search.clone()}
+                aria_label={&props.search_aria_label}
+                disabled={props.disabled}
+                onchange={onsearch.clone()}
+                onclear={onsearch.reform(|_| String::new())}
+            />
         },
+        None => html!(),
     };
-    let status = match &props.status {
-        None => html! {},
-        Some(status) => html! {
-            <div class="pf-v5-c-dual-list-selector__status">
-                <div class="pf-v5-c-dual-list-selector__status-text" id="dual-list-selector-basic-available-pane-status">
-                    { status }
-                </div>
-            </div>
-        },
+    let actions = match &props.onsort {
+        Some(onsort) => {
+            let order = props.order;
+            let onclick = onsort.reform(move |_| match order {
+                None => Some(Order::Ascending),
+                Some(Order::Ascending) => Some(Order::Descending),
+                Some(Order::Descending) => None,
+            });
+            let icon = match order {
+                Some(Order::Descending) => Icon::SortAmountDown,
+                _ => Icon::SortAmountDownAlt,
+            };
+            html! {
+                <Button
+                    variant={ButtonVariant::Plain}
+                    aria_label={props.sort_aria_label.clone()}
+                    disabled={props.disabled}
+                    {icon}
+                    {onclick}
+                />
+            }
+        }
+        None => html!(),
     };
+
+    // keep the options in their own order, unless they need to be filtered or sorted
+    let visible = (!props.search.is_empty() || props.order.is_some())
+        .then(|| visible_options(&props.options, &props.filter, &props.search, props.order));
+
     html! {
         <div {class}>
-            { title }
-            { status }
+            { header(props.title.as_ref(), tools(filter, actions)) }
+            { status(props.status.as_ref()) }
             <DualListSelectorListWrapper<T>
                 options={props.options.clone()}
                 selected_options={props.selected_options.clone()}
                 onoptionselect={props.onoptionselect.clone()}
                 disabled={props.disabled}
+                onreorder={props.onreorder.clone()}
+                {visible}
             >
                 { for props.children.iter() }
             </DualListSelectorListWrapper<T>>
         </div>
     }
 }
+
+/// The header of a pane, with its title and tools like a search input.
+pub(super) fn header(title: Option<&AttrValue>, tools: Html) -> Html {
+    if title.is_none() && tools == html!() {
+        return html!();
+    }
+    html! {
+        <div class="pf-v5-c-dual-list-selector__header">
+            if let Some(title) = title {
+                <div class="pf-v5-c-dual-list-selector__title">
+                    <div class="pf-v5-c-dual-list-selector__title-text">
+                        { title }
+                    </div>
+                </div>
+            }
+            { tools }
+        </div>
+    }
+}
+
+/// The tools of a pane, a search input and actions.
+pub(super) fn tools(filter: Html, actions: Html) -> Html {
+    if filter == html!() && actions == html!() {
+        return html!();
+    }
+    html! {
+        <div class="pf-v5-c-dual-list-selector__tools">
+            if filter != html!() {
+                <div class="pf-v5-c-dual-list-selector__tools-filter">
+                    { filter }
+                </div>
+            }
+            if actions != html!() {
+                <div class="pf-v5-c-dual-list-selector__tools-actions">
+                    { actions }
+                </div>
+            }
+        </div>
+    }
+}
+
+/// The status of a pane, shown above its options.
+pub(super) fn status(status: Option<&AttrValue>) -> Html {
+    match status {
+        None => html! {},
+        Some(status) => html! {
+            <div class="pf-v5-c-dual-list-selector__status">
+                <div class="pf-v5-c-dual-list-selector__status-text">
+                    { status }
+                </div>
+            </div>
+        },
+    }
+}
@@ -0,0 +1,631 @@
+//! The tree variant of the dual list selector.
+
+use std::collections::BTreeSet;
+
+use yew::prelude::*;
+
+use crate::prelude::{Badge, Checkbox, CheckboxState, Icon, SearchInput};
+
+use super::{
+    pane, DualListSelectorControl, DualListSelectorControlsWrapper, DualListSelectorFilter,
+    DualListSelectorItemRenderer as ItemRenderer, DualListSelectorOnListChangedInputs,
+};
+
+/// The position of a node in the tree, the indices of the node and its ancestors.
+type Path = Vec<usize>;
+
+/// A node of a [`DualListSelectorTree`].
+///
+/// A node without children is a leaf, which can be chosen. Nodes with children are folders,
+/// shown in a pane as long as one of their leaves is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DualListSelectorTreeNode<T> {
+    pub item: T,
+    pub children: Vec<DualListSelectorTreeNode<T>>,
+}
+
+impl<T> DualListSelectorTreeNode<T> {
+    /// Create a leaf node.
+    pub fn leaf(item: T) -> Self {
+        Self {
+            item,
+            children: vec![],
+        }
+    }
+
+    /// Create a folder node.
+    pub fn folder(item: T, children: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            item,
+            children: children.into_iter().collect(),
+        }
+    }
+
+    fn is_folder(&self) -> bool {
+        !self.children.is_empty()
+    }
+}
+
+/// Properties for [`DualListSelectorTree`]
+#[derive(Debug, Clone, PartialEq, Properties)]
+pub struct DualListSelectorTreeProps<T: ItemRenderer> {
+    /// Additional classes applied to the dual list selector.
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// The complete tree of options.
+    pub options: Vec<DualListSelectorTreeNode<T>>,
+    /// The leaves which are initially chosen.
+    #[prop_or_default]
+    pub chosen: Vec<T>,
+
+    /// Title applied to the available options pane.
+    #[prop_or_default]
+    pub available_options_title: Option<AttrValue>,
+    /// Title applied to the chosen options pane.
+    #[prop_or_default]
+    pub chosen_options_title: Option<AttrValue>,
+
+    /// Show a search input in each pane.
+    ///
+    /// Leaves and folders matching the search are shown with all their ancestors.
+    #[prop_or_default]
+    pub searchable: bool,
+    /// The predicate matching the nodes against the search of their pane.
+    #[prop_or_default]
+    pub filter: DualListSelectorFilter<T>,
+    /// Aria label of the search input of the available options pane.
+    #[prop_or(AttrValue::Static("Search available options"))]
+    pub available_search_aria_label: AttrValue,
+    /// Aria label of the search input of the chosen options pane.
+    #[prop_or(AttrValue::Static("Search chosen options"))]
+    pub chosen_search_aria_label: AttrValue,
+
+    /// Tooltip content for the add selected button.
+    #[prop_or_default]
+    pub add_selected_tooltip: Option<AttrValue>,
+    /// Tooltip content for the add all button.
+    #[prop_or_default]
+    pub add_all_available_tooltip: Option<AttrValue>,
+    /// Tooltip content for the remove all button.
+    #[prop_or_default]
+    pub remove_all_chosen_tooltip: Option<AttrValue>,
+    /// Tooltip content for the remove selected button.
+    #[prop_or_default]
+    pub remove_selected_tooltip: Option<AttrValue>,
+
+    /// Callback fired every time leaves are chosen or removed.
+    /// Inputs are the mouse event as well as the available and chosen leaves after the change.
+    #[prop_or_default]
+    pub onlistchange: Option<Callback<DualListSelectorOnListChangedInputs<T>>>,
+
+    /// Flag indicating if the dual list selector is in a disabled state
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// A dual list selector for a tree of options.
+///
+/// Leaves are selected using checkboxes. Checking a folder selects all of its leaves shown in
+/// the pane, a folder with some of its leaves selected shows an indeterminate checkbox.
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let options = vec![
+///     DualListSelectorTreeNode::folder("Fruits", [
+///       DualListSelectorTreeNode::leaf("Apple"),
+///       DualListSelectorTreeNode::leaf("Banana"),
+///     ]),
+///     DualListSelectorTreeNode::leaf("Bread"),
+///   ];
+///
+///   html!(
+///     <DualListSelectorTree<&'static str>
+///       {options}
+///       chosen={vec!["Bread"]}
+///       available_options_title="Available"
+///       chosen_options_title="Chosen"
+///       searchable=true
+///     />
+///   )
+/// }
+/// ```
+#[function_component(DualListSelectorTree)]
+pub fn dual_list_selector_tree<T: ItemRenderer>(props: &DualListSelectorTreeProps<T>) -> Html {
+    let chosen = use_state_eq(|| {
+        leaves(&props.options)
+            .into_iter()
+            .filter(|(_, item)| props.chosen.contains(item))
+            .map(|(path, _)| path)
+            .collect::<BTreeSet<_>>()
+    });
+    let available_selected = use_state_eq(BTreeSet::<Path>::new);
+    let chosen_selected = use_state_eq(BTreeSet::<Path>::new);
+    let available_search = use_state_eq(String::new);
+    let chosen_search = use_state_eq(String::new);
+
+    let (chosen_leaves, available_leaves): (BTreeSet<_>, BTreeSet<_>) = leaves(&props.options)
+        .into_iter()
+        .map(|(path, _)| path)
+        .partition(|path| chosen.contains(path));
+
+    let control = |choose: bool, all: bool| {
+        let options = props.options.clone();
+        let filter = props.filter.clone();
+        let onlistchange = props.onlistchange.clone();
+        let chosen = chosen.clone();
+        let (selected, search, pane) = match choose {
+            true => (
+                available_selected.clone(),
+                available_search.clone(),
+                available_leaves.clone(),
+            ),
+            false => (
+                chosen_selected.clone(),
+                chosen_search.clone(),
+                chosen_leaves.clone(),
+            ),
+        };
+        Callback::from(move |e: MouseEvent| {
+            let moved = match all {
+                true => visible_leaves(&options, &pane, &filter, &search),
+                false => (*selected).clone(),
+            };
+            let mut new_chosen = (*chosen).clone();
+            for path in &moved {
+                match choose {
+                    true => new_chosen.insert(path.clone()),
+                    false => new_chosen.remove(path),
+                };
+            }
+            selected.set(&*selected - &moved);
+
+            if let Some(onlistchange) = &onlistchange {
+                let (chosen_items, available_items): (Vec<_>, Vec<_>) = leaves(&options)
+                    .into_iter()
+                    .partition(|(path, _)| new_chosen.contains(path));
+                let items = |items: Vec<(Path, &T)>| {
+                    items.into_iter().map(|(_, item)| item.clone()).collect()
+                };
+                onlistchange.emit((e, items(available_items), items(chosen_items)));
+            }
+            chosen.set(new_chosen);
+        })
+    };
+
+    let status = |selected: &BTreeSet<Path>, leaves: &BTreeSet<Path>| -> AttrValue {
+        format!("{} of {} options selected", selected.len(), leaves.len()).into()
+    };
+
+    let onsearch = |search: &UseStateHandle<String>| {
+        props.searchable.then(|| {
+            let search = search.clone();
+            Callback::from(move |value| search.set(value))
+        })
+    };
+
+    html! {
+        <div class={classes!["pf-v5-c-dual-list-selector", props.class.clone()]}>
+            <TreePane<T>
+                title={props.available_options_title.clone()}
+                status={status(&available_selected, &available_leaves)}
+                options={props.options.clone()}
+                leaves={available_leaves.clone()}
+                selected={(*available_selected).clone()}
+                onselect={let selected = available_selected.clone(); Callback::from(move |value| selected.set(value))}
+                search={(*available_search).clone()}
+                onsearch={onsearch(&available_search)}
+                filter={props.filter.clone()}
+                search_aria_label={props.available_search_aria_label.clone()}
+                disabled={props.disabled}
+            />
+            <DualListSelectorControlsWrapper>
+                <DualListSelectorControl
+                    tooltip={props.add_selected_tooltip.clone()}
+                    disabled={props.disabled || available_selected.is_empty()}
+                    onclick={control(true, false)}
+                >
+                    { Icon::AngleRight.with_style("width:1em;display:block;") }
+                </DualListSelectorControl>
+                <DualListSelectorControl
+                    tooltip={props.add_all_available_tooltip.clone()}
+                    disabled={props.disabled || available_leaves.is_empty()}
+                    onclick={control(true, true)}
+                >
+                    { Icon::AngleDoubleRight.with_style("width:1em;display:block;") }
+                </DualListSelectorControl>
+                <DualListSelectorControl
+                    tooltip={props.remove_all_chosen_tooltip.clone()}
+                    disabled={props.disabled || chosen_leaves.is_empty()}
+                    onclick={control(false, true)}
+                >
+                    { Icon::AngleDoubleLeft.with_style("width:1em;display:block;") }
+                </DualListSelectorControl>
+                <DualListSelectorControl
+                    tooltip={props.remove_selected_tooltip.clone()}
+                    disabled={props.disabled || chosen_selected.is_empty()}
+                    onclick={control(false, false)}
+                >
+                    { Icon::AngleLeft.with_style("width:1em;display:block;") }
+                </DualListSelectorControl>
+            </DualListSelectorControlsWrapper>
+            <TreePane<T>
+                is_chosen=true
+                title={props.chosen_options_title.clone()}
+                status={status(&chosen_selected, &chosen_leaves)}
+                options={props.options.clone()}
+                leaves={chosen_leaves.clone()}
+                selected={(*chosen_selected).clone()}
+                onselect={let selected = chosen_selected.clone(); Callback::from(move |value| selected.set(value))}
+                search={(*chosen_search).clone()}
+                onsearch={onsearch(&chosen_search)}
+                filter={props.filter.clone()}
+                search_aria_label={props.chosen_search_aria_label.clone()}
+                disabled={props.disabled}
+            />
+        </div>
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Properties)]
+struct TreePaneProperties<T: ItemRenderer> {
+    title: Option<AttrValue>,
+    status: AttrValue,
+    options: Vec<DualListSelectorTreeNode<T>>,
+    /// The leaves shown in the pane
+    leaves: BTreeSet<Path>,
+    selected: BTreeSet<Path>,
+    onselect: Callback<BTreeSet<Path>>,
+    search: String,
+    /// Show a search input, if set
+    onsearch: Option<Callback<String>>,
+    filter: DualListSelectorFilter<T>,
+    search_aria_label: AttrValue,
+    #[prop_or_default]
+    is_chosen: bool,
+    disabled: bool,
+}
+
+#[function_component(TreePane)]
+fn tree_pane<T: ItemRenderer>(props: &TreePaneProperties<T>) -> Html {
+    let expanded = use_state_eq(BTreeSet::<Path>::new);
+
+    let class = match props.is_chosen {
+        true => classes!["pf-v5-c-dual-list-selector__pane", "pf-m-chosen"],
+        false => classes!["pf-v5-c-dual-list-selector__pane", "pf-m-available"],
+    };
+
+    let filter = match &props.onsearch {
+        Some(onsearch) => html! {
+            <SearchInput
+                value={props.search.clone()}
+                aria_label={&props.search_aria_label}
+                disabled={props.disabled}
+                onchange={onsearch.clone()}
+                onclear={onsearch.reform(|_| String::new())}
+            />
+        },
+        None => html!(),
+    };
+
+    let tree = Tree {
+        props,
+        expanded: &expanded,
+    };
+
+    html! {
+        <div {class}>
+            { pane::header(props.title.as_ref(), pane::tools(filter, html!())) }
+            { pane::status(Some(&props.status)) }
+            <div class="pf-v5-c-dual-list-selector__menu" tabindex=0>
+                <ul class="pf-v5-c-dual-list-selector__list" role="tree">
+                    { tree.render_nodes(&props.options, &vec![], false) }
+                </ul>
+            </div>
+        </div>
+    }
+}
+
+/// Renders the nodes of a [`TreePane`].
+struct Tree<'a, T: ItemRenderer> {
+    props: &'a TreePaneProperties<T>,
+    expanded: &'a UseStateHandle<BTreeSet<Path>>,
+}
+
+impl<T: ItemRenderer> Tree<'_, T> {
+    fn render_nodes(
+        &self,
+        nodes: &[DualListSelectorTreeNode<T>],
+        parent: &Path,
+        matched: bool,
+    ) -> Html {
+        html! {
+            { for nodes.iter().enumerate().filter_map(|(index, node)| {
+                let mut path = parent.clone();
+                path.push(index);
+                self.render_node(node, path, matched)
+            }) }
+        }
+    }
+
+    fn render_node(
+        &self,
+        node: &DualListSelectorTreeNode<T>,
+        path: Path,
+        matched: bool,
+    ) -> Option<Html> {
+        let props = self.props;
+
+        let mut leaves = vec![];
+        collect_visible(
+            node,
+            &path,
+            &props.leaves,
+            &props.filter,
+            &props.search,
+            matched,
+            &mut leaves,
+        );
+        if leaves.is_empty() {
+            return None;
+        }
+
+        let count = leaves.len();
+        let checked = match leaves
+            .iter()
+            .filter(|path| props.selected.contains(*path))
+            .count()
+        {
+            0 => CheckboxState::Unchecked,
+            n if n == count => CheckboxState::Checked,
+            _ => CheckboxState::Indeterminate,
+        };
+
+        let onselect = {
+            let onselect = props.onselect.clone();
+            let selected = props.selected.clone();
+            Callback::from(move |_| {
+                let mut selected = selected.clone();
+                for path in &leaves {
+                    if checked == CheckboxState::Checked {
+                        selected.remove(path);
+                    } else {
+                        selected.insert(path.clone());
+                    }
+                }
+                onselect.emit(selected);
+            })
+        };
+
+        let mut row_class = classes!["pf-v5-c-dual-list-selector__list-item-row"];
+        if checked == CheckboxState::Checked {
+            row_class.push("pf-m-selected");
+        }
+        let mut item_class = classes!["pf-v5-c-dual-list-selector__list-item"];
+        if props.disabled {
+            item_class.push("pf-m-disabled");
+        }
+
+        let check = html! {
+            // the row handles clicks on the checkbox too
+            <span class="pf-v5-c-dual-list-selector__item-check" onclick={|e: MouseEvent| e.stop_propagation()}>
+                <Checkbox
+                    {checked}
+                    disabled={props.disabled}
+                    onchange={onselect.reform(|_| ())}
+                />
+            </span>
+        };
+        let text = html! {
+            <span class="pf-v5-c-dual-list-selector__item-main">
+                <span class="pf-v5-c-dual-list-selector__item-text">
+                    { node.item.to_html() }
+                </span>
+            </span>
+        };
+        let onclick = (!props.disabled).then(|| onselect.reform(|_: MouseEvent| ()));
+
+        if !node.is_folder() {
+            return Some(html! {
+                <li class={item_class} role="treeitem" aria-selected={(checked == CheckboxState::Checked).to_string()} tabindex="-1">
+                    <div class={row_class} {onclick}>
+                        <div class="pf-v5-c-dual-list-selector__item">
+                            { check }
+                            { text }
+                        </div>
+                    </div>
+                </li>
+            });
+        }
+
+        // while searching, folders are expanded to show the matches
+        let expanded = !props.search.is_empty() || self.expanded.contains(&path);
+        item_class.push("pf-m-expandable");
+        if expanded {
+            item_class.push("pf-m-expanded");
+        }
+
+        let ontoggle = {
+            let state = self.expanded.clone();
+            let path = path.clone();
+            Callback::from(move |e: MouseEvent| {
+                e.stop_propagation();
+                let mut expanded = (*state).clone();
+                if !expanded.remove(&path) {
+                    expanded.insert(path.clone());
+                }
+                state.set(expanded);
+            })
+        };
+
+        let matched = matched || props.filter.matches(&node.item, &props.search);
+
+        Some(html! {
+            <li class={item_class} role="treeitem" aria-expanded={expanded.to_string()} tabindex="-1">
+                <div class={row_class} {onclick}>
+                    <div class="pf-v5-c-dual-list-selector__item">
+                        <span class="pf-v5-c-dual-list-selector__item-toggle">
+                            <button
+                                class="pf-v5-c-button pf-m-plain"
+                                type="button"
+                                aria-label="Toggle"
+                                onclick={ontoggle}
+                            >
+                                <span class="pf-v5-c-dual-list-selector__item-toggle-icon">
+                                    { Icon::AngleRight }
+                                </span>
+                            </button>
+                        </span>
+                        { check }
+                        { text }
+                        <span class="pf-v5-c-dual-list-selector__item-count">
+                            <Badge read=true>{ count }</Badge>
+                        </span>
+                    </div>
+                </div>
+                if expanded {
+                    <ul class="pf-v5-c-dual-list-selector__list" role="group">
+                        { self.render_nodes(&node.children, &path, matched) }
+                    </ul>
+                }
+            </li>
+        })
+    }
+}
+
+/// All leaves of the tree, in tree order.
+fn leaves<T>(nodes: &[DualListSelectorTreeNode<T>]) -> Vec<(Path, &T)> {
+    fn collect<'t, T>(
+        nodes: &'t [DualListSelectorTreeNode<T>],
+        parent: &Path,
+        result: &mut Vec<(Path, &'t T)>,
+    ) {
+        for (index, node) in nodes.iter().enumerate() {
+            let mut path = parent.clone();
+            path.push(index);
+            if node.is_folder() {
+                collect(&node.children, &path, result);
+            } else {
+                result.push((path, &node.item));
+            }
+        }
+    }
+
+    let mut result = vec![];
+    collect(nodes, &vec![], &mut result);
+    result
+}
+
+/// Collect the leaves of a node which are shown in a pane, in tree order.
+///
+/// A leaf is shown when it is part of the pane and it, or one of its ancestors, matches the
+/// search. `matched` tells if an ancestor of the node matched.
+fn collect_visible<T: ItemRenderer>(
+    node: &DualListSelectorTreeNode<T>,
+    path: &Path,
+    pane: &BTreeSet<Path>,
+    filter: &DualListSelectorFilter<T>,
+    search: &str,
+    matched: bool,
+    result: &mut Vec<Path>,
+) {
+    let matched = matched || filter.matches(&node.item, search);
+    if !node.is_folder() {
+        if matched && pane.contains(path) {
+            result.push(path.clone());
+        }
+        return;
+    }
+    for (index, child) in node.children.iter().enumerate() {
+        let mut path = path.clone();
+        path.push(index);
+        collect_visible(child, &path, pane, filter, search, matched, result);
+    }
+}
+
+/// The leaves of all nodes which are shown in a pane.
+fn visible_leaves<T: ItemRenderer>(
+    nodes: &[DualListSelectorTreeNode<T>],
+    pane: &BTreeSet<Path>,
+    filter: &DualListSelectorFilter<T>,
+    search: &str,
+) -> BTreeSet<Path> {
+    let mut result = vec![];
+    for (index, node) in nodes.iter().enumerate() {
+        collect_visible(node, &vec![index], pane, filter, search, false, &mut result);
+    }
+    result.into_iter().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tree() -> Vec<DualListSelectorTreeNode<&'static str>> {
+        vec![
+            DualListSelectorTreeNode::folder(
+                "Fruits",
+                [
+                    DualListSelectorTreeNode::leaf("Apple"),
+                    DualListSelectorTreeNode::leaf("Banana"),
+                ],
+            ),
+            DualListSelectorTreeNode::folder(
+                "Vegetables",
+                [DualListSelectorTreeNode::folder(
+                    "Roots",
+                    [DualListSelectorTreeNode::leaf("Carrot")],
+                )],
+            ),
+            DualListSelectorTreeNode::leaf("Bread"),
+        ]
+    }
+
+    #[test]
+    fn test_leaves() {
+        let tree = tree();
+        assert_eq!(
+            leaves(&tree),
+            vec![
+                (vec![0, 0], &"Apple"),
+                (vec![0, 1], &"Banana"),
+                (vec![1, 0, 0], &"Carrot"),
+                (vec![2], &"Bread"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_visible_leaves() {
+        let tree = tree();
+        let filter = DualListSelectorFilter::default();
+        let all = leaves(&tree)
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<BTreeSet<_>>();
+
+        assert_eq!(visible_leaves(&tree, &all, &filter, ""), all);
+        // matching leaves
+        assert_eq!(
+            visible_leaves(&tree, &all, &filter, "an"),
+            BTreeSet::from([vec![0, 1]])
+        );
+        // matching folders show all their leaves
+        assert_eq!(
+            visible_leaves(&tree, &all, &filter, "VEG"),
+            BTreeSet::from([vec![1, 0, 0]])
+        );
+        // only leaves of the pane
+        let pane = BTreeSet::from([vec![0, 0], vec![2]]);
+        assert_eq!(
+            visible_leaves(&tree, &pane, &filter, "fruit"),
+            BTreeSet::from([vec![0, 0]])
+        );
+    }
+}
@@ -1,5 +1,14 @@
+//! File upload
 use yew::prelude::*;
 
+mod multiple;
+mod read;
+mod simple;
+
+pub use multiple::*;
+pub use read::*;
+pub use simple::*;
+
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct FileUploadProperties {
     #[prop_or_default]
@@ -8,6 +17,13 @@ pub struct FileUploadProperties {
     pub drag_over: bool,
     #[prop_or_default]
     pub r#ref: NodeRef,
+
+    #[prop_or_default]
+    pub ondragover: Callback<DragEvent>,
+    #[prop_or_default]
+    pub ondragleave: Callback<DragEvent>,
+    #[prop_or_default]
+    pub ondrop: Callback<DragEvent>,
 }
 
 /// File upload component
@@ -19,6 +35,9 @@ pub struct FileUploadProperties {
 /// # Properties
 ///
 /// Defined in [`FileUploadProperties`].
+///
+/// This only provides the layout, see [`SimpleFileUpload`] for a component which also handles
+/// selecting and reading files.
 #[function_component(FileUpload)]
 pub fn file_upload(props: &FileUploadProperties) -> Html {
     let mut class = classes!("pf-v5-c-file-upload");
@@ -28,7 +47,13 @@ pub fn file_upload(props: &FileUploadProperties) -> Html {
     }
 
     html! (
-        <div {class} ref={props.r#ref.clone()}>
+        <div
+            {class}
+            ref={props.r#ref.clone()}
+            ondragover={props.ondragover.clone()}
+            ondragleave={props.ondragleave.clone()}
+            ondrop={props.ondrop.clone()}
+        >
             { props.children.clone() }
         </div>
     )
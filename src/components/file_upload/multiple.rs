@@ -0,0 +1,579 @@
+use super::accepts;
+use crate::prelude::{
+    Button, ButtonVariant, HelperText, HelperTextItem, HelperTextItemVariant, Icon, Progress,
+    ProgressSize, ProgressVariant,
+};
+use futures::future::{abortable, AbortHandle, LocalBoxFuture};
+use futures::FutureExt;
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::rc::Rc;
+use web_sys::{File, FileList, HtmlInputElement};
+use yew::{html_nested, prelude::*};
+
+type UploadFn = dyn Fn(File, Callback<f64>) -> LocalBoxFuture<'static, Result<(), String>>;
+
+/// Uploads a file of a [`MultipleFileUpload`].
+///
+/// The function gets the file and a callback for reporting the progress, as a value between
+/// 0 and 1. The future completes once the file is uploaded, or failed with an error message.
+/// Dropping the future cancels the upload.
+#[derive(Clone)]
+pub struct FileUploader(Rc<UploadFn>);
+
+impl FileUploader {
+    pub fn new<F, Fut>(f: F) -> Self
+    where
+        F: Fn(File, Callback<f64>) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        Self(Rc::new(move |file, onprogress| {
+            f(file, onprogress).boxed_local()
+        }))
+    }
+}
+
+impl<F, Fut> From<F> for FileUploader
+where
+    F: Fn(File, Callback<f64>) -> Fut + 'static,
+    Fut: Future<Output = Result<(), String>> + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
+/// Uploaders are equal if they are the same function.
+impl PartialEq for FileUploader {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Debug for FileUploader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FileUploader")
+    }
+}
+
+/// Properties for [`MultipleFileUpload`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct MultipleFileUploadProperties {
+    /// Uploads each of the files.
+    pub uploader: FileUploader,
+
+    /// The accepted file types, in the format of the `accept` attribute of a file input.
+    ///
+    /// See [`super::accepts`].
+    #[prop_or_default]
+    pub accept: Option<AttrValue>,
+
+    /// The maximum size of a file, in bytes.
+    #[prop_or_default]
+    pub max_size: Option<u64>,
+
+    /// Information about the accepted files, shown below the upload button.
+    #[prop_or_default]
+    pub info: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub horizontal: bool,
+
+    #[prop_or(AttrValue::Static("Drag and drop files here"))]
+    pub title_text: AttrValue,
+    #[prop_or(AttrValue::Static("or"))]
+    pub separator_text: AttrValue,
+    #[prop_or(AttrValue::Static("Upload"))]
+    pub upload_text: AttrValue,
+    #[prop_or(AttrValue::Static("Remove from list"))]
+    pub remove_aria_label: AttrValue,
+
+    #[prop_or(AttrValue::Static("The file type is not accepted"))]
+    pub accept_error_text: AttrValue,
+    #[prop_or(AttrValue::Static("The file is too large"))]
+    pub size_error_text: AttrValue,
+
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum UploadStatus {
+    /// Uploading, with the progress between 0 and 1
+    Uploading(f64),
+    Done,
+    Failed(String),
+}
+
+struct Upload {
+    id: usize,
+    name: String,
+    size: u64,
+    status: UploadStatus,
+    /// Cancels the upload while it is running
+    abort: Option<AbortHandle>,
+}
+
+#[derive(Default)]
+struct Uploads {
+    next_id: usize,
+    uploads: Vec<Upload>,
+}
+
+impl Uploads {
+    fn update(&mut self, id: usize, status: UploadStatus) {
+        if let Some(upload) = self.uploads.iter_mut().find(|upload| upload.id == id) {
+            if !matches!(status, UploadStatus::Uploading(_)) {
+                upload.abort = None;
+            }
+            upload.status = status;
+        }
+    }
+
+    fn remove(&mut self, id: usize) {
+        self.uploads.retain(|upload| {
+            if upload.id != id {
+                return true;
+            }
+            if let Some(abort) = &upload.abort {
+                abort.abort();
+            }
+            false
+        });
+    }
+}
+
+impl Drop for Uploads {
+    fn drop(&mut self) {
+        for abort in self
+            .uploads
+            .iter()
+            .filter_map(|upload| upload.abort.as_ref())
+        {
+            abort.abort();
+        }
+    }
+}
+
+/// The overall status of the uploads: the number of uploaded files and the most relevant state.
+fn summary<'a>(
+    statuses: impl IntoIterator<Item = &'a UploadStatus>,
+) -> (usize, usize, ProgressVariant) {
+    let mut done = 0;
+    let mut total = 0;
+    let mut uploading = false;
+    let mut failed = false;
+
+    for status in statuses {
+        total += 1;
+        match status {
+            UploadStatus::Uploading(_) => uploading = true,
+            UploadStatus::Done => done += 1,
+            UploadStatus::Failed(_) => failed = true,
+        }
+    }
+
+    let variant = if failed {
+        ProgressVariant::Danger
+    } else if uploading {
+        ProgressVariant::Default
+    } else {
+        ProgressVariant::Success
+    };
+
+    (done, total, variant)
+}
+
+/// Format a file size for humans.
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if size < 1024 {
+        return format!("{size} B");
+    }
+    let mut value = size as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value < 1024f64 {
+            break;
+        }
+        value /= 1024f64;
+        unit = next;
+    }
+    format!("{value:.1} {unit}")
+}
+
+/// Multiple file upload component
+///
+/// > A **multiple file upload** component allows users to upload several files at once.
+///
+/// See: <https://www.patternfly.org/components/file-upload/multiple-file-upload>
+///
+/// Files can be dropped on the component or selected by browsing. Each file is uploaded using
+/// the [`FileUploader`], showing its progress in the status list, where uploads can also be
+/// cancelled.
+///
+/// ## Properties
+///
+/// Defined by [`MultipleFileUploadProperties`].
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let uploader = use_memo((), |()| {
+///     FileUploader::new(|file: web_sys::File, onprogress: Callback<f64>| async move {
+///       // upload the file, e.g. using an HTTP client
+///       onprogress.emit(1.0);
+///       Ok(())
+///     })
+///   });
+///
+///   html!(
+///     <MultipleFileUpload uploader={(*uploader).clone()} info="Accepted file types: JSON" accept=".json" />
+///   )
+/// }
+/// ```
+#[function_component(MultipleFileUpload)]
+pub fn multiple_file_upload(props: &MultipleFileUploadProperties) -> Html {
+    let input = use_node_ref();
+    let drag_over = use_state_eq(|| false);
+    let expanded = use_state_eq(|| true);
+    let uploads = use_mut_ref(Uploads::default);
+    let update = {
+        let force_update = use_force_update();
+        Callback::from(move |()| force_update.force_update())
+    };
+
+    let onfiles = {
+        let props = props.clone();
+        let uploads = uploads.clone();
+        let update = update.clone();
+        Callback::from(move |files: FileList| {
+            for file in (0..files.length()).filter_map(|index| files.get(index)) {
+                start(&props, &uploads, &update, file);
+            }
+            update.emit(());
+        })
+    };
+
+    let onbrowse = use_callback(input.clone(), |_: MouseEvent, input| {
+        if let Some(input) = input.cast::<HtmlInputElement>() {
+            input.click();
+        }
+    });
+
+    let oninputchange = use_callback(onfiles.clone(), |e: Event, onfiles| {
+        let Some(input) = e.target_dyn_into::<HtmlInputElement>() else {
+            return;
+        };
+        if let Some(files) = input.files() {
+            onfiles.emit(files);
+        }
+        // allow selecting the same files again
+        input.set_value("");
+    });
+
+    let disabled = props.disabled;
+
+    let ondragover = use_callback(
+        (drag_over.clone(), disabled),
+        |e: DragEvent, (drag_over, disabled)| {
+            if !*disabled {
+                // allow dropping
+                e.prevent_default();
+                drag_over.set(true);
+            }
+        },
+    );
+
+    let ondragleave = use_callback(drag_over.clone(), |_: DragEvent, drag_over| {
+        drag_over.set(false);
+    });
+
+    let ondrop = use_callback(
+        (drag_over.clone(), onfiles, disabled),
+        |e: DragEvent, (drag_over, onfiles, disabled)| {
+            e.prevent_default();
+            drag_over.set(false);
+            if *disabled {
+                return;
+            }
+            if let Some(files) = e.data_transfer().and_then(|transfer| transfer.files()) {
+                onfiles.emit(files);
+            }
+        },
+    );
+
+    let ontoggle = use_callback(expanded.clone(), |_: MouseEvent, expanded| {
+        expanded.set(!**expanded)
+    });
+
+    let mut class = classes!("pf-v5-c-multiple-file-upload");
+    if props.horizontal {
+        class.push(classes!("pf-m-horizontal"));
+    }
+    if *drag_over {
+        class.push(classes!("pf-m-drag-over"));
+    }
+
+    let state = uploads.borrow();
+    let (done, total, variant) = summary(state.uploads.iter().map(|upload| &upload.status));
+    let status_icon = match variant {
+        ProgressVariant::Default => Icon::InProgress,
+        ProgressVariant::Success => Icon::CheckCircle,
+        ProgressVariant::Warning => Icon::ExclamationTriangle,
+        ProgressVariant::Danger => Icon::TimesCircle,
+    };
+
+    html!(
+        <div {class} {ondragover} {ondragleave} {ondrop}>
+            <div class="pf-v5-c-multiple-file-upload__main">
+                <div class="pf-v5-c-multiple-file-upload__title">
+                    <div class="pf-v5-c-multiple-file-upload__title-icon">
+                        { Icon::Upload }
+                    </div>
+                    <div class="pf-v5-c-multiple-file-upload__title-text">
+                        { &props.title_text }
+                        <div class="pf-v5-c-multiple-file-upload__title-text-separator">
+                            { &props.separator_text }
+                        </div>
+                    </div>
+                </div>
+                <div class="pf-v5-c-multiple-file-upload__upload">
+                    <Button variant={ButtonVariant::Secondary} {disabled} onclick={onbrowse}>
+                        { &props.upload_text }
+                    </Button>
+                </div>
+                if let Some(info) = &props.info {
+                    <div class="pf-v5-c-multiple-file-upload__info">{ info }</div>
+                }
+                <input
+                    ref={input}
+                    type="file"
+                    multiple=true
+                    hidden=true
+                    accept={props.accept.clone()}
+                    {disabled}
+                    onchange={oninputchange}
+                />
+            </div>
+            if total > 0 {
+                <div class="pf-v5-c-multiple-file-upload__status">
+                    <div class={classes!("pf-v5-c-expandable-section", expanded.then_some("pf-m-expanded"))}>
+                        <button
+                            class="pf-v5-c-expandable-section__toggle"
+                            type="button"
+                            aria-expanded={expanded.to_string()}
+                            onclick={ontoggle}
+                        >
+                            <span class="pf-v5-c-expandable-section__toggle-icon">
+                                { Icon::AngleRight }
+                            </span>
+                            <span class="pf-v5-c-expandable-section__toggle-text">
+                                <div class="pf-v5-c-multiple-file-upload__status-progress">
+                                    <div class="pf-v5-c-multiple-file-upload__status-progress-icon">
+                                        { status_icon }
+                                    </div>
+                                    <div class="pf-v5-c-multiple-file-upload__status-item-progress-text">
+                                        { format!("{done} of {total} files uploaded") }
+                                    </div>
+                                </div>
+                            </span>
+                        </button>
+                        <div class="pf-v5-c-expandable-section__content" hidden={!*expanded}>
+                            <ul class="pf-v5-c-multiple-file-upload__status-list">
+                                { for state.uploads.iter().map(|upload| render_upload(props, &uploads, &update, upload)) }
+                            </ul>
+                        </div>
+                    </div>
+                </div>
+            }
+        </div>
+    )
+}
+
+/// Add a file and start uploading it, unless it is rejected.
+fn start(
+    props: &MultipleFileUploadProperties,
+    uploads: &Rc<RefCell<Uploads>>,
+    update: &Callback<()>,
+    file: File,
+) {
+    let name = file.name();
+    let size = file.size() as u64;
+
+    let rejected = if !accepts(
+        props.accept.as_deref().unwrap_or_default(),
+        &name,
+        &file.type_(),
+    ) {
+        Some(props.accept_error_text.to_string())
+    } else if props.max_size.is_some_and(|max| size > max) {
+        Some(props.size_error_text.to_string())
+    } else {
+        None
+    };
+
+    let mut state = uploads.borrow_mut();
+    let id = state.next_id;
+    state.next_id += 1;
+
+    if let Some(rejected) = rejected {
+        state.uploads.push(Upload {
+            id,
+            name,
+            size,
+            status: UploadStatus::Failed(rejected),
+            abort: None,
+        });
+        return;
+    }
+
+    let onprogress = {
+        let uploads = uploads.clone();
+        let update = update.clone();
+        Callback::from(move |progress: f64| {
+            uploads
+                .borrow_mut()
+                .update(id, UploadStatus::Uploading(progress.clamp(0f64, 1f64)));
+            update.emit(());
+        })
+    };
+
+    state.uploads.push(Upload {
+        id,
+        name,
+        size,
+        status: UploadStatus::Uploading(0f64),
+        abort: None,
+    });
+    // release the state, as the uploader may already report progress before returning
+    drop(state);
+
+    let (upload, abort) = abortable((props.uploader.0)(file, onprogress));
+    match uploads
+        .borrow_mut()
+        .uploads
+        .iter_mut()
+        .find(|upload| upload.id == id)
+    {
+        Some(upload) => upload.abort = Some(abort),
+        None => abort.abort(),
+    }
+
+    let uploads = uploads.clone();
+    let update = update.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        // cancelled uploads are already removed
+        if let Ok(result) = upload.await {
+            let status = match result {
+                Ok(()) => UploadStatus::Done,
+                Err(err) => UploadStatus::Failed(err),
+            };
+            uploads.borrow_mut().update(id, status);
+            update.emit(());
+        }
+    });
+}
+
+fn render_upload(
+    props: &MultipleFileUploadProperties,
+    uploads: &Rc<RefCell<Uploads>>,
+    update: &Callback<()>,
+    upload: &Upload,
+) -> Html {
+    let (value, variant, error) = match &upload.status {
+        UploadStatus::Uploading(progress) => (*progress, ProgressVariant::Default, None),
+        UploadStatus::Done => (1f64, ProgressVariant::Success, None),
+        UploadStatus::Failed(err) => (1f64, ProgressVariant::Danger, Some(err.clone())),
+    };
+
+    let onremove = {
+        let id = upload.id;
+        let uploads = uploads.clone();
+        let update = update.clone();
+        Callback::from(move |_: MouseEvent| {
+            uploads.borrow_mut().remove(id);
+            update.emit(());
+        })
+    };
+
+    let description = html!(
+        <span class="pf-v5-c-multiple-file-upload__status-item-progress">
+            <span class="pf-v5-c-multiple-file-upload__status-item-progress-text">
+                { &upload.name }
+            </span>
+            <span class="pf-v5-c-multiple-file-upload__status-item-progress-size">
+                { format_size(upload.size) }
+            </span>
+        </span>
+    );
+    let helper_text = error.map(|error| {
+        html_nested!(
+            <HelperText>
+                <HelperTextItem variant={HelperTextItemVariant::Error}>{ error }</HelperTextItem>
+            </HelperText>
+        )
+    });
+
+    html!(
+        <li class="pf-v5-c-multiple-file-upload__status-item" key={upload.id}>
+            <div class="pf-v5-c-multiple-file-upload__status-item-icon">
+                { Icon::File }
+            </div>
+            <div class="pf-v5-c-multiple-file-upload__status-item-main">
+                <Progress
+                    {value}
+                    {description}
+                    size={ProgressSize::Small}
+                    {variant}
+                    {helper_text}
+                />
+            </div>
+            <div class="pf-v5-c-multiple-file-upload__status-item-close">
+                <Button
+                    variant={ButtonVariant::Plain}
+                    aria_label={props.remove_aria_label.clone()}
+                    icon={Icon::Times}
+                    onclick={onremove}
+                />
+            </div>
+        </li>
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        assert_eq!(summary([]), (0, 0, ProgressVariant::Success));
+        assert_eq!(
+            summary(&[UploadStatus::Done, UploadStatus::Uploading(0.5)]),
+            (1, 2, ProgressVariant::Default)
+        );
+        assert_eq!(
+            summary(&[
+                UploadStatus::Done,
+                UploadStatus::Uploading(0.5),
+                UploadStatus::Failed("error".into())
+            ]),
+            (1, 3, ProgressVariant::Danger)
+        );
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MB");
+    }
+}
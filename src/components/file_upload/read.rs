@@ -0,0 +1,163 @@
+use futures::channel::oneshot;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use web_sys::{File, FileReader, ProgressEvent};
+use yew::Callback;
+
+/// How to read the content of a file.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum FileReadMode {
+    #[default]
+    Text,
+    Bytes,
+}
+
+/// The content of a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileContent {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+/// The progress of reading a file, in bytes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileReadProgress {
+    pub loaded: u64,
+    pub total: u64,
+}
+
+/// Read the content of a file.
+///
+/// Dropping the future aborts reading the file.
+pub async fn read_file(
+    file: &File,
+    mode: FileReadMode,
+    onprogress: Callback<FileReadProgress>,
+) -> Result<FileContent, JsValue> {
+    let reader = FileReader::new()?;
+
+    let (sender, receiver) = oneshot::channel::<Result<(), JsValue>>();
+    let sender = Rc::new(RefCell::new(Some(sender)));
+    let complete = move |result| {
+        if let Some(sender) = sender.borrow_mut().take() {
+            let _ = sender.send(result);
+        }
+    };
+
+    let onload = {
+        let complete = complete.clone();
+        Closure::<dyn FnMut(ProgressEvent)>::new(move |_| complete(Ok(())))
+    };
+    let onerror = {
+        let reader = reader.clone();
+        Closure::<dyn FnMut(ProgressEvent)>::new(move |_| {
+            complete(Err(reader
+                .error()
+                .map(JsValue::from)
+                .unwrap_or_else(|| JsValue::from_str("Failed to read file"))))
+        })
+    };
+    let onprogress = Closure::<dyn FnMut(ProgressEvent)>::new(move |e: ProgressEvent| {
+        if e.length_computable() {
+            onprogress.emit(FileReadProgress {
+                loaded: e.loaded() as u64,
+                total: e.total() as u64,
+            });
+        }
+    });
+
+    reader.set_onload(Some(onload.as_ref().unchecked_ref()));
+    reader.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+    reader.set_onprogress(Some(onprogress.as_ref().unchecked_ref()));
+    let guard = ReaderGuard {
+        reader,
+        _closures: [onload, onerror, onprogress],
+    };
+
+    match mode {
+        FileReadMode::Text => guard.reader.read_as_text(file)?,
+        FileReadMode::Bytes => guard.reader.read_as_array_buffer(file)?,
+    }
+
+    receiver
+        .await
+        .map_err(|_| JsValue::from_str("Reading the file was cancelled"))??;
+
+    let result = guard.reader.result()?;
+    Ok(match mode {
+        FileReadMode::Text => FileContent::Text(result.as_string().unwrap_or_default()),
+        FileReadMode::Bytes => FileContent::Bytes(js_sys::Uint8Array::new(&result).to_vec()),
+    })
+}
+
+/// Keeps the handlers of a reader alive, detaching them and aborting the reader when dropped.
+struct ReaderGuard {
+    reader: FileReader,
+    _closures: [Closure<dyn FnMut(ProgressEvent)>; 3],
+}
+
+impl Drop for ReaderGuard {
+    fn drop(&mut self) {
+        self.reader.set_onload(None);
+        self.reader.set_onerror(None);
+        self.reader.set_onprogress(None);
+        if self.reader.ready_state() == FileReader::LOADING {
+            self.reader.abort();
+        }
+    }
+}
+
+/// Check if a file matches the `accept` attribute of a file input.
+///
+/// The attribute is a comma separated list of file extensions (`.txt`), MIME types
+/// (`text/plain`) and wildcard MIME types (`text/*`). An empty list accepts all files.
+pub fn accepts(accept: &str, name: &str, mime: &str) -> bool {
+    let mut tokens = accept
+        .split(',')
+        .map(|token| token.trim().to_lowercase())
+        .filter(|token| !token.is_empty())
+        .peekable();
+
+    if tokens.peek().is_none() {
+        return true;
+    }
+
+    let name = name.to_lowercase();
+    let mime = mime.to_lowercase();
+
+    tokens.any(|token| {
+        if token.starts_with('.') {
+            name.ends_with(&token)
+        } else if let Some(prefix) = token.strip_suffix("/*") {
+            mime.split_once('/')
+                .is_some_and(|(r#type, _)| r#type == prefix)
+        } else {
+            mime == token
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_accepts() {
+        assert!(accepts("", "foo.bin", ""));
+        assert!(accepts(" , ", "foo.bin", ""));
+
+        assert!(accepts(".txt,.json", "foo.JSON", ""));
+        assert!(!accepts(".txt,.json", "foo.yaml", "text/yaml"));
+
+        assert!(accepts("text/*", "foo", "text/plain"));
+        assert!(!accepts("text/*", "foo", "textual/plain"));
+
+        assert!(accepts(
+            "image/png, application/json",
+            "foo",
+            "application/json"
+        ));
+        assert!(!accepts("image/png", "foo.png", "image/jpeg"));
+    }
+}
@@ -0,0 +1,317 @@
+use super::{
+    accepts, read_file, FileContent, FileReadMode, FileReadProgress, FileUpload, FileUploadDetails,
+    FileUploadSelect,
+};
+use crate::prelude::{
+    Button, ButtonVariant, HelperText, HelperTextItem, HelperTextItemIcon, HelperTextItemVariant,
+    InputGroup, InputGroupItem, InputState, ResizeOrientation, TextArea, TextInput,
+};
+use web_sys::{File, HtmlInputElement};
+use yew::prelude::*;
+
+/// A file, read by a [`SimpleFileUpload`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UploadedFile {
+    pub name: String,
+    /// The MIME type, as reported by the browser. May be empty.
+    pub mime: String,
+    pub size: u64,
+    pub content: FileContent,
+}
+
+/// Properties for [`SimpleFileUpload`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct SimpleFileUploadProperties {
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+
+    /// How to read the content of the file.
+    #[prop_or_default]
+    pub mode: FileReadMode,
+
+    /// The accepted file types, in the format of the `accept` attribute of a file input.
+    ///
+    /// See [`accepts`].
+    #[prop_or_default]
+    pub accept: Option<AttrValue>,
+
+    /// The maximum size of a file, in bytes.
+    #[prop_or_default]
+    pub max_size: Option<u64>,
+
+    /// Show the content of text files.
+    #[prop_or(true)]
+    pub preview: bool,
+
+    #[prop_or(AttrValue::Static("Drag a file here or browse to upload"))]
+    pub placeholder: AttrValue,
+    #[prop_or(AttrValue::Static("Browse..."))]
+    pub browse_text: AttrValue,
+    #[prop_or(AttrValue::Static("Clear"))]
+    pub clear_text: AttrValue,
+
+    #[prop_or(AttrValue::Static("The file type is not accepted"))]
+    pub accept_error_text: AttrValue,
+    #[prop_or(AttrValue::Static("The file is too large"))]
+    pub size_error_text: AttrValue,
+    #[prop_or(AttrValue::Static("The file could not be read"))]
+    pub read_error_text: AttrValue,
+
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// Called with the file once its content was read, or with [`None`] when the file was
+    /// cleared or rejected.
+    #[prop_or_default]
+    pub onchange: Callback<Option<UploadedFile>>,
+
+    /// Called while reading the content of the file.
+    #[prop_or_default]
+    pub onprogress: Callback<FileReadProgress>,
+}
+
+/// A file upload, handling selecting and reading the file.
+///
+/// Files can be dropped on the component or selected by browsing. Files not matching the
+/// `accept` types or exceeding the `max_size` are rejected with an error.
+///
+/// See: <https://www.patternfly.org/components/file-upload>
+///
+/// ## Properties
+///
+/// Defined by [`SimpleFileUploadProperties`].
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let onchange = Callback::from(|file: Option<UploadedFile>| {
+///     if let Some(UploadedFile { content: FileContent::Text(text), .. }) = file {
+///       log::info!("Text: {text}");
+///     }
+///   });
+///
+///   html!(
+///     <SimpleFileUpload accept=".json,.yaml" max_size={1024 * 1024} {onchange} />
+///   )
+/// }
+/// ```
+#[function_component(SimpleFileUpload)]
+pub fn simple_file_upload(props: &SimpleFileUploadProperties) -> Html {
+    let input = use_node_ref();
+    let drag_over = use_state_eq(|| false);
+    let name = use_state_eq(|| None::<String>);
+    let text = use_state_eq(String::new);
+    let loading = use_state_eq(|| false);
+    let error = use_state_eq(|| None::<AttrValue>);
+    // incremented for every file, to drop the results of outdated reads
+    let generation = use_mut_ref(|| 0usize);
+
+    let onfile = {
+        let props = props.clone();
+        let name = name.clone();
+        let text = text.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let generation = generation.clone();
+        Callback::from(move |file: File| {
+            *generation.borrow_mut() += 1;
+            let current = *generation.borrow();
+
+            let upload_name = file.name();
+            let mime = file.type_();
+            let size = file.size() as u64;
+
+            name.set(Some(upload_name.clone()));
+            text.set(String::new());
+
+            let rejected = if !accepts(
+                props.accept.as_deref().unwrap_or_default(),
+                &upload_name,
+                &mime,
+            ) {
+                Some(props.accept_error_text.clone())
+            } else if props.max_size.is_some_and(|max| size > max) {
+                Some(props.size_error_text.clone())
+            } else {
+                None
+            };
+            if let Some(rejected) = rejected {
+                loading.set(false);
+                error.set(Some(rejected));
+                props.onchange.emit(None);
+                return;
+            }
+
+            error.set(None);
+            loading.set(true);
+
+            let props = props.clone();
+            let text = text.clone();
+            let loading = loading.clone();
+            let error = error.clone();
+            let generation = generation.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = read_file(&file, props.mode, props.onprogress.clone()).await;
+                if *generation.borrow() != current {
+                    return;
+                }
+                loading.set(false);
+                match result {
+                    Ok(content) => {
+                        if let FileContent::Text(content) = &content {
+                            text.set(content.clone());
+                        }
+                        props.onchange.emit(Some(UploadedFile {
+                            name: upload_name,
+                            mime,
+                            size,
+                            content,
+                        }));
+                    }
+                    Err(err) => {
+                        log::warn!("Failed to read file: {err:?}");
+                        error.set(Some(props.read_error_text.clone()));
+                        props.onchange.emit(None);
+                    }
+                }
+            });
+        })
+    };
+
+    let onclear = {
+        let name = name.clone();
+        let text = text.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let onchange = props.onchange.clone();
+        Callback::from(move |_: MouseEvent| {
+            *generation.borrow_mut() += 1;
+            name.set(None);
+            text.set(String::new());
+            loading.set(false);
+            error.set(None);
+            onchange.emit(None);
+        })
+    };
+
+    let onbrowse = use_callback(input.clone(), |_: MouseEvent, input| {
+        if let Some(input) = input.cast::<HtmlInputElement>() {
+            input.click();
+        }
+    });
+
+    let oninputchange = use_callback(onfile.clone(), |e: Event, onfile| {
+        let Some(input) = e.target_dyn_into::<HtmlInputElement>() else {
+            return;
+        };
+        if let Some(file) = input.files().and_then(|files| files.get(0)) {
+            onfile.emit(file);
+        }
+        // allow selecting the same file again
+        input.set_value("");
+    });
+
+    let disabled = props.disabled;
+
+    let ondragover = use_callback(
+        (drag_over.clone(), disabled),
+        |e: DragEvent, (drag_over, disabled)| {
+            if !*disabled {
+                // allow dropping
+                e.prevent_default();
+                drag_over.set(true);
+            }
+        },
+    );
+
+    let ondragleave = use_callback(drag_over.clone(), |_: DragEvent, drag_over| {
+        drag_over.set(false);
+    });
+
+    let ondrop = use_callback(
+        (drag_over.clone(), onfile, disabled),
+        |e: DragEvent, (drag_over, onfile, disabled)| {
+            e.prevent_default();
+            drag_over.set(false);
+            if *disabled {
+                return;
+            }
+            if let Some(file) = e
+                .data_transfer()
+                .and_then(|transfer| transfer.files())
+                .and_then(|files| files.get(0))
+            {
+                onfile.emit(file);
+            }
+        },
+    );
+
+    let state = match error.is_some() {
+        true => InputState::Error,
+        false => InputState::Default,
+    };
+
+    html!(
+        <>
+            <FileUpload drag_over={*drag_over} {ondragover} {ondragleave} {ondrop}>
+                <FileUploadSelect>
+                    <InputGroup>
+                        <InputGroupItem fill=true>
+                            <TextInput
+                                id={props.id.clone()}
+                                readonly=true
+                                value={(*name).clone().unwrap_or_default()}
+                                placeholder={props.placeholder.clone()}
+                                {state}
+                            />
+                        </InputGroupItem>
+                        <InputGroupItem>
+                            <Button variant={ButtonVariant::Control} {disabled} onclick={onbrowse}>
+                                { &props.browse_text }
+                            </Button>
+                        </InputGroupItem>
+                        <InputGroupItem>
+                            <Button
+                                variant={ButtonVariant::Control}
+                                disabled={disabled || name.is_none()}
+                                onclick={onclear}
+                            >
+                                { &props.clear_text }
+                            </Button>
+                        </InputGroupItem>
+                    </InputGroup>
+                </FileUploadSelect>
+                if props.preview && props.mode == FileReadMode::Text {
+                    <FileUploadDetails processing={*loading} invalid={error.is_some()}>
+                        <TextArea
+                            value={(*text).clone()}
+                            readonly=true
+                            resize={ResizeOrientation::Vertical}
+                            {state}
+                        />
+                    </FileUploadDetails>
+                }
+                <input
+                    ref={input}
+                    type="file"
+                    hidden=true
+                    accept={props.accept.clone()}
+                    {disabled}
+                    onchange={oninputchange}
+                />
+            </FileUpload>
+            if let Some(error) = &*error {
+                <HelperText live_region=true>
+                    <HelperTextItem variant={HelperTextItemVariant::Error} icon={HelperTextItemIcon::Visible}>
+                        { error }
+                    </HelperTextItem>
+                </HelperText>
+            }
+        </>
+    )
+}
@@ -15,13 +15,12 @@ pub enum HelperTextComponent {
     Ul,
 }
 
-impl ToString for HelperTextComponent {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for HelperTextComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
             Self::Div => "div",
             Self::Ul => "ul",
-        }
-        .to_string()
+        })
     }
 }
 
@@ -170,13 +169,12 @@ pub enum HelperTextItemComponent {
     Li,
 }
 
-impl ToString for HelperTextItemComponent {
-    fn to_string(&self) -> String {
-        match self {
+impl std::fmt::Display for HelperTextItemComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
             Self::Div => "div",
             Self::Li => "li",
-        }
-        .to_string()
+        })
     }
 }
 
@@ -0,0 +1,396 @@
+//! Jump links
+use crate::components::page::PageContext;
+use crate::prelude::{Breakpoint, Icon};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{
+    Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit,
+    MutationObserver, MutationObserverInit,
+};
+use yew::{html::ChildrenWithProps, prelude::*, virtual_dom::VChild};
+
+#[derive(Clone, PartialEq)]
+struct JumpLinksContext {
+    /// The `href` of the current item
+    active: Option<AttrValue>,
+    onselect: Callback<AttrValue>,
+}
+
+/// Properties for [`JumpLinks`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct JumpLinksProperties {
+    #[prop_or_default]
+    pub children: ChildrenWithProps<JumpLinksItem>,
+
+    /// A label, shown before the links.
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+
+    #[prop_or(AttrValue::Static("Jump links"))]
+    pub aria_label: AttrValue,
+
+    #[prop_or_default]
+    pub vertical: bool,
+
+    #[prop_or_default]
+    pub center: bool,
+
+    /// The element scrolling the sections.
+    ///
+    /// Defaults to the main section of the wrapping [`crate::prelude::Page`], or the viewport
+    /// when used outside a page.
+    #[prop_or_default]
+    pub scroll_container: Option<NodeRef>,
+
+    /// The offset from the top of the scroll container in pixels, e.g. the height of a sticky
+    /// header, which hides the top of the sections.
+    #[prop_or_default]
+    pub offset: u32,
+
+    /// Collapse the links into a toggle below this breakpoint.
+    #[prop_or_default]
+    pub expandable: Option<Breakpoint>,
+
+    #[prop_or(AttrValue::Static("Jump to section"))]
+    pub toggle_text: AttrValue,
+}
+
+/// Jump links component
+///
+/// > **Jump links** allow users to navigate to sections within a page.
+///
+/// See: <https://www.patternfly.org/components/jump-links>
+///
+/// The current item follows scrolling: it is the first item whose section, identified by the
+/// fragment of its `href`, is visible in the scroll container. Sections which are rendered
+/// later, e.g. after loading content, are tracked as well.
+///
+/// ## Properties
+///
+/// Defined by [`JumpLinksProperties`].
+///
+/// ## Children
+///
+/// A list of [`JumpLinksItem`]s, which may have a nested [`JumpLinksList`].
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   html!(
+///     <JumpLinks vertical=true label="Jump to section" expandable={Breakpoint::Medium}>
+///       <JumpLinksItem href="#overview">{ "Overview" }</JumpLinksItem>
+///       <JumpLinksItem
+///         href="#settings"
+///         list={html_nested!(
+///           <JumpLinksList>
+///             <JumpLinksItem href="#general">{ "General" }</JumpLinksItem>
+///             <JumpLinksItem href="#advanced">{ "Advanced" }</JumpLinksItem>
+///           </JumpLinksList>
+///         )}
+///       >
+///         { "Settings" }
+///       </JumpLinksItem>
+///     </JumpLinks>
+///   )
+/// }
+/// ```
+#[function_component(JumpLinks)]
+pub fn jump_links(props: &JumpLinksProperties) -> Html {
+    let page = use_context::<PageContext>();
+    let container = props
+        .scroll_container
+        .clone()
+        .or_else(|| page.map(|page| page.main));
+
+    let active = use_state_eq(|| None::<AttrValue>);
+    let expanded = use_state_eq(|| false);
+
+    {
+        let active = active.clone();
+        use_effect_with(
+            (hrefs(&props.children), container, props.offset),
+            move |(hrefs, container, offset)| {
+                let root = container.as_ref().and_then(|c| c.cast::<Element>());
+                let observer = ScrollSpy::new(
+                    hrefs.clone(),
+                    root,
+                    *offset,
+                    Callback::from(move |href| active.set(Some(href))),
+                );
+                move || drop(observer)
+            },
+        );
+    }
+
+    let onselect = use_callback(
+        (active.clone(), expanded.clone()),
+        |href: AttrValue, (active, expanded)| {
+            if let Some(target) = section(&href) {
+                target.scroll_into_view();
+            }
+            active.set(Some(href));
+            expanded.set(false);
+        },
+    );
+
+    let ontoggle = use_callback(expanded.clone(), |_: MouseEvent, expanded| {
+        expanded.set(!**expanded)
+    });
+
+    let context = JumpLinksContext {
+        active: (*active).clone(),
+        onselect,
+    };
+
+    let mut class = classes!("pf-v5-c-jump-links");
+
+    if props.vertical {
+        class.push(classes!("pf-m-vertical"));
+    }
+
+    if props.center {
+        class.push(classes!("pf-m-center"));
+    }
+
+    if let Some(breakpoint) = props.expandable {
+        class.push(classes!("pf-m-expandable"));
+        if breakpoint != Breakpoint::None {
+            class.push(format!("pf-m-non-expandable{breakpoint}"));
+        }
+        if *expanded {
+            class.push(classes!("pf-m-expanded"));
+        }
+    }
+
+    let label = props.label.as_ref().map(|label| {
+        html!(
+            <div class="pf-v5-c-jump-links__label">{ label }</div>
+        )
+    });
+
+    html!(
+        <ContextProvider<JumpLinksContext> {context}>
+            <nav {class} aria-label={props.label.clone().unwrap_or_else(|| props.aria_label.clone())}>
+                <div class="pf-v5-c-jump-links__main">
+                    if props.expandable.is_some() {
+                        <div class="pf-v5-c-jump-links__header">
+                            <div class="pf-v5-c-jump-links__toggle">
+                                <button
+                                    class="pf-v5-c-button pf-m-plain"
+                                    type="button"
+                                    aria-expanded={expanded.to_string()}
+                                    onclick={ontoggle}
+                                >
+                                    <span class="pf-v5-c-jump-links__toggle-icon">{ Icon::AngleRight }</span>
+                                    <span class="pf-v5-c-jump-links__toggle-text">{ &props.toggle_text }</span>
+                                </button>
+                            </div>
+                            { label }
+                        </div>
+                    } else if label.is_some() {
+                        <div class="pf-v5-c-jump-links__header">
+                            { label }
+                        </div>
+                    }
+                    <ul class="pf-v5-c-jump-links__list">
+                        { for props.children.iter() }
+                    </ul>
+                </div>
+            </nav>
+        </ContextProvider<JumpLinksContext>>
+    )
+}
+
+/// All `href`s of the items, in document order, including nested ones.
+fn hrefs(children: &ChildrenWithProps<JumpLinksItem>) -> Vec<AttrValue> {
+    let mut result = vec![];
+    for child in children.iter() {
+        result.push(child.props.href.clone());
+        if let Some(list) = &child.props.list {
+            result.extend(hrefs(&list.props.children));
+        }
+    }
+    result
+}
+
+/// The section element an `href` points to.
+fn section(href: &str) -> Option<Element> {
+    let id = href.strip_prefix('#')?;
+    gloo_utils::document().get_element_by_id(id)
+}
+
+/// Observe the sections of the jump links, reporting the first visible one.
+///
+/// Sections which are rendered later, e.g. after loading content, are picked up by watching
+/// the scroll container for changes.
+struct ScrollSpy {
+    observer: IntersectionObserver,
+    mutations: MutationObserver,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+    _onmutation: Closure<dyn FnMut()>,
+}
+
+impl ScrollSpy {
+    fn new(
+        hrefs: Vec<AttrValue>,
+        root: Option<Element>,
+        offset: u32,
+        onchange: Callback<AttrValue>,
+    ) -> Option<Self> {
+        let visible = Rc::new(RefCell::new(HashSet::<String>::new()));
+
+        let callback = Closure::<dyn FnMut(js_sys::Array)>::new({
+            let hrefs = hrefs.clone();
+            let visible = visible.clone();
+            move |entries: js_sys::Array| {
+                let mut visible = visible.borrow_mut();
+                for entry in entries.iter() {
+                    let Ok(entry) = entry.dyn_into::<IntersectionObserverEntry>() else {
+                        continue;
+                    };
+                    let id = entry.target().id();
+                    if entry.is_intersecting() {
+                        visible.insert(id);
+                    } else {
+                        visible.remove(&id);
+                    }
+                }
+
+                // keep the current item while no section is visible
+                if let Some(href) = hrefs.iter().find(|href| {
+                    href.strip_prefix('#')
+                        .is_some_and(|id| visible.contains(id))
+                }) {
+                    onchange.emit(href.clone());
+                }
+            }
+        });
+
+        let options = IntersectionObserverInit::new();
+        options.set_root(root.as_ref());
+        options.set_root_margin(&format!("-{offset}px 0px 0px 0px"));
+
+        let observer =
+            IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &options)
+                .ok()?;
+
+        // observe the sections which exist, and the ones which got replaced or added since
+        let mut observed = Vec::<Element>::new();
+        let mut observe = {
+            let observer = observer.clone();
+            move || {
+                observed.retain(|section| {
+                    let connected = section.is_connected();
+                    if !connected {
+                        observer.unobserve(section);
+                        visible.borrow_mut().remove(&section.id());
+                    }
+                    connected
+                });
+                for section in hrefs.iter().filter_map(|href| section(href)) {
+                    if !observed.contains(&section) {
+                        observer.observe(&section);
+                        observed.push(section);
+                    }
+                }
+            }
+        };
+        observe();
+
+        let onmutation = Closure::<dyn FnMut()>::new(observe);
+        let mutations = MutationObserver::new(onmutation.as_ref().unchecked_ref()).ok()?;
+        let options = MutationObserverInit::new();
+        options.set_child_list(true);
+        options.set_subtree(true);
+        let target = root.or_else(|| gloo_utils::document().body().map(Into::into))?;
+        mutations.observe_with_options(&target, &options).ok()?;
+
+        Some(Self {
+            observer,
+            mutations,
+            _callback: callback,
+            _onmutation: onmutation,
+        })
+    }
+}
+
+impl Drop for ScrollSpy {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+        self.mutations.disconnect();
+    }
+}
+
+/// Properties for [`JumpLinksItem`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct JumpLinksItemProperties {
+    /// The target of the link, the `id` of the section, prefixed with `#`.
+    pub href: AttrValue,
+
+    #[prop_or_default]
+    pub children: Html,
+
+    /// A nested list of items.
+    #[prop_or_default]
+    pub list: Option<VChild<JumpLinksList>>,
+}
+
+#[function_component(JumpLinksItem)]
+pub fn jump_links_item(props: &JumpLinksItemProperties) -> Html {
+    let context = use_context::<JumpLinksContext>();
+
+    let current = context
+        .as_ref()
+        .is_some_and(|context| context.active.as_ref() == Some(&props.href));
+
+    let mut class = classes!("pf-v5-c-jump-links__item");
+    if current {
+        class.push(classes!("pf-m-current"));
+    }
+
+    let onclick = context.map(|context| {
+        let href = props.href.clone();
+        Callback::from(move |e: MouseEvent| {
+            e.prevent_default();
+            context.onselect.emit(href.clone());
+        })
+    });
+
+    html!(
+        <li {class}>
+            <a
+                class="pf-v5-c-jump-links__link"
+                href={&props.href}
+                aria-current={current.then_some("location")}
+                {onclick}
+            >
+                <span class="pf-v5-c-jump-links__link-text">{ props.children.clone() }</span>
+            </a>
+            { props.list.clone() }
+        </li>
+    )
+}
+
+/// Properties for [`JumpLinksList`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct JumpLinksListProperties {
+    #[prop_or_default]
+    pub children: ChildrenWithProps<JumpLinksItem>,
+}
+
+/// A nested list of [`JumpLinksItem`]s.
+#[function_component(JumpLinksList)]
+pub fn jump_links_list(props: &JumpLinksListProperties) -> Html {
+    html!(
+        <ul class="pf-v5-c-jump-links__list">
+            { for props.children.iter() }
+        </ul>
+    )
+}
@@ -4,8 +4,9 @@ use yew::prelude::*;
 
 use strum_macros::{Display, EnumIter, EnumString};
 
-#[derive(Copy, Clone, Display, Debug, PartialEq, Eq, EnumIter, EnumString)]
+#[derive(Copy, Clone, Default, Display, Debug, PartialEq, Eq, EnumIter, EnumString)]
 pub enum Color {
+    #[default]
     Grey,
     Blue,
     Green,
@@ -16,12 +17,6 @@ pub enum Color {
     Gold,
 }
 
-impl Default for Color {
-    fn default() -> Self {
-        Self::Grey
-    }
-}
-
 impl AsClasses for Color {
     fn extend_classes(&self, classes: &mut Classes) {
         match self {
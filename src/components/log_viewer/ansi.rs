@@ -0,0 +1,237 @@
+//! Parsing ANSI escape sequences.
+
+/// A color of the terminal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnsiColor {
+    /// A color of the 256 color palette. The first 16 colors are the standard and bright colors.
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+/// The standard and bright colors.
+const COLORS: [(u8, u8, u8); 16] = [
+    (0x1e, 0x1e, 0x1e),
+    (0xc9, 0x19, 0x0b),
+    (0x3e, 0x86, 0x35),
+    (0xf0, 0xab, 0x00),
+    (0x00, 0x66, 0xcc),
+    (0x6a, 0x6e, 0x73),
+    (0x00, 0x95, 0x96),
+    (0xd2, 0xd2, 0xd2),
+    (0x6a, 0x6e, 0x73),
+    (0xf0, 0x56, 0x1d),
+    (0x5b, 0xa3, 0x52),
+    (0xf4, 0xc1, 0x45),
+    (0x2b, 0x9a, 0xf3),
+    (0xa1, 0x8f, 0xff),
+    (0x73, 0xc5, 0xc5),
+    (0xff, 0xff, 0xff),
+];
+
+impl AnsiColor {
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Self::Indexed(index @ 0..=15) => COLORS[index as usize],
+            Self::Indexed(index @ 16..=231) => {
+                let level = |value: u8| match value {
+                    0 => 0,
+                    value => 55 + value * 40,
+                };
+                let index = index - 16;
+                (level(index / 36), level(index / 6 % 6), level(index % 6))
+            }
+            Self::Indexed(index) => {
+                let gray = 8 + (index - 232) * 10;
+                (gray, gray, gray)
+            }
+            Self::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+}
+
+/// The style of a text.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AnsiStyle {
+    pub foreground: Option<AnsiColor>,
+    pub background: Option<AnsiColor>,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+impl AnsiStyle {
+    /// The inline style, [`None`] for the default style.
+    pub fn css(&self) -> Option<String> {
+        if *self == Self::default() {
+            return None;
+        }
+
+        let mut css = String::new();
+        if let Some((r, g, b)) = self.foreground.map(|color| color.rgb()) {
+            css.push_str(&format!("color: rgb({r}, {g}, {b});"));
+        }
+        if let Some((r, g, b)) = self.background.map(|color| color.rgb()) {
+            css.push_str(&format!("background-color: rgb({r}, {g}, {b});"));
+        }
+        if self.bold {
+            css.push_str("font-weight: bold;");
+        }
+        if self.italic {
+            css.push_str("font-style: italic;");
+        }
+        if self.underline {
+            css.push_str("text-decoration: underline;");
+        }
+        Some(css)
+    }
+
+    /// Apply the parameters of a "select graphic rendition" sequence.
+    fn apply(&mut self, params: &str) {
+        let mut params = params
+            .split(';')
+            .map(|param| param.parse::<u8>().unwrap_or_default());
+
+        while let Some(param) = params.next() {
+            match param {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                22 => self.bold = false,
+                23 => self.italic = false,
+                24 => self.underline = false,
+                30..=37 => self.foreground = Some(AnsiColor::Indexed(param - 30)),
+                38 => self.foreground = extended_color(&mut params),
+                39 => self.foreground = None,
+                40..=47 => self.background = Some(AnsiColor::Indexed(param - 40)),
+                48 => self.background = extended_color(&mut params),
+                49 => self.background = None,
+                90..=97 => self.foreground = Some(AnsiColor::Indexed(param - 90 + 8)),
+                100..=107 => self.background = Some(AnsiColor::Indexed(param - 100 + 8)),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Parse the color of a `38` or `48` parameter.
+fn extended_color(params: &mut impl Iterator<Item = u8>) -> Option<AnsiColor> {
+    match params.next()? {
+        5 => Some(AnsiColor::Indexed(params.next()?)),
+        2 => Some(AnsiColor::Rgb(
+            params.next()?,
+            params.next()?,
+            params.next()?,
+        )),
+        _ => None,
+    }
+}
+
+/// A part of a line, sharing the same style.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AnsiSpan<'a> {
+    pub text: &'a str,
+    pub style: AnsiStyle,
+}
+
+/// Split a line into styled spans, removing all escape sequences.
+///
+/// Each line starts with the default style.
+pub fn parse_ansi(line: &str) -> Vec<AnsiSpan<'_>> {
+    let mut spans = Vec::new();
+    let mut style = AnsiStyle::default();
+    let mut rest = line;
+
+    while let Some(start) = rest.find('\x1b') {
+        if start > 0 {
+            spans.push(AnsiSpan {
+                text: &rest[..start],
+                style,
+            });
+        }
+
+        let sequence = &rest[start + 1..];
+        rest = match sequence.strip_prefix('[') {
+            // control sequence, terminated by a byte in the range of `@` to `~`
+            Some(sequence) => match sequence.find(|c| ('@'..='~').contains(&c)) {
+                Some(end) => {
+                    if sequence[end..].starts_with('m') {
+                        style.apply(&sequence[..end]);
+                    }
+                    &sequence[end + 1..]
+                }
+                None => "",
+            },
+            // other escape sequences, skipping the next character
+            None => {
+                let mut chars = sequence.chars();
+                chars.next();
+                chars.as_str()
+            }
+        };
+    }
+
+    if !rest.is_empty() {
+        spans.push(AnsiSpan { text: rest, style });
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_ansi("plain"),
+            vec![AnsiSpan {
+                text: "plain",
+                style: AnsiStyle::default()
+            }]
+        );
+
+        let red = AnsiStyle {
+            foreground: Some(AnsiColor::Indexed(1)),
+            bold: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_ansi("\x1b[1;31mERROR\x1b[0m: \x1b[2Kfailed"),
+            vec![
+                AnsiSpan {
+                    text: "ERROR",
+                    style: red
+                },
+                AnsiSpan {
+                    text: ": ",
+                    style: AnsiStyle::default()
+                },
+                AnsiSpan {
+                    text: "failed",
+                    style: AnsiStyle::default()
+                },
+            ]
+        );
+
+        assert_eq!(
+            parse_ansi("\x1b[38;5;196;48;2;1;2;3mx\x1b[39;49m\x1b[")
+                .into_iter()
+                .map(|span| span.style)
+                .collect::<Vec<_>>(),
+            vec![AnsiStyle {
+                foreground: Some(AnsiColor::Indexed(196)),
+                background: Some(AnsiColor::Rgb(1, 2, 3)),
+                ..Default::default()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_rgb() {
+        assert_eq!(AnsiColor::Indexed(196).rgb(), (255, 0, 0));
+        assert_eq!(AnsiColor::Indexed(16).rgb(), (0, 0, 0));
+        assert_eq!(AnsiColor::Indexed(255).rgb(), (238, 238, 238));
+    }
+}
@@ -0,0 +1,103 @@
+//! Layout of the virtualized lines.
+
+use std::ops::Range;
+
+/// The estimated height of a line, before any line was measured.
+const DEFAULT_HEIGHT: f64 = 21.0;
+
+/// The heights of the lines, as far as they have been rendered and measured.
+#[derive(Clone, Debug, Default)]
+pub struct Heights {
+    measured: Vec<Option<f64>>,
+    /// The height of lines which haven't been measured yet.
+    estimate: Option<f64>,
+}
+
+impl Heights {
+    /// Forget all measurements, e.g. when the width of the lines changed.
+    pub fn reset(&mut self) {
+        self.measured.clear();
+    }
+
+    /// Record the height of a line, returning `true` if it changed.
+    pub fn measure(&mut self, index: usize, height: f64) -> bool {
+        if self.measured.len() <= index {
+            self.measured.resize(index + 1, None);
+        }
+        self.estimate = Some(
+            self.estimate
+                .map_or(height, |estimate| estimate.min(height)),
+        );
+
+        let changed = self.measured[index].map_or(true, |current| (current - height).abs() > 0.5);
+        self.measured[index] = Some(height);
+        changed
+    }
+
+    /// The offsets of `len` lines, followed by the total height.
+    pub fn offsets(&self, len: usize) -> Vec<f64> {
+        let estimate = self.estimate.unwrap_or(DEFAULT_HEIGHT);
+
+        let mut offsets = Vec::with_capacity(len + 1);
+        let mut offset = 0.0;
+        offsets.push(offset);
+        for index in 0..len {
+            offset += self
+                .measured
+                .get(index)
+                .copied()
+                .flatten()
+                .unwrap_or(estimate);
+            offsets.push(offset);
+        }
+        offsets
+    }
+}
+
+/// The range of lines to render, for the visible area of the scroll container.
+///
+/// Adds `overscan` lines before and after the visible lines.
+pub fn visible_range(offsets: &[f64], top: f64, height: f64, overscan: usize) -> Range<usize> {
+    let len = offsets.len().saturating_sub(1);
+    if len == 0 {
+        return 0..0;
+    }
+
+    // the first line ending below the top
+    let first = offsets[1..].partition_point(|end| *end <= top);
+    // the lines starting above the bottom
+    let last = offsets[..len].partition_point(|start| *start < top + height);
+
+    first.min(len).saturating_sub(overscan)..(last.max(first) + overscan).min(len)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_offsets() {
+        let mut heights = Heights::default();
+        assert_eq!(heights.offsets(2), vec![0.0, 21.0, 42.0]);
+
+        assert!(heights.measure(1, 40.0));
+        assert!(!heights.measure(1, 40.25));
+        assert!(heights.measure(0, 20.0));
+        assert_eq!(heights.offsets(3), vec![0.0, 20.0, 60.25, 80.25]);
+
+        heights.reset();
+        assert_eq!(heights.offsets(1), vec![0.0, 20.0]);
+    }
+
+    #[test]
+    fn test_visible_range() {
+        let offsets = (0..=100).map(|i| i as f64 * 10.0).collect::<Vec<_>>();
+
+        assert_eq!(visible_range(&offsets, 0.0, 50.0, 0), 0..5);
+        assert_eq!(visible_range(&offsets, 5.0, 50.0, 0), 0..6);
+        assert_eq!(visible_range(&offsets, 500.0, 50.0, 2), 48..57);
+        assert_eq!(visible_range(&offsets, 980.0, 50.0, 5), 93..100);
+        assert_eq!(visible_range(&offsets, 0.0, 0.0, 3), 0..3);
+        assert_eq!(visible_range(&[0.0], 0.0, 50.0, 3), 0..0);
+    }
+}
@@ -0,0 +1,193 @@
+use futures::future::{abortable, AbortHandle};
+use futures::{Stream, StreamExt};
+use std::cell::{Ref, RefCell};
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+use yew::prelude::*;
+
+/// The lines of a log, shown by a [`super::LogViewer`].
+///
+/// Lines can be appended incrementally using [`use_log_lines`]. For a static log, the lines can
+/// also be collected from an iterator.
+///
+/// Cloning is cheap, as the lines are shared.
+#[derive(Clone, Default)]
+pub struct LogLines {
+    lines: Rc<RefCell<Vec<String>>>,
+    version: usize,
+    cleared: usize,
+}
+
+impl LogLines {
+    pub fn len(&self) -> usize {
+        self.lines.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.borrow().is_empty()
+    }
+
+    pub(super) fn borrow(&self) -> Ref<'_, Vec<String>> {
+        self.lines.borrow()
+    }
+
+    /// Check if the lines are the lines of a previous version of the same log, with lines only
+    /// appended since.
+    pub(super) fn extends(&self, previous: &LogLines) -> bool {
+        Rc::ptr_eq(&self.lines, &previous.lines) && self.cleared == previous.cleared
+    }
+}
+
+/// Lines are equal if they are the same version of the same log.
+impl PartialEq for LogLines {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.lines, &other.lines) && self.version == other.version
+    }
+}
+
+impl Debug for LogLines {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LogLines")
+            .field("len", &self.len())
+            .field("version", &self.version)
+            .finish()
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for LogLines {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self {
+            lines: Rc::new(RefCell::new(iter.into_iter().map(Into::into).collect())),
+            version: 0,
+            cleared: 0,
+        }
+    }
+}
+
+impl From<Vec<String>> for LogLines {
+    fn from(lines: Vec<String>) -> Self {
+        Self {
+            lines: Rc::new(RefCell::new(lines)),
+            version: 0,
+            cleared: 0,
+        }
+    }
+}
+
+/// A handle for appending lines to a log, see [`use_log_lines`].
+#[derive(Clone)]
+pub struct UseLogLines {
+    lines: Rc<RefCell<Vec<String>>>,
+    version: Rc<RefCell<usize>>,
+    cleared: Rc<RefCell<usize>>,
+    trigger: Callback<()>,
+}
+
+impl UseLogLines {
+    /// The current lines, to be passed to a [`super::LogViewer`].
+    pub fn lines(&self) -> LogLines {
+        LogLines {
+            lines: self.lines.clone(),
+            version: *self.version.borrow(),
+            cleared: *self.cleared.borrow(),
+        }
+    }
+
+    pub fn push(&self, line: impl Into<String>) {
+        self.append([line]);
+    }
+
+    pub fn append<S: Into<String>>(&self, lines: impl IntoIterator<Item = S>) {
+        self.lines
+            .borrow_mut()
+            .extend(lines.into_iter().map(Into::into));
+        self.changed();
+    }
+
+    pub fn clear(&self) {
+        self.lines.borrow_mut().clear();
+        *self.cleared.borrow_mut() += 1;
+        self.changed();
+    }
+
+    /// Append all lines of a stream, like the receiving end of a channel.
+    ///
+    /// Lines which are ready at the same time are appended at once. Aborting the returned
+    /// handle stops appending lines.
+    pub fn append_stream<S>(&self, stream: S) -> AbortHandle
+    where
+        S: Stream + 'static,
+        S::Item: Into<String>,
+    {
+        let this = self.clone();
+        let (task, handle) = abortable(async move {
+            let mut chunks = Box::pin(stream.ready_chunks(1024));
+            while let Some(chunk) = chunks.next().await {
+                this.append(chunk);
+            }
+        });
+        wasm_bindgen_futures::spawn_local(async move {
+            let _ = task.await;
+        });
+        handle
+    }
+
+    fn changed(&self) {
+        *self.version.borrow_mut() += 1;
+        self.trigger.emit(());
+    }
+
+    /// A log which isn't bound to a component.
+    #[cfg(test)]
+    pub(super) fn detached() -> Self {
+        Self {
+            lines: Default::default(),
+            version: Default::default(),
+            cleared: Default::default(),
+            trigger: Callback::noop(),
+        }
+    }
+}
+
+/// Create a log, which can be appended to.
+///
+/// Modifying the log re-renders the calling component.
+///
+/// ## Example
+///
+/// ```
+/// use futures::channel::mpsc;
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let log = use_log_lines();
+///
+///   use_effect_with((), {
+///     let log = log.clone();
+///     move |()| {
+///       let (_sender, receiver) = mpsc::unbounded::<String>();
+///       // hand out the sender to the producer of the log
+///       let handle = log.append_stream(receiver);
+///       move || handle.abort()
+///     }
+///   });
+///
+///   html!(<LogViewer lines={log.lines()} />)
+/// }
+/// ```
+#[hook]
+pub fn use_log_lines() -> UseLogLines {
+    let lines = use_mut_ref(Vec::new);
+    let version = use_mut_ref(|| 0);
+    let cleared = use_mut_ref(|| 0);
+    let force_update = use_force_update();
+
+    UseLogLines {
+        lines,
+        version,
+        cleared,
+        trigger: Callback::from(move |()| force_update.force_update()),
+    }
+}
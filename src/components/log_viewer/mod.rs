@@ -0,0 +1,400 @@
+//! Log viewer
+use crate::prelude::{
+    Button, ButtonVariant, ResultsCount, SearchInput, Switch, Toolbar, ToolbarContent, ToolbarItem,
+};
+use web_sys::Element;
+use yew::prelude::*;
+use yew_hooks::use_event_with_window;
+
+mod ansi;
+mod layout;
+mod lines;
+mod search;
+
+pub use lines::*;
+
+use ansi::parse_ansi;
+use layout::{visible_range, Heights};
+use search::{split_matches, LogPiece, LogSearch};
+
+/// Properties for [`LogViewer`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct LogViewerProperties {
+    /// The lines of the log.
+    pub lines: LogLines,
+
+    /// The height of the scroll container, as CSS value.
+    #[prop_or(AttrValue::Static("600px"))]
+    pub height: AttrValue,
+
+    #[prop_or(true)]
+    pub line_numbers: bool,
+
+    /// Wrap long lines, instead of scrolling horizontally.
+    #[prop_or_default]
+    pub wrap: bool,
+
+    /// Follow the end of the log, scrolling down when lines get appended.
+    ///
+    /// Following stops when the user scrolls up, and resumes when scrolling to the bottom.
+    #[prop_or(true)]
+    pub follow: bool,
+
+    /// Show the toolbar, with the search and the wrap and follow toggles.
+    #[prop_or(true)]
+    pub toolbar: bool,
+
+    /// The number of lines rendered before and after the visible lines.
+    #[prop_or(20)]
+    pub overscan: usize,
+
+    #[prop_or(AttrValue::Static("Search"))]
+    pub search_placeholder: AttrValue,
+    #[prop_or(AttrValue::Static("Search the log"))]
+    pub search_aria_label: AttrValue,
+    #[prop_or(AttrValue::Static("Wrap lines"))]
+    pub wrap_text: AttrValue,
+    #[prop_or(AttrValue::Static("Follow"))]
+    pub follow_text: AttrValue,
+    #[prop_or(AttrValue::Static("Jump to the bottom"))]
+    pub jump_to_bottom_text: AttrValue,
+}
+
+/// The scroll position and the height of the scroll container.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+struct Viewport {
+    top: f64,
+    height: f64,
+}
+
+/// Log viewer component
+///
+/// > A **log viewer** is a read-only window that displays the logs of a container, build, or
+/// > other process.
+///
+/// Only the visible lines are rendered, so that logs with many lines can be shown. Lines may
+/// contain ANSI escape sequences for colors and text styles.
+///
+/// See: <https://www.patternfly.org/extensions/log-viewer>
+///
+/// ## Properties
+///
+/// Defined by [`LogViewerProperties`].
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let lines = use_memo((), |()| {
+///     LogLines::from_iter(["Starting", "\x1b[32mDone\x1b[0m"])
+///   });
+///
+///   html!(
+///     <LogViewer lines={(*lines).clone()} height="300px" wrap=true />
+///   )
+/// }
+/// ```
+///
+/// Lines can also be appended while showing the log, see [`use_log_lines`].
+#[function_component(LogViewer)]
+pub fn log_viewer(props: &LogViewerProperties) -> Html {
+    let container = use_node_ref();
+    let list = use_node_ref();
+    let heights = use_mut_ref(Heights::default);
+    let viewport = use_state_eq(Viewport::default);
+
+    let force_update = use_force_update();
+    let trigger = Callback::from(move |()| force_update.force_update());
+
+    // the toggles are initialized from the properties, and reset when those change
+    let wrap = use_state_eq(|| props.wrap);
+    use_effect_with(props.wrap, {
+        let wrap = wrap.clone();
+        move |value| wrap.set(*value)
+    });
+    let following = use_state_eq(|| props.follow);
+    use_effect_with(props.follow, {
+        let following = following.clone();
+        move |value| following.set(*value)
+    });
+
+    let search = use_state_eq(String::new);
+    let current = use_state_eq(|| 0usize);
+    let matches = use_mut_ref(LogSearch::default)
+        .borrow_mut()
+        .update(&props.lines, &search);
+
+    // line heights change with the width of the lines
+    use_effect_with(*wrap, {
+        let heights = heights.clone();
+        let trigger = trigger.clone();
+        move |_| {
+            heights.borrow_mut().reset();
+            trigger.emit(());
+        }
+    });
+    {
+        let heights = heights.clone();
+        let trigger = trigger.clone();
+        use_event_with_window("resize", move |_: Event| {
+            heights.borrow_mut().reset();
+            trigger.emit(());
+        });
+    }
+
+    // measure the rendered lines, and the scroll container
+    {
+        let container = container.clone();
+        let list = list.clone();
+        let heights = heights.clone();
+        let viewport = viewport.clone();
+        let trigger = trigger.clone();
+        use_effect(move || {
+            let mut changed = false;
+            if let Some(list) = list.cast::<Element>() {
+                let rows = list.children();
+                for row in (0..rows.length()).filter_map(|index| rows.item(index)) {
+                    if let Some(index) = row
+                        .get_attribute("data-index")
+                        .and_then(|index| index.parse().ok())
+                    {
+                        changed |= heights
+                            .borrow_mut()
+                            .measure(index, row.get_bounding_client_rect().height());
+                    }
+                }
+            }
+            if let Some(container) = container.cast::<Element>() {
+                viewport.set(Viewport {
+                    top: container.scroll_top() as f64,
+                    height: container.client_height() as f64,
+                });
+            }
+            if changed {
+                trigger.emit(());
+            }
+        });
+    }
+
+    let lines = props.lines.borrow();
+    let offsets = heights.borrow().offsets(lines.len());
+    let total = offsets[lines.len()];
+
+    // scroll down when following
+    use_effect_with(
+        (total, *following, container.clone()),
+        |(_, following, container)| {
+            if let Some(container) = container.cast::<Element>().filter(|_| *following) {
+                container.set_scroll_top(container.scroll_height());
+            }
+        },
+    );
+
+    // scroll to the current match
+    use_effect_with(((*search).clone(), *current), {
+        let matches = matches.clone();
+        let container = container.clone();
+        let heights = heights.clone();
+        let following = following.clone();
+        move |(_, current)| {
+            let Some(line) = matches.get(*current).map(|m| m.line) else {
+                return;
+            };
+            following.set(false);
+            if let Some(container) = container.cast::<Element>() {
+                let top = heights.borrow().offsets(line)[line];
+                let top = top - container.client_height() as f64 / 3.0;
+                container.set_scroll_top(top.max(0.0) as i32);
+            }
+        }
+    });
+
+    let onscroll = use_callback(
+        (container.clone(), viewport.clone(), following.clone()),
+        |_: Event, (container, viewport, following)| {
+            if let Some(container) = container.cast::<Element>() {
+                let top = container.scroll_top();
+                let height = container.client_height();
+                viewport.set(Viewport {
+                    top: top as f64,
+                    height: height as f64,
+                });
+                following.set(top + height >= container.scroll_height() - 2);
+            }
+        },
+    );
+
+    let onjump = use_callback(
+        (container.clone(), following.clone()),
+        |_: MouseEvent, (container, following)| {
+            following.set(true);
+            if let Some(container) = container.cast::<Element>() {
+                container.set_scroll_top(container.scroll_height());
+            }
+        },
+    );
+
+    let onsearch = use_callback(
+        (search.clone(), current.clone()),
+        |value: String, (search, current)| {
+            current.set(0);
+            search.set(value);
+        },
+    );
+    let onclear = use_callback(
+        (search.clone(), current.clone()),
+        |_: MouseEvent, (search, current)| {
+            current.set(0);
+            search.set(String::new());
+        },
+    );
+    let onnext = use_callback(
+        (current.clone(), matches.len()),
+        |_: MouseEvent, (current, len)| {
+            if *len > 0 {
+                current.set((**current + 1) % len);
+            }
+        },
+    );
+    let onprevious = use_callback(
+        (current.clone(), matches.len()),
+        |_: MouseEvent, (current, len)| {
+            if *len > 0 {
+                current.set((**current + len - 1) % len);
+            }
+        },
+    );
+
+    let onwrap = use_callback(wrap.clone(), |value: bool, wrap| wrap.set(value));
+    let onfollow = use_callback(following.clone(), |value: bool, following| {
+        following.set(value)
+    });
+
+    let results_count = match matches.len() {
+        0 => ResultsCount::Absolute(0),
+        len => ResultsCount::Fraction((*current).min(len - 1) + 1, len),
+    };
+
+    let range = visible_range(&offsets, viewport.top, viewport.height, props.overscan);
+    let rows = range.map(|index| {
+        let start = matches.partition_point(|m| m.line < index);
+        let end = matches.partition_point(|m| m.line <= index);
+        let marks = matches[start..end]
+            .iter()
+            .enumerate()
+            .map(|(n, m)| (m.range.clone(), start + n == *current))
+            .collect::<Vec<_>>();
+
+        html!(
+            <div
+                key={index}
+                class="pf-v5-c-log-viewer__list-item"
+                data-index={index.to_string()}
+                style={format!("position: absolute; top: {}px; left: 0; min-width: 100%;", offsets[index])}
+            >
+                if props.line_numbers {
+                    <span class="pf-v5-c-log-viewer__index">{ index + 1 }</span>
+                }
+                <span class="pf-v5-c-log-viewer__text">
+                    { for split_matches(&parse_ansi(&lines[index]), &marks).into_iter().map(piece) }
+                </span>
+            </div>
+        )
+    });
+
+    let mut class = classes!("pf-v5-c-log-viewer");
+    if *wrap {
+        class.push(classes!("pf-m-wrap-text"));
+    }
+    if props.line_numbers {
+        class.push(classes!("pf-m-line-numbers"));
+    }
+
+    let at_bottom = viewport.top + viewport.height >= total - 2.0;
+
+    html!(
+        <div
+            {class}
+            style={format!("--pf-v5-c-log-viewer--line-number-chars: {};", lines.len().to_string().len())}
+        >
+            if props.toolbar {
+                <div class="pf-v5-c-log-viewer__header">
+                    <Toolbar>
+                        <ToolbarContent>
+                            <ToolbarItem>
+                                <SearchInput
+                                    aria_label={props.search_aria_label.clone()}
+                                    placeholder={props.search_placeholder.clone()}
+                                    value={(*search).clone()}
+                                    onchange={onsearch}
+                                    {onclear}
+                                    {results_count}
+                                    onnextclick={onnext}
+                                    onpreviousclick={onprevious}
+                                    next_navigation_button_disabled={matches.is_empty()}
+                                    previous_navigation_button_disabled={matches.is_empty()}
+                                />
+                            </ToolbarItem>
+                            <ToolbarItem>
+                                <Switch
+                                    label={props.wrap_text.to_string()}
+                                    checked={*wrap}
+                                    onchange={onwrap}
+                                />
+                            </ToolbarItem>
+                            <ToolbarItem>
+                                <Switch
+                                    label={props.follow_text.to_string()}
+                                    checked={*following}
+                                    onchange={onfollow}
+                                />
+                            </ToolbarItem>
+                        </ToolbarContent>
+                    </Toolbar>
+                </div>
+            }
+            <div class="pf-v5-c-log-viewer__main" role="log">
+                <div
+                    ref={container}
+                    class="pf-v5-c-log-viewer__scroll-container"
+                    tabindex="0"
+                    style={format!("height: {}; overflow: auto;", props.height)}
+                    {onscroll}
+                >
+                    <div
+                        ref={list}
+                        class="pf-v5-c-log-viewer__list"
+                        style={format!("position: relative; height: {total}px;")}
+                    >
+                        { for rows }
+                    </div>
+                </div>
+            </div>
+            if !at_bottom {
+                <Button variant={ButtonVariant::Secondary} block=true onclick={onjump}>
+                    { &props.jump_to_bottom_text }
+                </Button>
+            }
+        </div>
+    )
+}
+
+fn piece(piece: LogPiece) -> Html {
+    let text = match piece.style.css() {
+        Some(style) => html!(<span {style}>{ piece.text }</span>),
+        None => html!({ piece.text }),
+    };
+
+    match piece.matched {
+        Some(current) => html!(
+            <span class={classes!("pf-v5-c-log-viewer__string", "pf-m-match", current.then_some("pf-m-current"))}>
+                { text }
+            </span>
+        ),
+        None => text,
+    }
+}
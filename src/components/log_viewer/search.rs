@@ -0,0 +1,246 @@
+//! Searching the lines of a log.
+
+use super::ansi::{parse_ansi, AnsiSpan, AnsiStyle};
+use super::LogLines;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// A match of the search, in the text of a line without escape sequences.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogMatch {
+    pub line: usize,
+    pub range: Range<usize>,
+}
+
+/// Find all matches of the search in the lines, starting at line `first`, ignoring the ASCII case.
+fn find_matches(lines: &[String], first: usize, search: &str) -> Vec<LogMatch> {
+    if search.is_empty() {
+        return vec![];
+    }
+
+    let search = search.to_ascii_lowercase();
+    let mut result = Vec::new();
+
+    for (line, text) in lines.iter().enumerate().skip(first) {
+        let text = parse_ansi(text)
+            .into_iter()
+            .map(|span| span.text)
+            .collect::<String>()
+            .to_ascii_lowercase();
+
+        let mut start = 0;
+        while let Some(index) = text[start..].find(&search) {
+            let begin = start + index;
+            start = begin + search.len();
+            result.push(LogMatch {
+                line,
+                range: begin..start,
+            });
+        }
+    }
+
+    result
+}
+
+/// The matches of a search in a log, kept up to date while lines are appended.
+///
+/// Only appended lines are searched. All lines are searched again when the search changes, or
+/// the lines belong to a different log or got cleared.
+#[derive(Default)]
+pub struct LogSearch {
+    search: String,
+    /// The log which was searched, and the number of lines searched.
+    searched: Option<(LogLines, usize)>,
+    matches: Rc<Vec<LogMatch>>,
+}
+
+impl LogSearch {
+    /// Update the matches for the current lines and search, returning them.
+    pub fn update(&mut self, lines: &LogLines, search: &str) -> Rc<Vec<LogMatch>> {
+        let len = lines.len();
+        let first = match &self.searched {
+            Some((previous, searched))
+                if self.search == search && lines.extends(previous) && *searched <= len =>
+            {
+                *searched
+            }
+            _ => {
+                self.search = search.to_string();
+                self.matches = Default::default();
+                0
+            }
+        };
+
+        if first < len {
+            let found = find_matches(&lines.borrow(), first, search);
+            if !found.is_empty() {
+                Rc::make_mut(&mut self.matches).extend(found);
+            }
+        }
+        self.searched = Some((lines.clone(), len));
+
+        self.matches.clone()
+    }
+}
+
+/// A part of a line, sharing the same style and match state.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LogPiece<'a> {
+    pub text: &'a str,
+    pub style: AnsiStyle,
+    /// If the piece is part of a match, and if that is the current match.
+    pub matched: Option<bool>,
+}
+
+/// Split the spans of a line at the boundaries of the matches.
+///
+/// The matches must be sorted, and may be flagged as the current match.
+pub fn split_matches<'a>(
+    spans: &[AnsiSpan<'a>],
+    matches: &[(Range<usize>, bool)],
+) -> Vec<LogPiece<'a>> {
+    let mut pieces = Vec::new();
+    let mut offset = 0;
+
+    for span in spans {
+        let end = offset + span.text.len();
+        let mut position = offset;
+
+        while position < end {
+            let current = matches
+                .iter()
+                .find(|(range, _)| range.end > position)
+                .filter(|(range, _)| range.start <= position);
+
+            let next = match current {
+                Some((range, _)) => range.end,
+                None => matches
+                    .iter()
+                    .map(|(range, _)| range.start)
+                    .find(|start| *start > position)
+                    .unwrap_or(end),
+            }
+            .min(end);
+
+            pieces.push(LogPiece {
+                text: &span.text[position - offset..next - offset],
+                style: span.style,
+                matched: current.map(|(_, current)| *current),
+            });
+            position = next;
+        }
+
+        offset = end;
+    }
+
+    pieces
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::UseLogLines;
+
+    #[test]
+    fn test_find_matches() {
+        let lines = vec![
+            "Foo foo".to_string(),
+            "bar".to_string(),
+            "\x1b[31mfo\x1b[0mo".to_string(),
+        ];
+
+        assert_eq!(find_matches(&lines, 0, ""), vec![]);
+        assert_eq!(
+            find_matches(&lines, 0, "FOO"),
+            vec![
+                LogMatch {
+                    line: 0,
+                    range: 0..3
+                },
+                LogMatch {
+                    line: 0,
+                    range: 4..7
+                },
+                LogMatch {
+                    line: 2,
+                    range: 0..3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_matches() {
+        let bold = AnsiStyle {
+            bold: true,
+            ..Default::default()
+        };
+        let spans = [
+            AnsiSpan {
+                text: "abc",
+                style: bold,
+            },
+            AnsiSpan {
+                text: "def",
+                style: AnsiStyle::default(),
+            },
+        ];
+
+        let pieces = split_matches(&spans, &[(1..4, true), (5..6, false)])
+            .into_iter()
+            .map(|piece| (piece.text, piece.matched))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            pieces,
+            vec![
+                ("a", None),
+                ("bc", Some(true)),
+                ("d", Some(true)),
+                ("e", None),
+                ("f", Some(false)),
+            ]
+        );
+
+        assert_eq!(split_matches(&spans, &[]).len(), 2);
+    }
+
+    #[test]
+    fn test_search() {
+        let log = UseLogLines::detached();
+        let mut search = LogSearch::default();
+        let lines = |search: &mut LogSearch, text: &str| {
+            search
+                .update(&log.lines(), text)
+                .iter()
+                .map(|m| m.line)
+                .collect::<Vec<_>>()
+        };
+
+        log.append(["foo", "bar"]);
+        assert_eq!(lines(&mut search, "foo"), vec![0]);
+
+        // only the appended lines are searched
+        log.append(["foo"]);
+        assert_eq!(lines(&mut search, "foo"), vec![0, 2]);
+
+        // a new search starts over
+        assert_eq!(lines(&mut search, "bar"), vec![1]);
+
+        // as does clearing the log, even if there are as many lines again
+        log.clear();
+        log.append(["bar", "baz", "bar"]);
+        assert_eq!(lines(&mut search, "bar"), vec![0, 2]);
+
+        // as does another log
+        let other = LogLines::from_iter(["x", "bar"]);
+        assert_eq!(
+            search
+                .update(&other, "bar")
+                .iter()
+                .map(|m| m.line)
+                .collect::<Vec<_>>(),
+            vec![1]
+        );
+    }
+}
@@ -9,6 +9,8 @@ pub enum MenuChild {
     Divider(Rc<<ListDivider as BaseComponent>::Properties>),
     Group(Rc<<MenuGroup as BaseComponent>::Properties>),
     Loading(Rc<<MenuLoading as BaseComponent>::Properties>),
+    Search(Rc<<MenuSearch as BaseComponent>::Properties>),
+    Checkbox(Rc<<MenuCheckbox as BaseComponent>::Properties>),
     Raw(Rc<<Raw as BaseComponent>::Properties>),
 }
 
@@ -42,6 +44,18 @@ impl From<MenuLoadingProperties> for MenuChild {
     }
 }
 
+impl From<MenuSearchProperties> for MenuChild {
+    fn from(props: MenuSearchProperties) -> Self {
+        MenuChild::Search(Rc::new(props))
+    }
+}
+
+impl From<MenuCheckboxProperties> for MenuChild {
+    fn from(props: MenuCheckboxProperties) -> Self {
+        MenuChild::Checkbox(Rc::new(props))
+    }
+}
+
 impl From<ChildrenProperties> for MenuChild {
     fn from(props: ChildrenProperties) -> Self {
         MenuChild::Raw(Rc::new(props))
@@ -64,3 +64,26 @@ pub fn use_close_menu_callback() -> Callback<()> {
     let context = use_close_menu();
     use_callback(context, |(), context| context.close())
 }
+
+/// A context to drill into the submenu of an action, provided to each action with a submenu by a
+/// [`super::Menu`] using drilldown.
+#[derive(Clone, PartialEq)]
+pub(crate) struct MenuDrilldownContext {
+    ondrill: Callback<()>,
+}
+
+impl MenuDrilldownContext {
+    pub fn new(ondrill: Callback<()>) -> Self {
+        Self { ondrill }
+    }
+
+    /// Drill into the submenu of the action
+    pub fn drill(&self) {
+        self.ondrill.emit(());
+    }
+}
+
+#[hook]
+pub(crate) fn use_menu_drilldown() -> Option<MenuDrilldownContext> {
+    use_context()
+}
@@ -1,6 +1,6 @@
-use super::use_close_menu_callback;
+use super::{focus_first_item, use_close_menu_callback, use_menu_drilldown, MenuGroup};
 use crate::prelude::Icon;
-use yew::prelude::*;
+use yew::{prelude::*, virtual_dom::VChild};
 
 #[derive(Clone, Debug, PartialEq, Properties)]
 struct MenuItemProperties {
@@ -13,6 +13,12 @@ struct MenuItemProperties {
     pub description: Option<String>,
     pub style: Option<AttrValue>,
     pub class: Classes,
+    pub submenu: Option<VChild<MenuGroup>>,
+    /// Drill into the submenu, instead of showing it as a flyout
+    pub ondrill: Option<Callback<()>>,
+    pub favorite: bool,
+    pub onfavorite: Option<Callback<bool>>,
+    pub external: bool,
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -35,6 +41,35 @@ fn menu_item(props: &MenuItemProperties) -> Html {
 
     let onclose = use_close_menu_callback();
 
+    // the state of a flyout submenu, and if it should receive the focus when opened
+    let flyout = use_state_eq(|| false);
+    let focus_flyout = use_state_eq(|| false);
+    let flyout_ref = use_node_ref();
+
+    use_effect_with(
+        (*flyout, *focus_flyout, flyout_ref.clone()),
+        |(flyout, focus_flyout, flyout_ref)| {
+            if *flyout && *focus_flyout {
+                focus_first_item(flyout_ref);
+            }
+        },
+    );
+
+    let (onmouseenter, onmouseleave) = match props.submenu.is_some() && props.ondrill.is_none() {
+        true => {
+            let enter = flyout.clone();
+            let leave = (flyout.clone(), focus_flyout.clone());
+            (
+                Some(Callback::from(move |_: MouseEvent| enter.set(true))),
+                Some(Callback::from(move |_: MouseEvent| {
+                    leave.0.set(false);
+                    leave.1.set(false);
+                })),
+            )
+        }
+        false => (None, None),
+    };
+
     let mut item_class = classes!("pf-v5-c-menu__item");
     if props.selected {
         item_class.push(classes!("pf-m-selected"));
@@ -42,6 +77,30 @@ fn menu_item(props: &MenuItemProperties) -> Html {
 
     let element = |content: Html| match &props.r#type {
         MenuItemType::Button(callback) => {
+            let onclick = match (&props.submenu, &props.ondrill) {
+                // submenus don't close the menu
+                (Some(_), Some(ondrill)) => {
+                    let ondrill = ondrill.clone();
+                    callback.reform(move |_| ondrill.emit(()))
+                }
+                (Some(_), None) => {
+                    let flyout = flyout.clone();
+                    let focus_flyout = focus_flyout.clone();
+                    callback.reform(move |_| {
+                        focus_flyout.set(true);
+                        flyout.set(!*flyout);
+                    })
+                }
+                (None, _) => callback.reform(move |_| {
+                    onclose.emit(());
+                }),
+            };
+
+            let (aria_haspopup, aria_expanded) = match &props.submenu {
+                Some(_) => (Some("menu"), Some(flyout.to_string())),
+                None => (None, None),
+            };
+
             html!(
                 <button
                     class={item_class}
@@ -49,9 +108,9 @@ fn menu_item(props: &MenuItemProperties) -> Html {
                     role="menuitem"
                     tabindex="-1"
                     disabled={props.disabled}
-                    onclick={callback.reform(move |_| {
-                        onclose.emit(());
-                    })}
+                    aria-haspopup={aria_haspopup}
+                    aria-expanded={aria_expanded}
+                    {onclick}
                 >
                     { content }
                 </button>
@@ -62,11 +121,13 @@ fn menu_item(props: &MenuItemProperties) -> Html {
                 true => Some("-1"),
                 false => None,
             };
+            // don't give external pages access to the opening window
+            let rel = props.external.then_some("noopener noreferrer");
 
             html!(
                 <a
                     class={item_class}
-                    {href} {target}
+                    {href} {target} {rel}
                     onclick={onclose.reform(|_|())}
                     aria-disabled={props.disabled.to_string()}
                     {tabindex}
@@ -81,7 +142,7 @@ fn menu_item(props: &MenuItemProperties) -> Html {
     class.extend(&props.class);
 
     html!(
-        <li {class} style={&props.style}>
+        <li {class} style={&props.style} {onmouseenter} {onmouseleave}>
             { element(html!(
                 <>
                     <span class="pf-v5-c-menu__item-main">
@@ -97,16 +158,61 @@ fn menu_item(props: &MenuItemProperties) -> Html {
                         if props.selected {
                             <span class="pf-v5-c-menu__item-select-icon">{ Icon::Check }</span>
                         }
+
+                        if props.submenu.is_some() {
+                            <span class="pf-v5-c-menu__item-toggle-icon">{ Icon::AngleRight }</span>
+                        }
+
+                        if props.external {
+                            <span class="pf-v5-c-menu__item-external-icon">{ Icon::ExternalLinkAlt }</span>
+                            <span class="pf-v5-screen-reader">{ "(opens a new window)" }</span>
+                        }
                     </span>
                     if let Some(description) = &props.description {
                         <span class="pf-v5-c-menu__item-description"> {description} </span>
                     }
                 </>
             )) }
+            if let Some(onfavorite) = &props.onfavorite {
+                { favorite_action(props.favorite, onfavorite) }
+            }
+            if let Some(submenu) = props.submenu.clone().filter(|_| *flyout) {
+                <div class="pf-v5-c-menu pf-m-flyout" ref={flyout_ref}>
+                    <div class="pf-v5-c-menu__content">
+                        { submenu }
+                    </div>
+                </div>
+            }
         </li>
     )
 }
 
+fn favorite_action(favorite: bool, onfavorite: &Callback<bool>) -> Html {
+    let mut class = classes!("pf-v5-c-menu__item-action", "pf-m-favorite");
+    if favorite {
+        class.push(classes!("pf-m-favorited"));
+    }
+
+    let aria_label = match favorite {
+        true => "starred",
+        false => "not starred",
+    };
+
+    html!(
+        <button
+            {class}
+            type="button"
+            tabindex="-1"
+            aria-label={aria_label}
+            onclick={onfavorite.reform(move |_| !favorite)}
+        >
+            <span class="pf-v5-c-menu__item-action-icon">
+                <i class="fas fa-star" aria-hidden="true"></i>
+            </span>
+        </button>
+    )
+}
+
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct MenuActionProperties {
     #[prop_or_default]
@@ -135,10 +241,32 @@ pub struct MenuActionProperties {
 
     #[prop_or_default]
     pub class: Classes,
+
+    /// A submenu, shown as flyout, or drilled into when the [`super::Menu`] uses drilldown.
+    ///
+    /// When the action has a submenu, clicking on it doesn't close the menu.
+    #[prop_or_default]
+    pub submenu: Option<VChild<MenuGroup>>,
+
+    /// Mark the item as favorite.
+    #[prop_or_default]
+    pub favorite: bool,
+
+    /// Called with the new favorite state, when the user toggles it.
+    ///
+    /// The favorite toggle is only shown when this is set.
+    #[prop_or_default]
+    pub onfavorite: Option<Callback<bool>>,
 }
 
 #[function_component(MenuAction)]
 pub fn menu_action(props: &MenuActionProperties) -> Html {
+    let drilldown = use_menu_drilldown();
+    let ondrill = match (&props.submenu, drilldown) {
+        (Some(_), Some(drilldown)) => Some(Callback::from(move |()| drilldown.drill())),
+        _ => None,
+    };
+
     // we use destructing and struct initialization here to ensure we're not missing any new field
 
     let MenuActionProperties {
@@ -151,6 +279,9 @@ pub fn menu_action(props: &MenuActionProperties) -> Html {
         selected,
         style,
         class,
+        submenu,
+        favorite,
+        onfavorite,
     } = props.clone();
 
     let props = MenuItemProperties {
@@ -163,6 +294,11 @@ pub fn menu_action(props: &MenuActionProperties) -> Html {
         selected,
         style,
         class,
+        submenu,
+        ondrill,
+        favorite,
+        onfavorite,
+        external: false,
     };
 
     html!(<MenuItem ..props />)
@@ -190,6 +326,12 @@ pub struct MenuLinkProperties {
     #[prop_or_default]
     pub target: AttrValue,
 
+    /// Mark the link as external, opening it in a new window unless a `target` is set.
+    ///
+    /// External links are opened with `rel="noopener noreferrer"`.
+    #[prop_or_default]
+    pub external: bool,
+
     #[prop_or_default]
     pub selected: bool,
 
@@ -198,6 +340,16 @@ pub struct MenuLinkProperties {
 
     #[prop_or_default]
     pub class: Classes,
+
+    /// Mark the item as favorite.
+    #[prop_or_default]
+    pub favorite: bool,
+
+    /// Called with the new favorite state, when the user toggles it.
+    ///
+    /// The favorite toggle is only shown when this is set.
+    #[prop_or_default]
+    pub onfavorite: Option<Callback<bool>>,
 }
 
 #[function_component(MenuLink)]
@@ -215,8 +367,16 @@ pub fn menu_link(props: &MenuLinkProperties) -> Html {
         selected,
         style,
         class,
+        favorite,
+        onfavorite,
+        external,
     } = props.clone();
 
+    let target = match external && target.is_empty() {
+        true => AttrValue::Static("_blank"),
+        false => target,
+    };
+
     let props = MenuItemProperties {
         children,
         icon,
@@ -227,7 +387,78 @@ pub fn menu_link(props: &MenuLinkProperties) -> Html {
         selected,
         style,
         class,
+        submenu: None,
+        ondrill: None,
+        favorite,
+        onfavorite,
+        external,
     };
 
     html!(<MenuItem ..props />)
 }
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct MenuCheckboxProperties {
+    #[prop_or_default]
+    pub children: Html,
+
+    #[prop_or_default]
+    pub description: Option<String>,
+
+    #[prop_or_default]
+    pub disabled: bool,
+
+    #[prop_or_default]
+    pub checked: bool,
+
+    /// Called with the new state, when the user toggles the checkbox.
+    #[prop_or_default]
+    pub onchange: Callback<bool>,
+
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// A menu item with a checkbox.
+///
+/// Other than actions, toggling the checkbox doesn't close the menu.
+#[function_component(MenuCheckbox)]
+pub fn menu_checkbox(props: &MenuCheckboxProperties) -> Html {
+    let mut class = classes!("pf-v5-c-menu__list-item");
+
+    if props.disabled {
+        class.push(classes!("pf-m-disabled"));
+    }
+
+    class.extend(&props.class);
+
+    let checked = props.checked;
+
+    html!(
+        <li {class} style={&props.style}>
+            <label class="pf-v5-c-menu__item">
+                <span class="pf-v5-c-menu__item-main">
+                    <span class="pf-v5-c-menu__item-check">
+                        <span class="pf-v5-c-check pf-m-standalone">
+                            <input
+                                class="pf-v5-c-check__input"
+                                type="checkbox"
+                                tabindex="-1"
+                                {checked}
+                                disabled={props.disabled}
+                                onchange={props.onchange.reform(move |_| !checked)}
+                            />
+                        </span>
+                    </span>
+                    <span class="pf-v5-c-menu__item-text">{ props.children.clone() }</span>
+                </span>
+                if let Some(description) = &props.description {
+                    <span class="pf-v5-c-menu__item-description"> {description} </span>
+                }
+            </label>
+        </li>
+    )
+}
@@ -4,6 +4,7 @@ mod context;
 mod group;
 mod item;
 mod loading;
+mod search;
 mod toggle;
 mod variant;
 
@@ -12,16 +13,19 @@ pub use context::*;
 pub use group::*;
 pub use item::*;
 pub use loading::*;
+pub use search::*;
 pub use toggle::*;
 pub use variant::*;
 
 use crate::ouia;
 use crate::prelude::OuiaComponentType;
+use crate::prelude::{ChildrenProperties, Icon, ListDivider, Raw};
 use crate::utils::{Ouia, OuiaSafe};
+use std::rc::Rc;
 use wasm_bindgen::JsCast;
 use web_sys::{Element, HtmlElement};
 use web_tools::prelude::*;
-use yew::{html::ChildrenRenderer, prelude::*};
+use yew::{html::ChildrenRenderer, prelude::*, virtual_dom::VChild};
 use yew_hooks::use_event_with_window;
 
 const OUIA: Ouia = ouia!("Menu");
@@ -43,6 +47,14 @@ pub struct MenuProperties {
     #[prop_or_default]
     pub plain: bool,
 
+    /// Drill into submenus, instead of showing them as flyouts.
+    #[prop_or_default]
+    pub drilldown: bool,
+
+    /// Show the favorite items in a separate group with this title, at the top of the menu.
+    #[prop_or_default]
+    pub favorites: Option<AttrValue>,
+
     #[prop_or_default]
     pub children: ChildrenRenderer<MenuChildVariant>,
 
@@ -57,6 +69,76 @@ pub struct MenuProperties {
     pub ouia_safe: OuiaSafe,
 }
 
+/// Menu component
+///
+/// > A **menu** is a list of options or actions that users can choose from.
+///
+/// See: <https://www.patternfly.org/components/menus/menu>
+///
+/// ## Properties
+///
+/// Defined by [`MenuProperties`].
+///
+/// ## Submenus
+///
+/// A [`MenuAction`] can have a submenu. By default, it is shown as flyout next to the action.
+/// When `drilldown` is enabled, selecting the action replaces the content of the menu with the
+/// submenu instead, along with an entry to navigate back up.
+///
+/// Submenus can be entered and left using the right and left arrow keys.
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   html!(
+///     <Menu drilldown=true>
+///       <MenuAction
+///         submenu={html_nested!(
+///           <MenuGroup>
+///             <MenuAction>{ "Application" }</MenuAction>
+///             <MenuAction>{ "Workspace" }</MenuAction>
+///           </MenuGroup>
+///         )}
+///       >
+///         { "New" }
+///       </MenuAction>
+///       <MenuAction>{ "Open" }</MenuAction>
+///     </Menu>
+///   )
+/// }
+/// ```
+///
+/// ## Search and favorites
+///
+/// A [`MenuSearch`] child is rendered above the menu content. Filtering the items based on the
+/// search value is up to the application.
+///
+/// Items which have an `onfavorite` callback show a favorite toggle. If `favorites` is set, the
+/// items marked as `favorite` are additionally shown in a separate group at the top of the menu.
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let search = use_state_eq(String::new);
+///   let favorite = use_state_eq(|| false);
+///   let onchange = use_callback(search.clone(), |value, search| search.set(value));
+///   let onfavorite = use_callback(favorite.clone(), |value, favorite| favorite.set(value));
+///
+///   html!(
+///     <Menu favorites="Favorites">
+///       <MenuSearch value={(*search).clone()} {onchange} />
+///       <MenuAction favorite={*favorite} {onfavorite}>{ "Console" }</MenuAction>
+///       <MenuCheckbox checked=true>{ "Show hidden" }</MenuCheckbox>
+///     </Menu>
+///   )
+/// }
+/// ```
 #[function_component(Menu)]
 pub fn menu(props: &MenuProperties) -> Html {
     let ouia_id = use_memo(props.ouia_id.clone(), |id| {
@@ -72,6 +154,119 @@ pub fn menu(props: &MenuProperties) -> Html {
         class.push(classes!("pf-m-plain"));
     }
 
+    // submenus are shown as flyouts, unless drilling into them
+    if !props.drilldown
+        && actions(&props.children)
+            .iter()
+            .any(|action| action.submenu.is_some())
+    {
+        class.push(classes!("pf-m-flyout"));
+    }
+
+    // the drilldown path, as indexes of the actions of each level
+    let path = use_state_eq(Vec::<usize>::new);
+    let levels = drilldown_levels(&props.children, &path);
+
+    let ondrill = use_callback(
+        (levels.path.clone(), path.clone()),
+        |index: usize, (current, path)| {
+            let mut new_path = current.clone();
+            new_path.push(index);
+            path.set(new_path);
+        },
+    );
+    let ondrillup = use_callback(
+        (levels.path.clone(), path.clone()),
+        |_: MouseEvent, (current, path)| {
+            let mut new_path = current.clone();
+            new_path.pop();
+            path.set(new_path);
+        },
+    );
+
+    // move the focus into the new level
+    let content_ref = use_node_ref();
+    {
+        let first = use_mut_ref(|| true);
+        use_effect_with(
+            (levels.path.clone(), content_ref.clone()),
+            move |(_, content_ref)| {
+                if !first.replace(false) {
+                    focus_first_item(content_ref);
+                }
+            },
+        );
+    }
+
+    let children = match &levels.parent {
+        Some(parent) => {
+            let back = html!(
+                <li class="pf-v5-c-menu__list-item pf-m-drill-up">
+                    <button
+                        class="pf-v5-c-menu__item"
+                        type="button"
+                        role="menuitem"
+                        tabindex="-1"
+                        onclick={ondrillup}
+                    >
+                        <span class="pf-v5-c-menu__item-main">
+                            <span class="pf-v5-c-menu__item-toggle-icon">{ Icon::AngleLeft }</span>
+                            <span class="pf-v5-c-menu__item-text">{ parent.children.clone() }</span>
+                        </span>
+                    </button>
+                </li>
+            );
+
+            let mut children: Vec<MenuChildVariant> = vec![
+                VChild::<Raw>::new(ChildrenProperties { children: back }, None).into(),
+                VChild::<ListDivider>::new((), None).into(),
+            ];
+            children.extend(levels.current.iter());
+            ChildrenRenderer::new(children)
+        }
+        None => {
+            let children = props.children.iter().filter(|child| !is_search(child));
+
+            match &props.favorites {
+                Some(title) => {
+                    let favorites = favorites(&props.children);
+                    let mut result = vec![];
+                    if !favorites.is_empty() {
+                        let group = MenuGroupProperties {
+                            title: Some(title.to_string()),
+                            children: ChildrenRenderer::new(favorites),
+                        };
+                        result.push(VChild::<MenuGroup>::new(group, None).into());
+                        result.push(VChild::<ListDivider>::new((), None).into());
+                    }
+                    result.extend(children);
+                    ChildrenRenderer::new(result)
+                }
+                None => ChildrenRenderer::new(children.collect()),
+            }
+        }
+    };
+
+    let children = match props.drilldown {
+        true => with_drilldown(&children, &actions(&levels.current), &ondrill),
+        false => children,
+    };
+
+    // the search is only shown on the top level
+    let search = match &levels.parent {
+        Some(_) => html!(),
+        None => html!({ for props.children.iter().filter(is_search) }),
+    };
+
+    let content = html!(
+        <>
+            { search }
+            <div class="pf-v5-c-menu__content" ref={content_ref}>
+                <MenuList>{ children }</MenuList>
+            </div>
+        </>
+    );
+
     html!(
         <div
             ref={props.r#ref.clone()}
@@ -82,13 +277,115 @@ pub fn menu(props: &MenuProperties) -> Html {
             data-ouia-component-type={props.ouia_type}
             data-ouia-safe={props.ouia_safe}
         >
-            <div class="pf-v5-c-menu__content">
-                <MenuList>{ props.children.clone() }</MenuList>
-            </div>
+            { content }
         </div>
     )
 }
 
+#[derive(Clone, PartialEq)]
+struct DrilldownLevels {
+    /// The path which could be resolved
+    path: Vec<usize>,
+    /// The action of the current submenu, if drilled in
+    parent: Option<Rc<MenuActionProperties>>,
+    /// The children of the current level
+    current: ChildrenRenderer<MenuChildVariant>,
+}
+
+/// Resolve the drilldown path, as far as possible with the current children.
+fn drilldown_levels(
+    children: &ChildrenRenderer<MenuChildVariant>,
+    path: &[usize],
+) -> DrilldownLevels {
+    let mut levels = DrilldownLevels {
+        path: vec![],
+        parent: None,
+        current: children.clone(),
+    };
+
+    for index in path {
+        let Some(action) = actions(&levels.current).into_iter().nth(*index) else {
+            break;
+        };
+        let Some(submenu) = action.submenu.clone() else {
+            break;
+        };
+
+        levels.path.push(*index);
+        levels.current = submenu.props.children.clone();
+        levels.parent = Some(action);
+    }
+
+    levels
+}
+
+/// All actions of a level, including the ones in groups.
+fn actions(children: &ChildrenRenderer<MenuChildVariant>) -> Vec<Rc<MenuActionProperties>> {
+    let mut result = vec![];
+    for child in children.iter() {
+        match child.props {
+            MenuChild::Action(action) => result.push(action),
+            MenuChild::Group(group) => result.extend(actions(&group.children)),
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Provide the actions with a submenu with a context to drill into it, using their index in the
+/// actions of the level.
+fn with_drilldown(
+    children: &ChildrenRenderer<MenuChildVariant>,
+    actions: &[Rc<MenuActionProperties>],
+    ondrill: &Callback<usize>,
+) -> ChildrenRenderer<MenuChildVariant> {
+    let children = children.iter().map(|child| match &child.props {
+        MenuChild::Action(action) if action.submenu.is_some() => {
+            // identify the action by its instance, as equal actions may exist on the same level
+            match actions.iter().position(|a| Rc::ptr_eq(a, action)) {
+                Some(index) => {
+                    let context = MenuDrilldownContext::new(ondrill.reform(move |()| index));
+                    let props = (**action).clone();
+                    let children = html!(
+                        <ContextProvider<MenuDrilldownContext> {context}>
+                            <MenuAction ..props />
+                        </ContextProvider<MenuDrilldownContext>>
+                    );
+                    VChild::<Raw>::new(ChildrenProperties { children }, None).into()
+                }
+                None => child,
+            }
+        }
+        MenuChild::Group(group) => {
+            let group = MenuGroupProperties {
+                title: group.title.clone(),
+                children: with_drilldown(&group.children, actions, ondrill),
+            };
+            VChild::<MenuGroup>::new(group, None).into()
+        }
+        _ => child,
+    });
+    ChildrenRenderer::new(children.collect())
+}
+
+fn is_search(child: &MenuChildVariant) -> bool {
+    matches!(child.props, MenuChild::Search(_))
+}
+
+/// All items marked as favorite, including the ones in groups.
+fn favorites(children: &ChildrenRenderer<MenuChildVariant>) -> Vec<MenuChildVariant> {
+    let mut result = vec![];
+    for child in children.iter() {
+        match &child.props {
+            MenuChild::Action(action) if action.favorite => result.push(child),
+            MenuChild::Link(link) if link.favorite => result.push(child),
+            MenuChild::Group(group) => result.extend(favorites(&group.children)),
+            _ => {}
+        }
+    }
+    result
+}
+
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub(crate) struct MenuListProperties {
     pub(crate) children: ChildrenRenderer<MenuChildVariant>,
@@ -105,6 +402,15 @@ pub(crate) fn menu_list(props: &MenuListProperties) -> Html {
                 return;
             }
 
+            // only handle keys of items in our own menu, and not in a nested flyout menu
+            let target_menu = e
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|target| target.closest(".pf-v5-c-menu").ok().flatten());
+            if target_menu != own_menu(&r#ref) {
+                return;
+            }
+
             handle_key(&r#ref, e);
         });
     }
@@ -116,6 +422,29 @@ pub(crate) fn menu_list(props: &MenuListProperties) -> Html {
     )
 }
 
+/// The closest menu element of a node.
+fn own_menu(node: &NodeRef) -> Option<Element> {
+    node.cast::<Element>()?.closest(".pf-v5-c-menu").ok()?
+}
+
+/// Focus the first item of a menu.
+pub(crate) fn focus_first_item(node: &NodeRef) {
+    if let Some(item) = node
+        .cast::<Element>()
+        .and_then(|element| {
+            element
+                .query_selector(".pf-v5-c-menu__list-item:not(.pf-m-disabled)")
+                .ok()
+                .flatten()
+        })
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+        .and_then(|element| focusable_element(&element))
+    {
+        item.set_tab_index(0);
+        let _ = item.focus();
+    }
+}
+
 fn focusable_element(element: &HtmlElement) -> Option<HtmlElement> {
     element
         .query_selector("a, button, input")
@@ -136,10 +465,66 @@ fn handle_key(node: &NodeRef, e: KeyboardEvent) {
             }
         }
         "ArrowUp" | "ArrowDown" => handle_arrows(node, e),
+        "ArrowRight" => handle_enter_submenu(e),
+        "ArrowLeft" => handle_leave_submenu(node, e),
         _ => {}
     }
 }
 
+/// Open the submenu of the active item, if it has one.
+fn handle_enter_submenu(e: KeyboardEvent) {
+    let Some(active) = gloo_utils::document()
+        .active_element()
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+    else {
+        return;
+    };
+
+    if active.get_attribute("aria-haspopup").as_deref() == Some("menu")
+        && active.get_attribute("aria-expanded").as_deref() != Some("true")
+    {
+        e.prevent_default();
+        active.click();
+    }
+}
+
+/// Leave the current submenu: drill up, or close the flyout.
+fn handle_leave_submenu(node: &NodeRef, e: KeyboardEvent) {
+    let Some(menu) = own_menu(node) else {
+        return;
+    };
+
+    // drilldown, navigate up
+    if let Some(drill_up) = menu
+        .query_selector(".pf-v5-c-menu__list-item.pf-m-drill-up > .pf-v5-c-menu__item")
+        .ok()
+        .flatten()
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+    {
+        e.prevent_default();
+        drill_up.click();
+        return;
+    }
+
+    // flyout, close and focus the parent item
+    if let Some(toggle) = menu
+        .parent_element()
+        .filter(|parent| parent.class_list().contains("pf-v5-c-menu__list-item"))
+        .and_then(|parent| {
+            parent
+                .query_selector(":scope > .pf-v5-c-menu__item")
+                .ok()
+                .flatten()
+        })
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+    {
+        e.prevent_default();
+        toggle.click();
+        toggle.set_tab_index(0);
+        let _ = toggle.focus();
+    }
+}
+
 fn handle_arrows(node: &NodeRef, e: KeyboardEvent) {
     e.prevent_default();
     e.stop_immediate_propagation();
@@ -148,10 +533,9 @@ fn handle_arrows(node: &NodeRef, e: KeyboardEvent) {
         .active_element()
         .and_then(|element| element.dyn_into::<HtmlElement>().ok());
 
-    let elements = match node
-        .cast::<Element>()
-        .map(|ele| ele.get_elements_by_tag_name("LI"))
-    {
+    // all items of the menu, across groups, but without nested flyout menus
+    let menu = own_menu(node);
+    let elements = match menu.as_ref().map(|ele| ele.get_elements_by_tag_name("LI")) {
         Some(elements) => elements,
         None => return,
     };
@@ -159,6 +543,7 @@ fn handle_arrows(node: &NodeRef, e: KeyboardEvent) {
     let items = IterableHtmlCollection(&elements)
         .into_iter()
         .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .filter(|element| element.closest(".pf-v5-c-menu").ok().flatten() == menu)
         .filter(|element| {
             !element.class_list().contains("pf-m-disabled")
                 && !element.class_list().contains("pf-v5-c-divider")
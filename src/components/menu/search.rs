@@ -0,0 +1,48 @@
+use crate::prelude::{Divider, SearchInput};
+use yew::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct MenuSearchProperties {
+    /// The current search value.
+    #[prop_or_default]
+    pub value: String,
+
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+
+    #[prop_or(AttrValue::Static("Search"))]
+    pub aria_label: AttrValue,
+
+    /// Focus the input when the menu gets shown.
+    #[prop_or_default]
+    pub autofocus: bool,
+
+    /// Called with the new value, when the user changes or clears the search.
+    #[prop_or_default]
+    pub onchange: Callback<String>,
+}
+
+/// A search input at the top of a menu.
+///
+/// When used as a direct child of a [`super::Menu`], it is rendered above the menu content,
+/// followed by a divider. Filtering the items is up to the application.
+#[function_component(MenuSearch)]
+pub fn menu_search(props: &MenuSearchProperties) -> Html {
+    html!(
+        <>
+            <div class="pf-v5-c-menu__search">
+                <div class="pf-v5-c-menu__search-input">
+                    <SearchInput
+                        value={props.value.clone()}
+                        placeholder={props.placeholder.clone()}
+                        aria_label={&props.aria_label}
+                        autofocus={props.autofocus}
+                        onchange={props.onchange.clone()}
+                        onclear={props.onchange.reform(|_| String::new())}
+                    />
+                </div>
+            </div>
+            <Divider />
+        </>
+    )
+}
@@ -7,7 +7,7 @@ use yew::{
 
 #[derive(PartialEq, Clone)]
 pub struct MenuChildVariant {
-    props: MenuChild,
+    pub(crate) props: MenuChild,
 }
 
 impl<CHILD> From<VChild<CHILD>> for MenuChildVariant
@@ -29,6 +29,8 @@ impl From<MenuChildVariant> for Html {
             MenuChild::Link(props) => VComp::new::<MenuLink>(props, None).into(),
             MenuChild::Group(props) => VComp::new::<MenuGroup>(props, None).into(),
             MenuChild::Loading(props) => VComp::new::<MenuLoading>(props, None).into(),
+            MenuChild::Search(props) => VComp::new::<MenuSearch>(props, None).into(),
+            MenuChild::Checkbox(props) => VComp::new::<MenuCheckbox>(props, None).into(),
             MenuChild::Divider(props) => VComp::new::<ListDivider>(props, None).into(),
             MenuChild::Raw(props) => VComp::new::<Raw>(props, None).into(),
         }
@@ -3,6 +3,7 @@
 pub mod about;
 pub mod accordion;
 pub mod alert;
+pub mod app_launcher;
 pub mod avatar;
 pub mod backdrop;
 pub mod background;
@@ -18,7 +19,9 @@ pub mod clipboard;
 pub mod code_block;
 pub mod content;
 pub mod context_selector;
+pub mod data_list;
 pub mod date;
+pub mod dialog;
 pub mod divider;
 pub mod dl;
 pub mod drawer;
@@ -31,13 +34,17 @@ pub mod form;
 pub mod helper_text;
 pub mod hint;
 pub mod input_group;
+pub mod jump_links;
 pub mod label;
 pub mod list;
+pub mod log_viewer;
 pub mod login_page;
 pub mod menu;
 pub mod modal;
 pub mod nav;
+pub mod notification_drawer;
 pub mod number_input;
+pub mod overflow_menu;
 pub mod page;
 pub mod pagination;
 pub mod panel;
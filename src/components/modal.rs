@@ -1,7 +1,7 @@
 //! Modal
 use crate::ouia;
-use crate::prelude::use_backdrop;
 use crate::prelude::wrap::wrapper_div_with_attributes;
+use crate::prelude::{use_backdrop, use_backdrop_topmost, use_focus_trap, InitialFocus};
 use crate::utils::{Ouia, OuiaComponentType, OuiaSafe};
 use yew::prelude::*;
 use yew::virtual_dom::ApplyAttributeAs;
@@ -57,6 +57,13 @@ pub struct ModalProperties {
     #[prop_or_default]
     pub disable_close_click_outside: bool,
 
+    /// Disable trapping the focus inside the modal
+    #[prop_or_default]
+    pub disable_focus_trap: bool,
+    /// The element to focus when the modal opens
+    #[prop_or_default]
+    pub initial_focus: InitialFocus,
+
     /// OUIA Component id
     #[prop_or_default]
     pub ouia_id: Option<String>,
@@ -84,6 +91,11 @@ pub struct ModalProperties {
 /// `onclose` callback is set, then it will automatically close the backdrop when the modal dialog
 /// gets closed.
 ///
+/// ## Focus
+///
+/// While open, the keyboard focus is trapped inside the modal. When closed, the focus returns to
+/// the element which was focused before. See [`crate::prelude::FocusTrap`].
+///
 #[function_component(Modal)]
 pub fn modal(props: &ModalProperties) -> Html {
     let ouia_id = use_memo(props.ouia_id.clone(), |id| {
@@ -106,9 +118,12 @@ pub fn modal(props: &ModalProperties) -> Html {
         })
     });
 
+    // only the topmost modal handles closing
+    let topmost = use_backdrop_topmost();
+
     // escape key
     {
-        let disabled = props.disable_close_escape;
+        let disabled = props.disable_close_escape || !topmost;
         let onclose = onclose.clone();
         use_event_with_window("keydown", move |e: KeyboardEvent| {
             if !disabled && e.key() == "Escape" {
@@ -122,7 +137,7 @@ pub fn modal(props: &ModalProperties) -> Html {
     let node_ref = use_node_ref();
 
     {
-        let disabled = props.disable_close_click_outside;
+        let disabled = props.disable_close_click_outside || !topmost;
         let onclose = onclose.clone();
         use_click_away(node_ref.clone(), move |_: Event| {
             if !disabled {
@@ -131,6 +146,12 @@ pub fn modal(props: &ModalProperties) -> Html {
         });
     }
 
+    use_focus_trap(
+        node_ref.clone(),
+        !props.disable_focus_trap,
+        props.initial_focus.clone(),
+    );
+
     html! (
         <div
             class={classes}
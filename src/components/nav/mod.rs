@@ -6,8 +6,9 @@ mod router;
 pub use router::*;
 use std::collections::HashSet;
 
+use crate::hooks::id::use_random_id;
 use crate::ouia;
-use crate::prelude::{Icon, Id, OuiaComponentType};
+use crate::prelude::{AsClasses, ExtendClasses, Icon, Id, OuiaComponentType};
 use crate::utils::{Ouia, OuiaSafe};
 use std::fmt::Debug;
 use yew::prelude::*;
@@ -17,12 +18,45 @@ const OUIA_NAV_ITEM: Ouia = ouia!("NavItem");
 
 // nav
 
+/// The variant of a [`Nav`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum NavVariant {
+    /// A vertical navigation, e.g. for the sidebar.
+    #[default]
+    Default,
+    /// A horizontal navigation, e.g. for the masthead.
+    Horizontal,
+    /// A horizontal navigation below the masthead.
+    HorizontalSubnav,
+    /// A horizontal navigation, e.g. for the main section of a page.
+    Tertiary,
+}
+
+impl AsClasses for NavVariant {
+    fn extend_classes(&self, classes: &mut Classes) {
+        match self {
+            Self::Default => {}
+            Self::Horizontal => classes.push("pf-m-horizontal"),
+            Self::HorizontalSubnav => {
+                classes.push(classes!("pf-m-horizontal", "pf-m-horizontal-subnav"))
+            }
+            Self::Tertiary => classes.push(classes!("pf-m-horizontal", "pf-m-tertiary")),
+        }
+    }
+}
+
 /// Properties for [`Nav`]
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct NavProperties {
     #[prop_or_default]
     pub children: Html,
 
+    #[prop_or_default]
+    pub variant: NavVariant,
+
+    #[prop_or(AttrValue::Static("Global"))]
+    pub aria_label: AttrValue,
+
     /// OUIA Component id
     #[prop_or_default]
     pub ouia_id: Option<String>,
@@ -35,15 +69,41 @@ pub struct NavProperties {
 }
 
 /// A navigation component.
+///
+/// The items of a [`NavVariant::Horizontal`], [`NavVariant::HorizontalSubnav`] or
+/// [`NavVariant::Tertiary`] navigation are placed into a single [`NavList`].
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   html!(
+///     <Nav variant={NavVariant::Tertiary} aria_label="Local">
+///       <NavList>
+///         <NavLink href="#overview">{ "Overview" }</NavLink>
+///         <NavLink href="#details">{ "Details" }</NavLink>
+///       </NavList>
+///     </Nav>
+///   )
+/// }
+/// ```
 #[function_component(Nav)]
 pub fn nav(props: &NavProperties) -> Html {
     let ouia_id = use_memo(props.ouia_id.clone(), |id| {
         id.clone().unwrap_or(OUIA_NAV.generated_id())
     });
+
+    let mut class = classes!("pf-v5-c-nav");
+    class.extend_from(&props.variant);
+
     html! {
         <nav
-            class="pf-v5-c-nav"
-            aria-label="Global"
+            {class}
+            aria-label={&props.aria_label}
             data-ouia-component-id={(*ouia_id).clone()}
             data-ouia-component-type={props.ouia_type}
             data-ouia-safe={props.ouia_safe}
@@ -166,6 +226,7 @@ pub fn nav_link(props: &NavLinkProperties) -> Html {
     )
 }
 
+/// Tracks the active state of the items inside a [`NavExpandable`] or [`NavFlyout`].
 #[derive(Clone, PartialEq)]
 pub struct Expandable {
     callback: Callback<(Id, bool)>,
@@ -177,8 +238,52 @@ impl Expandable {
     }
 }
 
+/// Provide an [`Expandable`] context, returning it and if any of the items is active.
+///
+/// The state is forwarded to a wrapping [`Expandable`], so that nested sections get activated
+/// as well.
+#[hook]
+fn use_active_items() -> (Expandable, bool) {
+    let items = use_mut_ref(HashSet::<Id>::new);
+    let force_update = use_force_update();
+
+    let context = {
+        let items = items.clone();
+        use_memo((), move |()| Expandable {
+            callback: Callback::from(move |(id, active)| {
+                let changed = match active {
+                    true => items.borrow_mut().insert(id),
+                    false => items.borrow_mut().remove(&id),
+                };
+                if changed {
+                    force_update.force_update();
+                }
+            }),
+        })
+    };
+
+    let active = !items.borrow().is_empty();
+
+    let id = use_random_id();
+    let parent = use_expandable();
+    use_effect_with(active, move |active| {
+        if let Some(parent) = &parent {
+            parent.state(*id, *active);
+        }
+        move || {
+            if let Some(parent) = parent {
+                parent.state(*id, false);
+            }
+        }
+    });
+
+    ((*context).clone(), active)
+}
+
 // nav expandable
 
+const STORAGE_PREFIX: &str = "patternfly-yew:nav-expandable:";
+
 /// Properties for [`NavExpandable`]
 #[derive(Clone, PartialEq, Properties)]
 pub struct NavExpandableProperties {
@@ -186,127 +291,254 @@ pub struct NavExpandableProperties {
     pub children: Html,
     #[prop_or_default]
     pub title: String,
+    /// Expand the section. Setting it to `true` expands the section, even if the user collapsed it.
     #[prop_or_default]
     pub expanded: bool,
-}
 
-/// Expandable navigation group/section.
-pub struct NavExpandable {
-    expanded: Option<bool>,
-    context: Expandable,
-    active: HashSet<Id>,
-}
-
-#[doc(hidden)]
-#[derive(Clone, Debug)]
-pub enum MsgExpandable {
-    Toggle,
-    ChildState(Id, bool),
+    /// The key for remembering the expanded state for the session.
+    ///
+    /// The state is only remembered when set. The key must be unique among the expandable
+    /// sections of the application, as sections with the same key share their state.
+    #[prop_or_default]
+    pub storage_key: Option<AttrValue>,
 }
 
-impl Component for NavExpandable {
-    type Message = MsgExpandable;
-    type Properties = NavExpandableProperties;
-
-    fn create(ctx: &Context<Self>) -> Self {
-        let expanded = match ctx.props().expanded {
-            true => Some(true),
-            false => None,
-        };
-
-        log::debug!("Creating new NavExpandable");
-
-        let callback = ctx
-            .link()
-            .callback(|(id, state)| MsgExpandable::ChildState(id, state));
+/// Expandable navigation group/section.
+///
+/// The section expands when one of its items becomes active, like a [`NavRouterItem`] for
+/// the current route. When a `storage_key` is set, the state is remembered in the session storage
+/// of the browser, so that it survives reloading the page.
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   html!(
+///     <Nav>
+///       <NavList>
+///         <NavExpandable title="Settings">
+///           <NavLink href="#general">{ "General" }</NavLink>
+///           <NavExpandable title="Advanced" storage_key="settings.advanced">
+///             <NavLink href="#network">{ "Network" }</NavLink>
+///           </NavExpandable>
+///         </NavExpandable>
+///       </NavList>
+///     </Nav>
+///   )
+/// }
+/// ```
+#[function_component(NavExpandable)]
+pub fn nav_expandable(props: &NavExpandableProperties) -> Html {
+    let key = props.storage_key.clone();
+
+    let expanded = use_state_eq(|| {
+        props.expanded || key.as_deref().and_then(load_expanded).unwrap_or_default()
+    });
+    let (context, active) = use_active_items();
 
-        Self {
-            expanded,
-            active: Default::default(),
-            context: Expandable { callback },
+    use_effect_with(props.expanded, {
+        let expanded = expanded.clone();
+        move |value| {
+            if *value {
+                expanded.set(true);
+            }
         }
-    }
-
-    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        match msg {
-            MsgExpandable::Toggle => {
-                self.expanded = Some(!self.is_expanded(ctx));
+    });
+    use_effect_with(active, {
+        let expanded = expanded.clone();
+        move |active| {
+            if *active {
+                expanded.set(true);
             }
-            MsgExpandable::ChildState(id, state) => match state {
-                true => {
-                    self.active.insert(id);
-                }
-                false => {
-                    self.active.remove(&id);
-                }
-            },
         }
-        true
-    }
-
-    fn changed(&mut self, ctx: &Context<Self>, _: &Self::Properties) -> bool {
-        if ctx.props().expanded {
-            self.expanded = Some(true);
+    });
+    use_effect_with((*expanded, key), |(expanded, key)| {
+        if let Some(key) = key {
+            store_expanded(key, *expanded);
         }
-        true
+    });
+
+    let onclick = use_callback(expanded.clone(), |_: MouseEvent, expanded| {
+        expanded.set(!**expanded)
+    });
+
+    let mut classes = Classes::from("pf-v5-c-nav__item pf-m-expandable");
+    if *expanded {
+        classes.push("pf-m-expanded");
     }
 
-    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
-        if first_render && self.expanded.is_none() && self.is_expanded(ctx) {
-            // if this is the first render, and we are expanded, we want to stay that way.
-            // Unless a user explicitly toggles.
-            self.expanded = Some(true);
-        }
+    html! {
+        <ContextProvider<Expandable> {context}>
+            <li class={classes}>
+                <button
+                    class="pf-v5-c-nav__link"
+                    aria-expanded={expanded.to_string()}
+                    {onclick}
+                >
+                    { &props.title }
+                    <span class="pf-v5-c-nav__toggle">
+                        <span class="pf-v5-c-nav__toggle-icon">
+                            { Icon::AngleRight }
+                        </span>
+                    </span>
+                </button>
+
+                <section class="pf-v5-c-nav__subnav" hidden={!*expanded}>
+                    <NavList>
+                        { props.children.clone() }
+                    </NavList>
+                </section>
+            </li>
+        </ContextProvider<Expandable>>
     }
+}
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let mut classes = Classes::from("pf-v5-c-nav__item pf-m-expandable");
+fn load_expanded(key: &str) -> Option<bool> {
+    let storage = gloo_utils::window().session_storage().ok()??;
+    let value = storage.get_item(&format!("{STORAGE_PREFIX}{key}")).ok()??;
+    Some(value == "true")
+}
 
-        let expanded = self.is_expanded(ctx);
+fn store_expanded(key: &str, expanded: bool) {
+    if let Ok(Some(storage)) = gloo_utils::window().session_storage() {
+        let _ = storage.set_item(&format!("{STORAGE_PREFIX}{key}"), &expanded.to_string());
+    }
+}
 
-        if expanded {
-            classes.push("pf-m-expanded");
-        }
+// nav flyout
 
-        let context = self.context.clone();
-
-        html! {
-            <ContextProvider<Expandable> {context}>
-                <li class={classes}>
-                    <button
-                        class="pf-v5-c-nav__link"
-                        aria-expanded={expanded.to_string()}
-                        onclick={ctx.link().callback(|_|MsgExpandable::Toggle)}
-                    >
-                        { &ctx.props().title }
-                        <span class="pf-v5-c-nav__toggle">
-                            <span class="pf-v5-c-nav__toggle-icon">
-                                { Icon::AngleRight }
-                            </span>
-                        </span>
-                    </button>
-
-                    <section class="pf-v5-c-nav__subnav" hidden={!expanded}>
-                        <NavList>
-                            { ctx.props().children.clone() }
-                        </NavList>
-                    </section>
-                </li>
-            </ContextProvider<Expandable>>
-        }
-    }
+/// Marks the content of a [`NavFlyout`], which renders its items as menu items.
+#[derive(Clone, PartialEq)]
+struct FlyoutContext;
+
+/// Properties for [`NavFlyout`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct NavFlyoutProperties {
+    #[prop_or_default]
+    pub children: Html,
+    #[prop_or_default]
+    pub title: String,
 }
 
-impl NavExpandable {
-    fn is_expanded(&self, ctx: &Context<Self>) -> bool {
-        // if we have a current state, that will always override.
-        self.expanded.unwrap_or_else(|| {
-            // if any child is currently active.
-            let active = !self.active.is_empty();
+/// Navigation item, showing its items in a flyout menu.
+///
+/// The content should consist of [`NavRouterItem`]s, or nested [`NavFlyout`]s. The item is
+/// marked as current when one of its items is active.
+///
+/// ## Example
+///
+#[cfg_attr(feature = "yew-nested-router", doc = "```")]
+#[cfg_attr(not(feature = "yew-nested-router"), doc = "```ignore")]
+/// use yew::prelude::*;
+/// use yew_nested_router::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq, Eq, Target)]
+/// enum AppRoute {
+///   Index,
+///   Users,
+///   Groups,
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   html!(
+///     <Nav>
+///       <NavList>
+///         <NavRouterItem<AppRoute> to={AppRoute::Index}>{ "Home" }</NavRouterItem<AppRoute>>
+///         <NavFlyout title="Access">
+///           <NavRouterItem<AppRoute> to={AppRoute::Users}>{ "Users" }</NavRouterItem<AppRoute>>
+///           <NavRouterItem<AppRoute> to={AppRoute::Groups}>{ "Groups" }</NavRouterItem<AppRoute>>
+///         </NavFlyout>
+///       </NavList>
+///     </Nav>
+///   )
+/// }
+/// ```
+#[function_component(NavFlyout)]
+pub fn nav_flyout(props: &NavFlyoutProperties) -> Html {
+    let nested = use_flyout();
+    let (context, active) = use_active_items();
+    let open = use_state_eq(|| false);
+
+    let onmouseenter = use_callback(open.clone(), |_: MouseEvent, open| open.set(true));
+    let onmouseleave = use_callback(open.clone(), |_: MouseEvent, open| open.set(false));
+    let onclick = use_callback(open.clone(), |_: MouseEvent, open| open.set(!**open));
+    let onkeydown = use_callback(open.clone(), |event: KeyboardEvent, open| {
+        if event.key() == "Escape" {
+            open.set(false);
+        }
+    });
 
-            ctx.props().expanded || active
-        })
-    }
+    let toggle = match nested {
+        true => {
+            let mut class = classes!("pf-v5-c-menu__item");
+            if active {
+                class.push(classes!("pf-m-selected"));
+            }
+            html!(
+                <button
+                    {class}
+                    type="button"
+                    role="menuitem"
+                    aria-haspopup="menu"
+                    aria-expanded={open.to_string()}
+                    {onclick}
+                >
+                    <span class="pf-v5-c-menu__item-main">
+                        <span class="pf-v5-c-menu__item-text">{ &props.title }</span>
+                        <span class="pf-v5-c-menu__item-toggle-icon">{ Icon::AngleRight }</span>
+                    </span>
+                </button>
+            )
+        }
+        false => {
+            let mut class = classes!("pf-v5-c-nav__link");
+            if active {
+                class.push(classes!("pf-m-current"));
+            }
+            html!(
+                <button
+                    {class}
+                    type="button"
+                    aria-haspopup="menu"
+                    aria-expanded={open.to_string()}
+                    {onclick}
+                >
+                    { &props.title }
+                    <span class="pf-v5-c-nav__toggle">
+                        <span class="pf-v5-c-nav__toggle-icon">{ Icon::AngleRight }</span>
+                    </span>
+                </button>
+            )
+        }
+    };
+
+    let class = match nested {
+        true => classes!("pf-v5-c-menu__list-item", "pf-m-flyout"),
+        false => classes!("pf-v5-c-nav__item", "pf-m-flyout"),
+    };
+
+    html!(
+        <ContextProvider<Expandable> {context}>
+            <li {class} {onmouseenter} {onmouseleave} {onkeydown}>
+                { toggle }
+                <div class="pf-v5-c-menu pf-m-flyout pf-m-nav" hidden={!*open}>
+                    <div class="pf-v5-c-menu__content">
+                        <ul class="pf-v5-c-menu__list" role="menu">
+                            <ContextProvider<FlyoutContext> context={FlyoutContext}>
+                                { props.children.clone() }
+                            </ContextProvider<FlyoutContext>>
+                        </ul>
+                    </div>
+                </div>
+            </li>
+        </ContextProvider<Expandable>>
+    )
 }
 
 /// Access a wrapping [`Expandable`] content.
@@ -314,3 +546,9 @@ impl NavExpandable {
 pub fn use_expandable() -> Option<Expandable> {
     use_context::<Expandable>()
 }
+
+/// Check if the item is rendered inside a [`NavFlyout`].
+#[hook]
+fn use_flyout() -> bool {
+    use_context::<FlyoutContext>().is_some()
+}
@@ -20,6 +20,9 @@ where
 }
 
 /// A navigation item, using the Router.
+///
+/// Being active, the item expands a wrapping [`NavExpandable`], and marks a wrapping
+/// [`NavFlyout`] as current. Inside a [`NavFlyout`], it is rendered as a menu item.
 #[function_component(NavRouterItem)]
 pub fn nav_router_item<R>(props: &NavRouterItemProperties<R>) -> Html
 where
@@ -27,19 +30,40 @@ where
 {
     let router = use_router().expect("Requires a Router or Nested router");
 
-    let mut classes = Classes::from("pf-v5-c-nav__link");
-
     let active = router.is_active(&props.to, props.predicate.as_ref());
 
     let id = use_random_id();
 
     let expandable = use_expandable();
-    use_effect_with(active, move |_| {
-        if let Some(expandable) = expandable {
-            expandable.state(*id, active)
+    use_effect_with(active, move |active| {
+        if let Some(expandable) = &expandable {
+            expandable.state(*id, *active);
+        }
+        move || {
+            if let Some(expandable) = expandable {
+                expandable.state(*id, false);
+            }
         }
     });
 
+    if use_flyout() {
+        let mut classes = Classes::from("pf-v5-c-menu__item");
+        if active {
+            classes.push("pf-m-selected");
+        }
+
+        return html! {
+            <li class="pf-v5-c-menu__list-item" role="none">
+                <Link<R> to={props.to.clone()} class={classes}>
+                    <span class="pf-v5-c-menu__item-main">
+                        <span class="pf-v5-c-menu__item-text">{ props.children.clone() }</span>
+                    </span>
+                </Link<R>>
+            </li>
+        };
+    }
+
+    let mut classes = Classes::from("pf-v5-c-nav__link");
     if active {
         classes.push("pf-m-current");
     }
@@ -0,0 +1,81 @@
+use super::use_notifications;
+use crate::prelude::{Button, ButtonVariant, Icon};
+use yew::prelude::*;
+
+/// Properties for [`NotificationBadge`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct NotificationBadgeProperties {
+    /// The number of unread notifications.
+    ///
+    /// If unset, the unread count of a wrapping [`super::NotificationViewer`] is used.
+    #[prop_or_default]
+    pub count: Option<usize>,
+
+    /// Show the badge in the "attention" state, for important notifications.
+    #[prop_or_default]
+    pub attention: bool,
+
+    /// The expanded state of the notification drawer controlled by this badge.
+    #[prop_or_default]
+    pub expanded: bool,
+
+    #[prop_or_default]
+    pub onclick: Callback<()>,
+
+    #[prop_or(AttrValue::Static("Notifications"))]
+    pub aria_label: AttrValue,
+}
+
+/// Notification badge component
+///
+/// > A **notification badge** is a visual indicator that alerts users about incoming
+/// > notifications.
+///
+/// See: <https://www.patternfly.org/components/notification-badge>
+///
+/// The badge is intended to be placed in the `tools` section of the [`crate::prelude::Page`],
+/// toggling the notification drawer.
+///
+/// ## Properties
+///
+/// Defined by [`NotificationBadgeProperties`].
+#[function_component(NotificationBadge)]
+pub fn notification_badge(props: &NotificationBadgeProperties) -> Html {
+    let notifications = use_notifications();
+    let count = props.count.unwrap_or_else(|| {
+        notifications
+            .map(|notifications| notifications.unread())
+            .unwrap_or_default()
+    });
+
+    let mut class = classes!("pf-v5-c-notification-badge");
+
+    if props.attention {
+        class.push(classes!("pf-m-attention"));
+    } else if count > 0 {
+        class.push(classes!("pf-m-unread"));
+    } else {
+        class.push(classes!("pf-m-read"));
+    }
+
+    let icon = match props.attention {
+        true => Icon::AttentionBell,
+        false => Icon::Bell,
+    };
+
+    html!(
+        <Button
+            variant={ButtonVariant::Plain}
+            aria_label={&props.aria_label}
+            aria_expanded={props.expanded.to_string()}
+            onclick={props.onclick.reform(|_| ())}
+        >
+            <span {class}>
+                { icon }
+                if count > 0 {
+                    <span class="pf-v5-c-notification-badge__count">{ count }</span>
+                }
+            </span>
+        </Button>
+    )
+}
@@ -0,0 +1,278 @@
+//! Notification drawer
+mod badge;
+mod viewer;
+
+pub use badge::*;
+pub use viewer::*;
+
+use crate::prelude::{AlertType, Badge, Button, ButtonVariant, Icon};
+use yew::prelude::*;
+
+/// Properties for [`NotificationDrawer`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct NotificationDrawerProperties {
+    #[prop_or_default]
+    pub children: Html,
+
+    /// Additional classes
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Notification drawer component
+///
+/// > A **notification drawer** is a collection of notifications, that can be viewed by the user
+/// > from the masthead.
+///
+/// See: <https://www.patternfly.org/components/notification-drawer>
+///
+/// ## Properties
+///
+/// Defined by [`NotificationDrawerProperties`].
+///
+/// ## Children
+///
+/// The drawer requires a structure of other notification drawer elements, which isn't enforced
+/// through types. For a managed variant, see [`SimpleNotificationDrawer`].
+#[function_component(NotificationDrawer)]
+pub fn notification_drawer(props: &NotificationDrawerProperties) -> Html {
+    let class = classes!("pf-v5-c-notification-drawer", props.class.clone());
+
+    html!(
+        <div {class}>
+            { props.children.clone() }
+        </div>
+    )
+}
+
+/// Properties for [`NotificationDrawerHeader`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct NotificationDrawerHeaderProperties {
+    #[prop_or(AttrValue::Static("Notifications"))]
+    pub title: AttrValue,
+
+    /// The number of unread notifications, shown as status.
+    #[prop_or_default]
+    pub count: Option<usize>,
+
+    /// Custom status text, overriding the default "N unread" text.
+    #[prop_or_default]
+    pub custom_text: Option<AttrValue>,
+
+    /// Called when the user clicks the close button. No close button is shown if unset.
+    #[prop_or_default]
+    pub onclose: Option<Callback<()>>,
+
+    /// Header actions, like a [`crate::prelude::Dropdown`].
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component(NotificationDrawerHeader)]
+pub fn notification_drawer_header(props: &NotificationDrawerHeaderProperties) -> Html {
+    let status = props.custom_text.clone().or_else(|| {
+        props
+            .count
+            .map(|count| AttrValue::from(format!("{count} unread")))
+    });
+
+    html!(
+        <div class="pf-v5-c-notification-drawer__header">
+            <h1 class="pf-v5-c-notification-drawer__header-title">{ &props.title }</h1>
+            if let Some(status) = status {
+                <span class="pf-v5-c-notification-drawer__header-status">{ status }</span>
+            }
+            <div class="pf-v5-c-notification-drawer__header-action">
+                { props.children.clone() }
+                if let Some(onclose) = &props.onclose {
+                    <div class="pf-v5-c-notification-drawer__header-action-close">
+                        <Button
+                            variant={ButtonVariant::Plain}
+                            icon={Icon::Times}
+                            aria_label="Close"
+                            onclick={onclose.reform(|_| ())}
+                        />
+                    </div>
+                }
+            </div>
+        </div>
+    )
+}
+
+/// Properties for [`NotificationDrawerBody`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct NotificationDrawerBodyProperties {
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component(NotificationDrawerBody)]
+pub fn notification_drawer_body(props: &NotificationDrawerBodyProperties) -> Html {
+    html!(
+        <div class="pf-v5-c-notification-drawer__body">
+            { props.children.clone() }
+        </div>
+    )
+}
+
+/// Properties for [`NotificationDrawerGroupList`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct NotificationDrawerGroupListProperties {
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component(NotificationDrawerGroupList)]
+pub fn notification_drawer_group_list(props: &NotificationDrawerGroupListProperties) -> Html {
+    html!(
+        <div class="pf-v5-c-notification-drawer__group-list">
+            { props.children.clone() }
+        </div>
+    )
+}
+
+/// Properties for [`NotificationDrawerGroup`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct NotificationDrawerGroupProperties {
+    pub title: AttrValue,
+
+    /// The number of unread notifications in this group.
+    #[prop_or_default]
+    pub count: usize,
+
+    #[prop_or_default]
+    pub expanded: bool,
+
+    #[prop_or_default]
+    pub ontoggle: Callback<()>,
+
+    /// The content of the group, expected to be a single [`NotificationDrawerList`].
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// A group of notifications, which can be expanded and collapsed.
+#[function_component(NotificationDrawerGroup)]
+pub fn notification_drawer_group(props: &NotificationDrawerGroupProperties) -> Html {
+    let mut class = classes!("pf-v5-c-notification-drawer__group");
+
+    if props.expanded {
+        class.push(classes!("pf-m-expanded"));
+    }
+
+    html!(
+        <section {class}>
+            <h1>
+                <button
+                    class="pf-v5-c-notification-drawer__group-toggle"
+                    aria-expanded={props.expanded.to_string()}
+                    onclick={props.ontoggle.reform(|_| ())}
+                >
+                    <div class="pf-v5-c-notification-drawer__group-toggle-title">
+                        { &props.title }
+                    </div>
+                    <div class="pf-v5-c-notification-drawer__group-toggle-count">
+                        <Badge read={props.count == 0}>{ props.count }</Badge>
+                    </div>
+                    <span class="pf-v5-c-notification-drawer__group-toggle-icon">
+                        { Icon::AngleRight }
+                    </span>
+                </button>
+            </h1>
+            if props.expanded {
+                { props.children.clone() }
+            }
+        </section>
+    )
+}
+
+/// Properties for [`NotificationDrawerList`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct NotificationDrawerListProperties {
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component(NotificationDrawerList)]
+pub fn notification_drawer_list(props: &NotificationDrawerListProperties) -> Html {
+    html!(
+        <ul class="pf-v5-c-notification-drawer__list" role="list">
+            { props.children.clone() }
+        </ul>
+    )
+}
+
+/// Properties for [`NotificationDrawerListItem`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct NotificationDrawerListItemProperties {
+    pub title: AttrValue,
+
+    /// The variant of the notification, controlling the icon.
+    #[prop_or_default]
+    pub r#type: AlertType,
+
+    #[prop_or_default]
+    pub read: bool,
+
+    #[prop_or(true)]
+    pub hoverable: bool,
+
+    #[prop_or_default]
+    pub timestamp: Option<AttrValue>,
+
+    /// Actions of the item, like a [`crate::prelude::Dropdown`].
+    #[prop_or_default]
+    pub actions: Html,
+
+    #[prop_or_default]
+    pub onclick: Callback<()>,
+
+    /// The description of the notification.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// A single notification.
+#[function_component(NotificationDrawerListItem)]
+pub fn notification_drawer_list_item(props: &NotificationDrawerListItemProperties) -> Html {
+    let mut class = classes!("pf-v5-c-notification-drawer__list-item");
+
+    class.extend(props.r#type.as_classes());
+
+    if props.read {
+        class.push(classes!("pf-m-read"));
+    }
+
+    if props.hoverable {
+        class.push(classes!("pf-m-hoverable"));
+    }
+
+    html!(
+        <li {class} tabindex="0" onclick={props.onclick.reform(|_| ())}>
+            <div class="pf-v5-c-notification-drawer__list-item-header">
+                <span class="pf-v5-c-notification-drawer__list-item-header-icon">
+                    { props.r#type.icon() }
+                </span>
+                <h2 class="pf-v5-c-notification-drawer__list-item-header-title">
+                    <span class="pf-v5-screen-reader">{ props.r#type.aria_label() }{":"}</span>
+                    { &props.title }
+                </h2>
+            </div>
+            // using the actions must not count as clicking the item
+            <div
+                class="pf-v5-c-notification-drawer__list-item-action"
+                onclick={|e: MouseEvent| e.stop_propagation()}
+            >
+                { props.actions.clone() }
+            </div>
+            <div class="pf-v5-c-notification-drawer__list-item-description">
+                { props.children.clone() }
+            </div>
+            if let Some(timestamp) = &props.timestamp {
+                <div class="pf-v5-c-notification-drawer__list-item-timestamp">
+                    { timestamp }
+                </div>
+            }
+        </li>
+    )
+}
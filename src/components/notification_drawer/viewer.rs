@@ -0,0 +1,605 @@
+use super::*;
+use crate::prelude::{
+    Action, Dropdown, ListDivider, MenuAction, MenuToggleVariant, Position, Toast,
+};
+use chrono::{DateTime, Utc};
+use gloo_timers::callback::Interval;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// A notification, kept in the history of a [`NotificationViewer`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Notification {
+    pub title: String,
+    pub r#type: AlertType,
+    pub body: Html,
+    /// The title of the group this notification belongs to.
+    pub group: Option<String>,
+    pub timestamp: DateTime<Utc>,
+    pub actions: Vec<Action>,
+}
+
+impl Default for Notification {
+    fn default() -> Self {
+        Self {
+            title: Default::default(),
+            r#type: Default::default(),
+            body: Default::default(),
+            group: None,
+            timestamp: Utc::now(),
+            actions: Vec::new(),
+        }
+    }
+}
+
+impl From<&str> for Notification {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_string())
+    }
+}
+
+impl From<String> for Notification {
+    fn from(value: String) -> Self {
+        Self {
+            title: value,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<Toast> for Notification {
+    fn from(toast: Toast) -> Self {
+        Self {
+            title: toast.title,
+            r#type: toast.r#type,
+            body: toast.body,
+            actions: toast.actions,
+            ..Default::default()
+        }
+    }
+}
+
+/// A notification, as stored by the [`NotificationViewer`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct NotificationEntry {
+    pub id: usize,
+    pub read: bool,
+    pub notification: Notification,
+}
+
+#[doc(hidden)]
+#[derive(Debug)]
+pub enum NotificationAction {
+    Add(Notification),
+    MarkRead(usize, bool),
+    MarkAllRead,
+    Remove(usize),
+    Clear,
+}
+
+/// A context for adding and managing notifications.
+#[derive(Clone, PartialEq)]
+pub struct Notifier {
+    callback: Callback<NotificationAction>,
+}
+
+impl Notifier {
+    /// Add a new, unread notification.
+    pub fn notify(&self, notification: impl Into<Notification>) {
+        self.callback
+            .emit(NotificationAction::Add(notification.into()))
+    }
+
+    /// Mark a notification as read or unread.
+    pub fn mark_read(&self, id: usize, read: bool) {
+        self.callback.emit(NotificationAction::MarkRead(id, read))
+    }
+
+    /// Mark all notifications as read.
+    pub fn mark_all_read(&self) {
+        self.callback.emit(NotificationAction::MarkAllRead)
+    }
+
+    /// Remove a notification.
+    pub fn remove(&self, id: usize) {
+        self.callback.emit(NotificationAction::Remove(id))
+    }
+
+    /// Remove all notifications.
+    pub fn clear(&self) {
+        self.callback.emit(NotificationAction::Clear)
+    }
+}
+
+/// The current state of the notifications of a [`NotificationViewer`].
+#[derive(Clone, Default)]
+pub struct Notifications {
+    entries: Rc<Vec<NotificationEntry>>,
+}
+
+impl PartialEq for Notifications {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.entries, &other.entries)
+    }
+}
+
+impl Notifications {
+    /// All notifications, newest first.
+    pub fn entries(&self) -> &[NotificationEntry] {
+        &self.entries
+    }
+
+    /// The number of unread notifications.
+    pub fn unread(&self) -> usize {
+        self.entries.iter().filter(|entry| !entry.read).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the entries, grouped by their group title, keeping the order of first appearance.
+    pub fn groups(&self) -> Vec<(Option<&str>, Vec<&NotificationEntry>)> {
+        group_entries(&self.entries)
+    }
+}
+
+fn group_entries(entries: &[NotificationEntry]) -> Vec<(Option<&str>, Vec<&NotificationEntry>)> {
+    let mut result: Vec<(Option<&str>, Vec<&NotificationEntry>)> = Vec::new();
+
+    for entry in entries {
+        let group = entry.notification.group.as_deref();
+        match result.iter_mut().find(|(title, _)| *title == group) {
+            Some((_, items)) => items.push(entry),
+            None => result.push((group, vec![entry])),
+        }
+    }
+
+    result
+}
+
+/// Properties for [`NotificationViewer`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct NotificationViewerProperties {
+    pub children: Html,
+
+    /// The maximum number of notifications to keep, dropping the oldest ones.
+    #[prop_or_default]
+    pub limit: Option<usize>,
+}
+
+/// A component keeping the history of notifications.
+///
+/// Notifications can be added using the [`Notifier`] context, acquired through [`use_notifier`].
+/// The current state is available through [`use_notifications`], and can be rendered using the
+/// [`SimpleNotificationDrawer`] and [`NotificationBadge`] components.
+///
+/// To also record toasts in the history, the [`crate::prelude::ToastViewer`] must be nested in
+/// the notification viewer and have its `record` property set.
+///
+/// ## Example
+///
+/// ```
+/// # use yew::prelude::*;
+/// # use patternfly_yew::prelude::*;
+/// #[function_component(App)]
+/// fn app() -> Html {
+///   html! {
+///     <NotificationViewer>
+///       <ToastViewer record=true>
+///         <View/>
+///       </ToastViewer>
+///     </NotificationViewer>
+///   }
+/// }
+/// #[function_component(View)]
+/// fn view() -> Html {
+///   let expanded = use_state_eq(|| false);
+///   let onclick = use_callback(expanded.clone(), |(), expanded| expanded.set(!**expanded));
+///   let onclose = use_callback(expanded.clone(), |(), expanded| expanded.set(false));
+///
+///   let tools = html!(<NotificationBadge expanded={*expanded} {onclick} />);
+///   let notification_drawer = html!(<SimpleNotificationDrawer {onclose} />);
+///
+///   html!(
+///     <Page {tools} {notification_drawer} notification_drawer_expanded={*expanded}>
+///       { "Content" }
+///     </Page>
+///   )
+/// }
+/// ```
+///
+/// ## Contexts
+///
+/// Provides the following contexts to its children:
+///
+/// * [`Notifier`]
+/// * [`Notifications`]
+pub struct NotificationViewer {
+    context: Notifier,
+    notifications: Notifications,
+    counter: usize,
+}
+
+#[doc(hidden)]
+pub enum NotificationViewerMsg {
+    Perform(NotificationAction),
+}
+
+impl Component for NotificationViewer {
+    type Message = NotificationViewerMsg;
+    type Properties = NotificationViewerProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let context = Notifier {
+            callback: ctx.link().callback(NotificationViewerMsg::Perform),
+        };
+        Self {
+            context,
+            notifications: Default::default(),
+            counter: 0,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            NotificationViewerMsg::Perform(action) => self.perform(ctx, action),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let context = self.context.clone();
+        let notifications = self.notifications.clone();
+
+        html! {
+            <ContextProvider<Notifier> {context}>
+                <ContextProvider<Notifications> context={notifications}>
+                    { ctx.props().children.clone() }
+                </ContextProvider<Notifications>>
+            </ContextProvider<Notifier>>
+        }
+    }
+}
+
+impl NotificationViewer {
+    fn perform(&mut self, ctx: &Context<Self>, action: NotificationAction) -> bool {
+        let entries = Rc::make_mut(&mut self.notifications.entries);
+
+        match action {
+            NotificationAction::Add(notification) => {
+                let id = self.counter;
+                self.counter += 1;
+                entries.insert(
+                    0,
+                    NotificationEntry {
+                        id,
+                        read: false,
+                        notification,
+                    },
+                );
+                if let Some(limit) = ctx.props().limit {
+                    entries.truncate(limit);
+                }
+            }
+            NotificationAction::MarkRead(id, read) => {
+                for entry in entries.iter_mut().filter(|entry| entry.id == id) {
+                    entry.read = read;
+                }
+            }
+            NotificationAction::MarkAllRead => {
+                for entry in entries.iter_mut() {
+                    entry.read = true;
+                }
+            }
+            NotificationAction::Remove(id) => entries.retain(|entry| entry.id != id),
+            NotificationAction::Clear => entries.clear(),
+        }
+
+        true
+    }
+}
+
+/// Get a [`Notifier`] context.
+#[hook]
+pub fn use_notifier() -> Option<Notifier> {
+    use_context()
+}
+
+/// Get the current [`Notifications`] state.
+#[hook]
+pub fn use_notifications() -> Option<Notifications> {
+    use_context()
+}
+
+/// The interval of updating the relative timestamps, in milliseconds.
+const TIMESTAMP_INTERVAL: u32 = 30_000;
+
+/// Properties for [`SimpleNotificationDrawer`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct SimpleNotificationDrawerProperties {
+    #[prop_or(AttrValue::Static("Notifications"))]
+    pub title: AttrValue,
+
+    /// The title of the group for notifications without a group.
+    ///
+    /// Only used when at least one notification has a group.
+    #[prop_or(AttrValue::Static("Other"))]
+    pub default_group: AttrValue,
+
+    /// Content shown when there are no notifications.
+    #[prop_or_default]
+    pub empty: Html,
+
+    /// Called when the user clicks the close button. No close button is shown if unset.
+    #[prop_or_default]
+    pub onclose: Option<Callback<()>>,
+}
+
+/// A notification drawer, showing the notifications of a wrapping [`NotificationViewer`].
+///
+/// Notifications are grouped by their `group`, unread notifications get marked as read when
+/// clicked. Each notification has an action menu, allowing to toggle the read state, trigger
+/// the notification's actions, and remove it.
+///
+/// It can be used as the `notification_drawer` of a [`crate::prelude::Page`].
+///
+/// ## Properties
+///
+/// Defined by [`SimpleNotificationDrawerProperties`].
+///
+/// ## Contexts
+///
+/// Requires a [`Notifier`] and [`Notifications`] context, provided by the
+/// [`NotificationViewer`]. If no context is found, the drawer stays empty.
+#[function_component(SimpleNotificationDrawer)]
+pub fn simple_notification_drawer(props: &SimpleNotificationDrawerProperties) -> Html {
+    let notifier = use_notifier();
+    let notifications = use_notifications().unwrap_or_default();
+
+    // groups are collapsed by default, we track the expanded ones
+    let expanded = use_state_eq(HashSet::<Option<String>>::new);
+
+    // keep the relative timestamps up to date while the drawer is shown
+    let now = use_state(Utc::now);
+    use_effect_with((), {
+        let now = now.clone();
+        move |()| {
+            let interval = Interval::new(TIMESTAMP_INTERVAL, move || now.set(Utc::now()));
+            move || drop(interval)
+        }
+    });
+
+    let item = |entry: &NotificationEntry| -> Html {
+        let id = entry.id;
+        let read = entry.read;
+        let notification = &entry.notification;
+
+        let (onclick, actions) = match &notifier {
+            Some(notifier) => {
+                let onclick = {
+                    let notifier = notifier.clone();
+                    Callback::from(move |()| {
+                        if !read {
+                            notifier.mark_read(id, true)
+                        }
+                    })
+                };
+                let toggle_read = {
+                    let notifier = notifier.clone();
+                    Callback::from(move |()| notifier.mark_read(id, !read))
+                };
+                let remove = {
+                    let notifier = notifier.clone();
+                    Callback::from(move |()| notifier.remove(id))
+                };
+                let actions = html!(
+                    <Dropdown
+                        variant={MenuToggleVariant::Plain}
+                        icon={html!({ Icon::EllipsisV })}
+                        position={Position::Right}
+                        aria_label="Notification actions"
+                    >
+                        <MenuAction onclick={toggle_read}>
+                            { if read { "Mark as unread" } else { "Mark as read" } }
+                        </MenuAction>
+                        { for notification.actions.iter().map(|action| html_nested!(
+                            <MenuAction onclick={action.callback.clone()}>{ &action.label }</MenuAction>
+                        )) }
+                        <ListDivider/>
+                        <MenuAction onclick={remove}>{ "Remove" }</MenuAction>
+                    </Dropdown>
+                );
+                (onclick, actions)
+            }
+            None => (Callback::noop(), html!()),
+        };
+
+        html!(
+            <NotificationDrawerListItem
+                key={id}
+                title={notification.title.clone()}
+                r#type={notification.r#type}
+                {read}
+                timestamp={format_timestamp(notification.timestamp, *now)}
+                {actions}
+                {onclick}
+            >
+                { notification.body.clone() }
+            </NotificationDrawerListItem>
+        )
+    };
+
+    let groups = notifications.groups();
+    let grouped = groups.iter().any(|(title, _)| title.is_some());
+
+    let body = if notifications.is_empty() {
+        props.empty.clone()
+    } else if grouped {
+        html!(
+            <NotificationDrawerGroupList>
+                { for groups.iter().map(|(title, entries)| {
+                    let key = title.map(ToString::to_string);
+                    let ontoggle = {
+                        let expanded = expanded.clone();
+                        let key = key.clone();
+                        Callback::from(move |()| {
+                            let mut state = (*expanded).clone();
+                            if !state.remove(&key) {
+                                state.insert(key.clone());
+                            }
+                            expanded.set(state);
+                        })
+                    };
+                    let count = entries.iter().filter(|entry| !entry.read).count();
+                    let title = title
+                        .map(|title| AttrValue::from(title.to_string()))
+                        .unwrap_or_else(|| props.default_group.clone());
+
+                    html!(
+                        <NotificationDrawerGroup
+                            {title}
+                            {count}
+                            expanded={expanded.contains(&key)}
+                            {ontoggle}
+                        >
+                            <NotificationDrawerList>
+                                { for entries.iter().map(|entry| item(entry)) }
+                            </NotificationDrawerList>
+                        </NotificationDrawerGroup>
+                    )
+                }) }
+            </NotificationDrawerGroupList>
+        )
+    } else {
+        html!(
+            <NotificationDrawerList>
+                { for notifications.entries().iter().map(item) }
+            </NotificationDrawerList>
+        )
+    };
+
+    let header_actions = match &notifier {
+        Some(notifier) => {
+            let mark_all_read = {
+                let notifier = notifier.clone();
+                Callback::from(move |()| notifier.mark_all_read())
+            };
+            let clear = {
+                let notifier = notifier.clone();
+                Callback::from(move |()| notifier.clear())
+            };
+            html!(
+                <Dropdown
+                    variant={MenuToggleVariant::Plain}
+                    icon={html!({ Icon::EllipsisV })}
+                    position={Position::Right}
+                    aria_label="Notification drawer actions"
+                >
+                    <MenuAction onclick={mark_all_read}>{ "Mark all read" }</MenuAction>
+                    <MenuAction onclick={clear}>{ "Clear all" }</MenuAction>
+                </Dropdown>
+            )
+        }
+        None => html!(),
+    };
+
+    html!(
+        <NotificationDrawer>
+            <NotificationDrawerHeader
+                title={props.title.clone()}
+                count={notifications.unread()}
+                onclose={props.onclose.clone()}
+            >
+                { header_actions }
+            </NotificationDrawerHeader>
+            <NotificationDrawerBody>
+                { body }
+            </NotificationDrawerBody>
+        </NotificationDrawer>
+    )
+}
+
+/// Format a timestamp relative to `now`, like "5 minutes ago".
+fn format_timestamp(timestamp: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let diff = now - timestamp;
+
+    let (value, unit) = if diff.num_days() > 0 {
+        (diff.num_days(), "day")
+    } else if diff.num_hours() > 0 {
+        (diff.num_hours(), "hour")
+    } else if diff.num_minutes() > 0 {
+        (diff.num_minutes(), "minute")
+    } else {
+        return "Just now".to_string();
+    };
+
+    match value {
+        1 => format!("1 {unit} ago"),
+        n => format!("{n} {unit}s ago"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::Duration;
+
+    fn entry(id: usize, group: Option<&str>) -> NotificationEntry {
+        NotificationEntry {
+            id,
+            read: false,
+            notification: Notification {
+                group: group.map(ToString::to_string),
+                ..Notification::from(format!("Notification {id}"))
+            },
+        }
+    }
+
+    #[test]
+    fn test_group_entries() {
+        let entries = vec![
+            entry(0, Some("a")),
+            entry(1, None),
+            entry(2, Some("b")),
+            entry(3, Some("a")),
+        ];
+
+        let groups = group_entries(&entries)
+            .into_iter()
+            .map(|(title, entries)| (title, entries.iter().map(|e| e.id).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            groups,
+            vec![
+                (Some("a"), vec![0, 3]),
+                (None, vec![1]),
+                (Some("b"), vec![2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        let now = Utc::now();
+
+        assert_eq!(format_timestamp(now, now), "Just now");
+        assert_eq!(
+            format_timestamp(now - Duration::seconds(30), now),
+            "Just now"
+        );
+        assert_eq!(
+            format_timestamp(now - Duration::minutes(1), now),
+            "1 minute ago"
+        );
+        assert_eq!(
+            format_timestamp(now - Duration::minutes(5), now),
+            "5 minutes ago"
+        );
+        assert_eq!(
+            format_timestamp(now - Duration::hours(3), now),
+            "3 hours ago"
+        );
+        assert_eq!(format_timestamp(now - Duration::days(2), now), "2 days ago");
+    }
+}
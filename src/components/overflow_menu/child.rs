@@ -0,0 +1,104 @@
+use super::*;
+use std::rc::Rc;
+use yew::{
+    prelude::*,
+    virtual_dom::{VChild, VComp},
+};
+
+/// Child of an [`OverflowMenu`] component
+#[derive(Clone, PartialEq)]
+pub enum OverflowMenuChild {
+    Content(Rc<<OverflowMenuContent as BaseComponent>::Properties>),
+    Control(Rc<<OverflowMenuControl as BaseComponent>::Properties>),
+}
+
+impl From<OverflowMenuContentProperties> for OverflowMenuChild {
+    fn from(props: OverflowMenuContentProperties) -> Self {
+        OverflowMenuChild::Content(Rc::new(props))
+    }
+}
+
+impl From<OverflowMenuControlProperties> for OverflowMenuChild {
+    fn from(props: OverflowMenuControlProperties) -> Self {
+        OverflowMenuChild::Control(Rc::new(props))
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct OverflowMenuChildVariant {
+    pub(crate) props: OverflowMenuChild,
+}
+
+impl<CHILD> From<VChild<CHILD>> for OverflowMenuChildVariant
+where
+    CHILD: BaseComponent,
+    CHILD::Properties: Into<OverflowMenuChild> + Clone,
+{
+    fn from(vchild: VChild<CHILD>) -> Self {
+        Self {
+            props: (*vchild.props).clone().into(),
+        }
+    }
+}
+
+impl From<OverflowMenuChildVariant> for Html {
+    fn from(value: OverflowMenuChildVariant) -> Self {
+        match value.props {
+            OverflowMenuChild::Content(props) => {
+                VComp::new::<OverflowMenuContent>(props, None).into()
+            }
+            OverflowMenuChild::Control(props) => {
+                VComp::new::<OverflowMenuControl>(props, None).into()
+            }
+        }
+    }
+}
+
+/// Child of an [`OverflowMenuContent`] component
+#[derive(Clone, PartialEq)]
+pub enum OverflowMenuContentChild {
+    Group(Rc<<OverflowMenuGroup as BaseComponent>::Properties>),
+    Item(Rc<<OverflowMenuItem as BaseComponent>::Properties>),
+}
+
+impl From<OverflowMenuGroupProperties> for OverflowMenuContentChild {
+    fn from(props: OverflowMenuGroupProperties) -> Self {
+        OverflowMenuContentChild::Group(Rc::new(props))
+    }
+}
+
+impl From<OverflowMenuItemProperties> for OverflowMenuContentChild {
+    fn from(props: OverflowMenuItemProperties) -> Self {
+        OverflowMenuContentChild::Item(Rc::new(props))
+    }
+}
+
+#[derive(PartialEq, Clone)]
+pub struct OverflowMenuContentChildVariant {
+    pub(crate) props: OverflowMenuContentChild,
+}
+
+impl<CHILD> From<VChild<CHILD>> for OverflowMenuContentChildVariant
+where
+    CHILD: BaseComponent,
+    CHILD::Properties: Into<OverflowMenuContentChild> + Clone,
+{
+    fn from(vchild: VChild<CHILD>) -> Self {
+        Self {
+            props: (*vchild.props).clone().into(),
+        }
+    }
+}
+
+impl From<OverflowMenuContentChildVariant> for Html {
+    fn from(value: OverflowMenuContentChildVariant) -> Self {
+        match value.props {
+            OverflowMenuContentChild::Group(props) => {
+                VComp::new::<OverflowMenuGroup>(props, None).into()
+            }
+            OverflowMenuContentChild::Item(props) => {
+                VComp::new::<OverflowMenuItem>(props, None).into()
+            }
+        }
+    }
+}
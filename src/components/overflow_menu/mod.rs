@@ -0,0 +1,307 @@
+//! Overflow menu
+mod child;
+
+pub use child::*;
+
+use crate::prelude::{
+    use_breakpoint, AsClasses, Breakpoint, Dropdown, ExtendClasses, Icon, ListDivider, MenuAction,
+    MenuChildVariant, MenuToggleVariant, Position,
+};
+use std::rc::Rc;
+use yew::{
+    html::{ChildrenRenderer, ChildrenWithProps},
+    prelude::*,
+    virtual_dom::VChild,
+};
+
+#[derive(Clone, PartialEq)]
+struct OverflowMenuContext {
+    collapsed: bool,
+    /// The actions of the items which are hidden, when collapsed
+    actions: Rc<Vec<VChild<MenuAction>>>,
+}
+
+/// Properties for [`OverflowMenu`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct OverflowMenuProperties {
+    /// The breakpoint below which non-persistent items are moved into the control.
+    #[prop_or(Breakpoint::Large)]
+    pub breakpoint: Breakpoint,
+
+    #[prop_or_default]
+    pub children: ChildrenRenderer<OverflowMenuChildVariant>,
+
+    /// Additional classes
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// Overflow menu component
+///
+/// > An **overflow menu** is a responsive component that consolidates horizontal actions into a
+/// > vertical menu on smaller viewports.
+///
+/// See: <https://www.patternfly.org/components/overflow-menu>
+///
+/// Below the `breakpoint`, all content, groups, and items which are not `persistent` get hidden.
+/// The hidden items which have an `action` get moved into the [`OverflowMenuControl`].
+///
+/// ## Properties
+///
+/// Defined by [`OverflowMenuProperties`].
+///
+/// ## Children
+///
+/// The overflow menu requires [`OverflowMenuContent`] children, and typically one
+/// [`OverflowMenuControl`].
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let onedit = Callback::from(|()| log::info!("Edit"));
+///   html!(
+///     <OverflowMenu breakpoint={Breakpoint::Large}>
+///       <OverflowMenuContent>
+///         <OverflowMenuGroup variant={OverflowMenuGroupVariant::Button}>
+///           <OverflowMenuItem
+///             action={html_nested!(<MenuAction onclick={onedit.clone()}>{ "Edit" }</MenuAction>)}
+///           >
+///             <Button variant={ButtonVariant::Primary} onclick={onedit.reform(|_| ())}>{ "Edit" }</Button>
+///           </OverflowMenuItem>
+///         </OverflowMenuGroup>
+///       </OverflowMenuContent>
+///       <OverflowMenuControl />
+///     </OverflowMenu>
+///   )
+/// }
+/// ```
+#[function_component(OverflowMenu)]
+pub fn overflow_menu(props: &OverflowMenuProperties) -> Html {
+    let breakpoint = use_breakpoint();
+    let collapsed = *breakpoint < props.breakpoint;
+
+    let actions = match collapsed {
+        true => collect_actions(&props.children),
+        false => vec![],
+    };
+    let context = OverflowMenuContext {
+        collapsed,
+        actions: Rc::new(actions),
+    };
+
+    let class = classes!("pf-v5-c-overflow-menu", props.class.clone());
+
+    html!(
+        <ContextProvider<OverflowMenuContext> {context}>
+            <div {class}>
+                { for props.children.iter() }
+            </div>
+        </ContextProvider<OverflowMenuContext>>
+    )
+}
+
+/// Collect the actions of all items which are hidden when collapsed.
+fn collect_actions(
+    children: &ChildrenRenderer<OverflowMenuChildVariant>,
+) -> Vec<VChild<MenuAction>> {
+    // an item is hidden if any of its parents isn't persistent
+    fn items(
+        persistent: bool,
+        items: &ChildrenWithProps<OverflowMenuItem>,
+        actions: &mut Vec<VChild<MenuAction>>,
+    ) {
+        for item in items.iter() {
+            if !persistent || !item.props.persistent {
+                actions.extend(item.props.action.clone());
+            }
+        }
+    }
+
+    let mut actions = vec![];
+
+    for child in children.iter() {
+        let OverflowMenuChild::Content(content) = &child.props else {
+            continue;
+        };
+        for child in content.children.iter() {
+            match &child.props {
+                OverflowMenuContentChild::Group(group) => items(
+                    content.persistent && group.persistent,
+                    &group.children,
+                    &mut actions,
+                ),
+                OverflowMenuContentChild::Item(item) => {
+                    if !content.persistent || !item.persistent {
+                        actions.extend(item.action.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    actions
+}
+
+/// Check if an element is visible, based on its persistence.
+#[hook]
+fn use_visible(persistent: bool) -> bool {
+    let collapsed = use_context::<OverflowMenuContext>()
+        .map(|context| context.collapsed)
+        .unwrap_or_default();
+    persistent || !collapsed
+}
+
+/// Properties for [`OverflowMenuContent`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct OverflowMenuContentProperties {
+    /// Keep the content visible below the breakpoint.
+    #[prop_or_default]
+    pub persistent: bool,
+
+    #[prop_or_default]
+    pub children: ChildrenRenderer<OverflowMenuContentChildVariant>,
+}
+
+#[function_component(OverflowMenuContent)]
+pub fn overflow_menu_content(props: &OverflowMenuContentProperties) -> Html {
+    let visible = use_visible(props.persistent);
+
+    html!(
+        if visible {
+            <div class="pf-v5-c-overflow-menu__content">
+                { for props.children.iter() }
+            </div>
+        }
+    )
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OverflowMenuGroupVariant {
+    #[default]
+    None,
+    Button,
+    IconButton,
+}
+
+impl AsClasses for OverflowMenuGroupVariant {
+    fn extend_classes(&self, classes: &mut Classes) {
+        match self {
+            Self::None => {}
+            Self::Button => classes.push("pf-m-button-group"),
+            Self::IconButton => classes.push("pf-m-icon-button-group"),
+        }
+    }
+}
+
+/// Properties for [`OverflowMenuGroup`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct OverflowMenuGroupProperties {
+    /// Keep the group visible below the breakpoint.
+    #[prop_or_default]
+    pub persistent: bool,
+
+    #[prop_or_default]
+    pub variant: OverflowMenuGroupVariant,
+
+    #[prop_or_default]
+    pub children: ChildrenWithProps<OverflowMenuItem>,
+}
+
+#[function_component(OverflowMenuGroup)]
+pub fn overflow_menu_group(props: &OverflowMenuGroupProperties) -> Html {
+    let visible = use_visible(props.persistent);
+
+    let mut class = classes!("pf-v5-c-overflow-menu__group");
+    class.extend_from(&props.variant);
+
+    html!(
+        if visible {
+            <div {class}>
+                { for props.children.iter() }
+            </div>
+        }
+    )
+}
+
+/// Properties for [`OverflowMenuItem`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct OverflowMenuItemProperties {
+    /// Keep the item visible below the breakpoint.
+    #[prop_or_default]
+    pub persistent: bool,
+
+    /// The action representing the item in the [`OverflowMenuControl`], when hidden.
+    #[prop_or_default]
+    pub action: Option<VChild<MenuAction>>,
+
+    #[prop_or_default]
+    pub children: Html,
+}
+
+#[function_component(OverflowMenuItem)]
+pub fn overflow_menu_item(props: &OverflowMenuItemProperties) -> Html {
+    let visible = use_visible(props.persistent);
+
+    html!(
+        if visible {
+            <div class="pf-v5-c-overflow-menu__item">
+                { props.children.clone() }
+            </div>
+        }
+    )
+}
+
+/// Properties for [`OverflowMenuControl`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct OverflowMenuControlProperties {
+    /// Always show the control, as it has additional options.
+    #[prop_or_default]
+    pub additional: bool,
+
+    /// Additional options, shown after the actions of hidden items.
+    #[prop_or_default]
+    pub children: ChildrenRenderer<MenuChildVariant>,
+
+    #[prop_or(AttrValue::Static("Actions"))]
+    pub aria_label: AttrValue,
+}
+
+/// The control of the [`OverflowMenu`], a kebab dropdown with the actions of hidden items.
+#[function_component(OverflowMenuControl)]
+pub fn overflow_menu_control(props: &OverflowMenuControlProperties) -> Html {
+    let context = use_context::<OverflowMenuContext>();
+    let (collapsed, actions) = context
+        .map(|context| (context.collapsed, context.actions))
+        .unwrap_or_default();
+
+    let visible =
+        props.additional || (collapsed && !(actions.is_empty() && props.children.is_empty()));
+
+    if !visible {
+        return html!();
+    }
+
+    let mut children: Vec<MenuChildVariant> = actions.iter().cloned().map(Into::into).collect();
+    if !children.is_empty() && !props.children.is_empty() {
+        children.push(VChild::<ListDivider>::new((), None).into());
+    }
+    children.extend(props.children.iter());
+
+    html!(
+        <div class="pf-v5-c-overflow-menu__control">
+            <Dropdown
+                variant={MenuToggleVariant::Plain}
+                icon={html!({ Icon::EllipsisV })}
+                position={Position::Right}
+                aria_label={&props.aria_label}
+            >
+                { ChildrenRenderer::new(children) }
+            </Dropdown>
+        </div>
+    )
+}
@@ -5,9 +5,11 @@ use yew::prelude::*;
 
 mod section;
 mod sidebar;
+mod tools;
 
 pub use section::*;
 pub use sidebar::*;
+pub use tools::*;
 
 /// Properties for [`Page`]
 #[derive(Clone, PartialEq, Properties)]
@@ -16,6 +18,9 @@ pub struct PageProperties {
     pub children: Html,
     #[prop_or_default]
     pub sidebar: ChildrenWithProps<PageSidebar>,
+    /// The tools section of the masthead.
+    ///
+    /// Typically a [`PageTools`] component.
     #[prop_or_default]
     pub tools: Html,
 
@@ -38,6 +43,13 @@ pub struct PageProperties {
     pub id: Option<AttrValue>,
     #[prop_or_default]
     pub on_main_scroll: Callback<Event>,
+
+    /// The notification drawer, e.g. a [`crate::prelude::SimpleNotificationDrawer`].
+    #[prop_or_default]
+    pub notification_drawer: Option<Html>,
+    /// The expanded state of the notification drawer.
+    #[prop_or_default]
+    pub notification_drawer_expanded: bool,
 }
 
 /// A full page
@@ -57,7 +69,12 @@ pub struct PageProperties {
 /// * **Tools**: Tools, shown in the header section of the page.
 /// * **Brand**: A brand logo, shown in the navigation header section.
 /// * **Children**: The actual page content, probably wrapped into [`PageSection`] components.
+/// * **Notification drawer**: A drawer, shown next to the page content when expanded.
 ///
+/// ## Contexts
+///
+/// Provides a context to its children, which allows components like
+/// [`crate::prelude::JumpLinks`] to use the main section as their scroll container.
 #[function_component(Page)]
 pub fn page(props: &PageProperties) -> Html {
     let open = use_state_eq(|| props.open);
@@ -69,6 +86,20 @@ pub fn page(props: &PageProperties) -> Html {
         })
     };
     let onscroll = props.on_main_scroll.clone();
+
+    let main_ref = use_node_ref();
+    let context = PageContext {
+        main: main_ref.clone(),
+    };
+
+    let main = html!(
+        <main ref={main_ref} class="pf-v5-c-page__main" tabindex="-1" {onscroll}>
+            <ContextProvider<PageContext> {context}>
+                { props.children.clone() }
+            </ContextProvider<PageContext>>
+        </main>
+    );
+
     html! (
         <div class="pf-v5-c-page" id={&props.id} role="main" tabindex="-1">
             <header class="pf-v5-c-masthead">
@@ -103,13 +134,35 @@ pub fn page(props: &PageProperties) -> Html {
                 s
             }) }
 
-            <main class="pf-v5-c-page__main" tabindex="-1" {onscroll}>
-                { props.children.clone() }
-            </main>
+            if let Some(notification_drawer) = &props.notification_drawer {
+                <div class="pf-v5-c-page__drawer">
+                    <div class={classes!("pf-v5-c-drawer", props.notification_drawer_expanded.then_some("pf-m-expanded"))}>
+                        <div class="pf-v5-c-drawer__main">
+                            <div class="pf-v5-c-drawer__content">
+                                { main }
+                            </div>
+                            <div class="pf-v5-c-drawer__panel" hidden={!props.notification_drawer_expanded}>
+                                <div class="pf-v5-c-drawer__body pf-m-no-padding">
+                                    { notification_drawer.clone() }
+                                </div>
+                            </div>
+                        </div>
+                    </div>
+                </div>
+            } else {
+                { main }
+            }
         </div>
     )
 }
 
+/// Context provided by [`Page`] to its content.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PageContext {
+    /// The main section, which scrolls the content and emits `on_main_scroll`.
+    pub main: NodeRef,
+}
+
 #[derive(Clone, Debug, PartialEq, Properties)]
 pub struct MastheadBrandProperties {
     /// Expected to be a single [`crate::prelude::Brand`] component.
@@ -0,0 +1,141 @@
+use crate::prelude::{
+    Avatar, AvatarSize, Breakpoint, Dropdown, MenuChildVariant, MenuToggleVariant, OverflowMenu,
+    OverflowMenuChildVariant, Position,
+};
+use yew::{html::ChildrenRenderer, prelude::*};
+
+/// Properties for [`PageTools`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct PageToolsProperties {
+    /// The breakpoint below which non-persistent tools are moved into the kebab.
+    #[prop_or(Breakpoint::Large)]
+    pub breakpoint: Breakpoint,
+
+    #[prop_or_default]
+    pub children: ChildrenRenderer<OverflowMenuChildVariant>,
+}
+
+/// The tools section of the masthead.
+///
+/// A full height toolbar, aligned to the right, wrapping an [`OverflowMenu`]. Below the
+/// `breakpoint`, tools which are not `persistent` collapse into the
+/// [`crate::prelude::OverflowMenuControl`].
+///
+/// This is intended to be used as the `tools` of a [`super::Page`].
+///
+/// ## Properties
+///
+/// Defined by [`PageToolsProperties`].
+///
+/// ## Children
+///
+/// The same children as an [`OverflowMenu`].
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let onsettings = Callback::from(|()| log::info!("Settings"));
+///
+///   let tools = html!(
+///     <PageTools>
+///       <OverflowMenuContent>
+///         <OverflowMenuGroup variant={OverflowMenuGroupVariant::IconButton}>
+///           <OverflowMenuItem
+///             action={html_nested!(<MenuAction onclick={onsettings.clone()}>{ "Settings" }</MenuAction>)}
+///           >
+///             <Button variant={ButtonVariant::Plain} icon={Icon::Cog} onclick={onsettings.reform(|_| ())} />
+///           </OverflowMenuItem>
+///         </OverflowMenuGroup>
+///       </OverflowMenuContent>
+///       <OverflowMenuContent persistent=true>
+///         <OverflowMenuItem persistent=true>
+///           <AppLauncher>
+///             <MenuLink href="/console">{ "Console" }</MenuLink>
+///           </AppLauncher>
+///         </OverflowMenuItem>
+///         <OverflowMenuItem persistent=true>
+///           <UserDropdown name="Jane Doe" avatar="/assets/avatar.svg">
+///             <MenuAction>{ "Logout" }</MenuAction>
+///           </UserDropdown>
+///         </OverflowMenuItem>
+///       </OverflowMenuContent>
+///       <OverflowMenuControl />
+///     </PageTools>
+///   );
+///
+///   html!(
+///     <Page {tools}>
+///       { "Content" }
+///     </Page>
+///   )
+/// }
+/// ```
+#[function_component(PageTools)]
+pub fn page_tools(props: &PageToolsProperties) -> Html {
+    html!(
+        <div class="pf-v5-c-toolbar pf-m-full-height pf-m-static">
+            <div class="pf-v5-c-toolbar__content">
+                <div class="pf-v5-c-toolbar__content-section">
+                    <div class="pf-v5-c-toolbar__group pf-m-align-right">
+                        <div class="pf-v5-c-toolbar__item">
+                            <OverflowMenu breakpoint={props.breakpoint}>
+                                { props.children.clone() }
+                            </OverflowMenu>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        </div>
+    )
+}
+
+/// Properties for [`UserDropdown`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct UserDropdownProperties {
+    /// The name of the user, shown in the toggle.
+    pub name: AttrValue,
+
+    /// The source of the avatar image.
+    #[prop_or_default]
+    pub avatar: Option<AttrValue>,
+
+    /// The options of the dropdown.
+    #[prop_or_default]
+    pub children: ChildrenRenderer<MenuChildVariant>,
+
+    #[prop_or(Position::Right)]
+    pub position: Position,
+}
+
+/// A dropdown of the current user, showing the name and avatar.
+///
+/// This is intended to be used as part of the [`PageTools`].
+///
+/// ## Properties
+///
+/// Defined by [`UserDropdownProperties`].
+#[function_component(UserDropdown)]
+pub fn user_dropdown(props: &UserDropdownProperties) -> Html {
+    let icon = props.avatar.as_ref().map(|src| {
+        html!(
+            <Avatar src={src.clone()} alt={props.name.clone()} size={AvatarSize::Small} />
+        )
+    });
+
+    html!(
+        <Dropdown
+            variant={MenuToggleVariant::Plain}
+            text={props.name.to_string()}
+            {icon}
+            position={props.position}
+            full_height=true
+        >
+            { props.children.clone() }
+        </Dropdown>
+    )
+}
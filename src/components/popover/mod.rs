@@ -1,5 +1,7 @@
 //! Popover
-use crate::prelude::{Button, ButtonVariant, ExtendClasses, Icon, Orientation};
+use crate::prelude::{
+    use_focus_trap, Button, ButtonVariant, ExtendClasses, Icon, InitialFocus, Orientation,
+};
 use popper_rs::{
     prelude::{State as PopperState, *},
     yew::component::PortalPopper,
@@ -37,6 +39,13 @@ pub struct PopoverProperties {
 
     #[prop_or_default]
     pub width_auto: bool,
+
+    /// Disable trapping the focus inside the popover while it is open
+    #[prop_or_default]
+    pub disable_focus_trap: bool,
+    /// The element to focus when the popover opens
+    #[prop_or_default]
+    pub initial_focus: InitialFocus,
 }
 
 /// Popover component
@@ -70,6 +79,12 @@ pub fn popover(props: &PopoverProperties) -> Html {
         });
     }
 
+    use_focus_trap(
+        content_ref.clone(),
+        *active && !props.disable_focus_trap,
+        props.initial_focus.clone(),
+    );
+
     let style = match *active {
         true => "pointer-events: none;",
         false => "",
@@ -64,7 +64,7 @@ impl AsClasses for ProgressMeasureLocation {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Default)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
 pub enum ProgressVariant {
     #[default]
     Default,
@@ -1,4 +1,4 @@
-use crate::prelude::{Button, ButtonVariant};
+use crate::prelude::{Button, ButtonVariant, ReorderHandle, ReorderItem};
 use yew::prelude::*;
 
 use super::*;
@@ -140,6 +140,9 @@ pub struct TableRowProperties {
     pub expanded: bool,
     #[prop_or_default]
     pub control_row: bool,
+    /// Make the row reorderable, see [`crate::prelude::use_reorder`].
+    #[prop_or_default]
+    pub reorder: Option<ReorderItem>,
 }
 
 #[function_component(TableRow)]
@@ -160,10 +163,36 @@ pub fn table_row(props: &TableRowProperties) -> Html {
     if props.control_row {
         class.push("pf-v5-c-table__control-row");
     }
-    html! {
-        <tr class={class.clone()} role="row" onclick={props.onclick.clone()}>
-            {props.children.clone()}
-        </tr>
+
+    match &props.reorder {
+        Some(item) => {
+            if item.dragging {
+                class.push("pf-m-ghost-row");
+            }
+            html! {
+                <tr
+                    {class}
+                    role="row"
+                    onclick={props.onclick.clone()}
+                    draggable="true"
+                    data-reorder-position={item.position.to_string()}
+                    ondragstart={item.ondragstart.clone()}
+                    ondragover={item.ondragover.clone()}
+                    ondrop={item.ondrop.clone()}
+                    ondragend={item.ondragend.clone()}
+                >
+                    <td class="pf-v5-c-table__td pf-v5-c-table__draggable" role="cell">
+                        <ReorderHandle item={item.clone()} />
+                    </td>
+                    {props.children.clone()}
+                </tr>
+            }
+        }
+        None => html! {
+            <tr class={class.clone()} role="row" onclick={props.onclick.clone()}>
+                {props.children.clone()}
+            </tr>
+        },
     }
 }
 
@@ -53,7 +53,18 @@ where
     #[prop_or_default]
     pub(crate) expandable: bool,
     #[prop_or_default]
+    pub(crate) draggable: bool,
+    #[prop_or_default]
     pub hide_actions: bool,
+
+    /// The current sort state, when controlled by the user.
+    ///
+    /// Only used together with [`Self::onsort`].
+    #[prop_or_default]
+    pub sortby: Option<TableHeaderSortBy<K>>,
+    /// Called when the sort state changes. Setting it lets the user control the sort state.
+    #[prop_or_default]
+    pub onsort: Option<Callback<TableHeaderSortBy<K>>>,
 }
 
 /// The Table Header component.
@@ -71,9 +82,15 @@ where
         sortby.set(Some(val));
     });
 
-    let table_header_context = TableHeaderContext {
-        onsort,
-        sortby: (*sortby).clone(),
+    let table_header_context = match &props.onsort {
+        Some(onsort) => TableHeaderContext {
+            onsort: onsort.clone(),
+            sortby: props.sortby.clone(),
+        },
+        None => TableHeaderContext {
+            onsort,
+            sortby: (*sortby).clone(),
+        },
     };
 
     html! (
@@ -81,6 +98,10 @@ where
 
             <tr class="pf-v5-c-table__tr" role="row">
 
+                if props.draggable {
+                    <td class="pf-v5-c-table__td pf-v5-c-table__draggable" role="cell"></td>
+                }
+
                 if props.expandable {
                     <td class="pf-v5-c-table__td pf-v5-c-table__toggle" role="cell"></td>
                 }
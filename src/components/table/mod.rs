@@ -5,6 +5,8 @@ mod header;
 mod model;
 mod props;
 mod render;
+#[cfg(feature = "yew-nested-router")]
+mod router;
 
 pub use cell::*;
 pub use column::*;
@@ -13,9 +15,14 @@ pub use header::*;
 pub use model::*;
 pub use props::*;
 pub use render::*;
+#[cfg(feature = "yew-nested-router")]
+pub use router::*;
 
 use crate::ouia;
-use crate::prelude::{Dropdown, ExtendClasses, Icon, MenuChildVariant, MenuToggleVariant};
+use crate::prelude::{
+    use_reorder, Dropdown, ExtendClasses, Icon, MenuChildVariant, MenuToggleVariant, Reorder,
+    UseReorder,
+};
 use crate::utils::{Ouia, OuiaComponentType, OuiaSafe};
 use yew::{prelude::*, virtual_dom::VChild};
 
@@ -62,6 +69,13 @@ where
     #[prop_or_default]
     pub row_selected: Option<Callback<<M as TableModel<C>>::Item, bool>>,
 
+    /// Make the rows reorderable using drag and drop.
+    ///
+    /// Called with the [`Reorder`] to apply to the entries, once the user dropped a row. Rows
+    /// of expandable tables can't be reordered.
+    #[prop_or_default]
+    pub onreorder: Option<Callback<Reorder>>,
+
     /// OUIA Component id
     #[prop_or_default]
     pub ouia_id: Option<String>,
@@ -199,31 +213,46 @@ where
         },
     );
 
+    let reorder = use_reorder(
+        props.entries.len(),
+        props.onreorder.clone().unwrap_or_default(),
+    );
+    let draggable = props.onreorder.is_some() && !props.is_expandable();
+
     let expandable = props.is_expandable() && !props.are_columns_expandable();
     html!(
-        <ComposableTable
-            id={&props.id}
-            class={props.class.clone()}
-            sticky_header={props.header.as_ref().is_some_and(|header| header.props.sticky)}
-            mode={props.mode}
-            borders={props.borders}
-            grid={props.grid}
-            ouia_id={props.ouia_id.clone()}
-            ouia_type={props.ouia_type}
-            ouia_safe={props.ouia_safe}
-        >
-            if let Some(caption) = &props.caption {
-                <Caption>{caption}</Caption>
-            }
-            if let Some(header) = props.header.clone() {
-                <TableHeader<C> {expandable} ..(*header.props).clone() />
+        <>
+            <ComposableTable
+                id={&props.id}
+                class={props.class.clone()}
+                sticky_header={props.header.as_ref().is_some_and(|header| header.props.sticky)}
+                mode={props.mode}
+                borders={props.borders}
+                grid={props.grid}
+                ouia_id={props.ouia_id.clone()}
+                ouia_type={props.ouia_type}
+                ouia_safe={props.ouia_safe}
+            >
+                if let Some(caption) = &props.caption {
+                    <Caption>{caption}</Caption>
+                }
+                if let Some(header) = props.header.clone() {
+                    <TableHeader<C> {expandable} {draggable} ..(*header.props).clone() />
+                }
+                { render_entries(props, &expandable_columns, draggable.then_some(&reorder)) }
+            </ComposableTable>
+            if draggable {
+                { reorder.live_region() }
             }
-            { render_entries(props, &expandable_columns) }
-        </ComposableTable>
+        </>
     )
 }
 
-fn render_entries<C, M>(props: &TableProperties<C, M>, expandable_columns: &[C]) -> Html
+fn render_entries<C, M>(
+    props: &TableProperties<C, M>,
+    expandable_columns: &[C],
+    reorder: Option<&UseReorder>,
+) -> Html
 where
     C: Clone + Eq + 'static,
     M: PartialEq + TableModel<C> + 'static,
@@ -243,10 +272,21 @@ where
                 }
             })
         };
+        // while dragging, show the rows in their new order
+        let mut entries = props.entries.iter().map(Some).collect::<Vec<_>>();
+        let order = match reorder {
+            Some(reorder) => reorder.order(),
+            None => (0..entries.len()).collect(),
+        };
+        let rows = order
+            .into_iter()
+            .filter_map(|index| entries.get_mut(index).and_then(Option::take));
+
         html!(
             <TableBody> {
-                for props.entries.iter().map(|entry| {
+                for rows.enumerate().map(|(position, entry)| {
                     let selected = props.row_selected.as_ref().is_some_and(|f| f.emit(entry.value.clone()));
+                    let reorder = reorder.map(|reorder| reorder.item(position));
                     let content = { render_row(props, &entry, |_| false)};
                     let onclick = if props.onrowclick.is_some() {
                         let cb = row_click_cb.clone();
@@ -256,7 +296,7 @@ where
                         None
                     };
                     html! {
-                        <TableRow {onclick} {selected}>
+                        <TableRow key={entry.key.into()} {onclick} {selected} {reorder}>
                             {content}
                         </TableRow>
                     }
@@ -0,0 +1,277 @@
+use crate::core::Order;
+use crate::prelude::{TableHeaderContext, TableHeaderSortBy};
+use crate::utils::query;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use yew::prelude::*;
+use yew_hooks::use_latest;
+use yew_nested_router::History;
+
+const SORT: &str = "sort";
+const ORDER: &str = "order";
+const FILTER: &str = "filter.";
+
+const ASCENDING: &str = "asc";
+const DESCENDING: &str = "desc";
+
+/// The sort and filter state of a table, stored in the query of the URL.
+///
+/// The column is stored in the `sort` parameter, using its [`Display`] and [`FromStr`]
+/// implementations. The order is stored in the `order` parameter, as `asc` or `desc`. Filters
+/// are stored as `filter.<key>=<value>`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableQueryState<K>
+where
+    K: Clone + Eq,
+{
+    pub sortby: Option<TableHeaderSortBy<K>>,
+    pub filters: BTreeMap<String, String>,
+}
+
+impl<K> Default for TableQueryState<K>
+where
+    K: Clone + Eq,
+{
+    fn default() -> Self {
+        Self {
+            sortby: None,
+            filters: Default::default(),
+        }
+    }
+}
+
+impl<K> TableQueryState<K>
+where
+    K: Clone + Eq + FromStr + Display,
+{
+    /// Parse the state from a query, ignoring unknown columns and orders.
+    pub fn from_query(search: &str) -> Self {
+        let mut index = None;
+        let mut order = Order::Ascending;
+        let mut filters = BTreeMap::new();
+
+        for (key, value) in query::params(search) {
+            match key.as_str() {
+                SORT => index = value.parse::<K>().ok(),
+                ORDER => {
+                    order = match value.as_str() {
+                        DESCENDING => Order::Descending,
+                        _ => Order::Ascending,
+                    }
+                }
+                key => {
+                    if let Some(key) = key.strip_prefix(FILTER) {
+                        filters.insert(key.to_string(), value);
+                    }
+                }
+            }
+        }
+
+        Self {
+            sortby: index.map(|index| TableHeaderSortBy { index, order }),
+            filters,
+        }
+    }
+
+    /// Replace the sort and filter parameters of a query, keeping all others.
+    pub fn to_query(&self, search: &str) -> String {
+        let sort = self.sortby.iter().flat_map(|sortby| {
+            let order = match sortby.order {
+                Order::Ascending => ASCENDING,
+                Order::Descending => DESCENDING,
+            };
+            [(SORT, sortby.index.to_string()), (ORDER, order.to_string())]
+        });
+        let filters = self
+            .filters
+            .iter()
+            .map(|(key, value)| (format!("{FILTER}{key}"), value.clone()))
+            .collect::<Vec<_>>();
+
+        query::update(
+            search,
+            |key| matches!(key, SORT | ORDER) || key.starts_with(FILTER),
+            sort.chain(
+                filters
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value.clone())),
+            ),
+        )
+    }
+}
+
+/// The result of [`use_router_table_state`].
+#[derive(Clone, PartialEq)]
+pub struct UseRouterTableState<K>
+where
+    K: Clone + Eq + 'static,
+{
+    pub state: UseStateHandle<TableQueryState<K>>,
+    /// Sets the sort state, to be used as `onsort` of the [`TableHeader`](crate::prelude::TableHeader).
+    pub onsort: Callback<TableHeaderSortBy<K>>,
+}
+
+impl<K> UseRouterTableState<K>
+where
+    K: Clone + Eq + 'static,
+{
+    /// The current sort state.
+    pub fn sortby(&self) -> Option<TableHeaderSortBy<K>> {
+        self.state.sortby.clone()
+    }
+
+    /// The value of a filter.
+    pub fn filter(&self, key: &str) -> Option<&str> {
+        self.state.filters.get(key).map(String::as_str)
+    }
+
+    /// Set or, when [`None`], remove a filter.
+    pub fn set_filter(&self, key: impl Into<String>, value: Option<String>) {
+        let mut state = (*self.state).clone();
+        match value {
+            Some(value) => state.filters.insert(key.into(), value),
+            None => state.filters.remove(&key.into()),
+        };
+        self.state.set(state);
+    }
+
+    /// The context driving the columns of a table header.
+    pub fn context(&self) -> TableHeaderContext<K> {
+        TableHeaderContext {
+            sortby: self.sortby(),
+            onsort: self.onsort.clone(),
+        }
+    }
+}
+
+/// Create a hook for managing the sort and filter state of a table, stored in the query of the
+/// URL.
+///
+/// Pass the sort state and the callback to the [`TableHeader`](crate::prelude::TableHeader) to
+/// let it drive the columns. The format of the query is described at [`TableQueryState`].
+///
+/// Navigating the history (like going back) updates the state as well.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Copy, Clone, Eq, PartialEq, strum_macros::Display, strum_macros::EnumString)]
+/// #[strum(serialize_all = "lowercase")]
+/// enum Column { Name, Size };
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let table = use_router_table_state::<Column>();
+///   let onsort = Callback::noop();
+///
+///   let header = html_nested! {
+///     <TableHeader<Column> sortby={table.sortby()} onsort={table.onsort.clone()}>
+///       <TableColumn<Column> label="Name" index={Column::Name} onsort={onsort.clone()} />
+///       <TableColumn<Column> label="Size" index={Column::Size} {onsort} />
+///     </TableHeader<Column>>
+///   };
+///
+///   // ... sort and filter the entries, e.g. using `table.filter("name")`
+///   # let _ = header;
+///   html!()
+/// }
+/// ```
+#[hook]
+pub fn use_router_table_state<K>() -> UseRouterTableState<K>
+where
+    K: Clone + Eq + FromStr + Display + 'static,
+{
+    let state = use_state_eq(|| TableQueryState::<K>::from_query(&query::current()));
+
+    // update the query from the state
+    use_effect_with((*state).clone(), |state| {
+        let search = query::current();
+        if TableQueryState::<K>::from_query(&search) != *state {
+            query::replace(&state.to_query(&search));
+        }
+    });
+
+    // update the state from the query
+    let latest = use_latest(state.clone());
+    use_effect_with((), move |()| {
+        let listener = History::listener(move || {
+            latest
+                .current()
+                .set(TableQueryState::from_query(&query::current()));
+        });
+        move || drop(listener)
+    });
+
+    let onsort = use_callback(state.clone(), |sortby: TableHeaderSortBy<K>, state| {
+        state.set(TableQueryState {
+            sortby: Some(sortby),
+            filters: state.filters.clone(),
+        });
+    });
+
+    UseRouterTableState { state, onsort }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn state(
+        sortby: Option<TableHeaderSortBy<String>>,
+        filters: &[(&str, &str)],
+    ) -> TableQueryState<String> {
+        TableQueryState {
+            sortby,
+            filters: filters
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_from_query() {
+        assert_eq!(TableQueryState::from_query(""), state(None, &[]));
+        assert_eq!(
+            TableQueryState::from_query("?sort=name&order=desc&filter.state=a%20b&page=2"),
+            state(
+                Some(TableHeaderSortBy::descending("name".into())),
+                &[("state", "a b")]
+            )
+        );
+        assert_eq!(
+            TableQueryState::from_query("?order=foo&sort=size"),
+            state(Some(TableHeaderSortBy::ascending("size".into())), &[])
+        );
+        assert_eq!(
+            TableQueryState::<String>::from_query("?order=desc"),
+            state(None, &[])
+        );
+    }
+
+    #[test]
+    fn test_to_query() {
+        assert_eq!(state(None, &[]).to_query(""), "");
+        assert_eq!(
+            state(
+                Some(TableHeaderSortBy::ascending("name".into())),
+                &[("a", "1"), ("b c", "&")]
+            )
+            .to_query("?page=2&sort=size&order=desc&filter.x=y"),
+            "?page=2&sort=name&order=asc&filter.a=1&filter.b%20c=%26"
+        );
+
+        let expected = state(
+            Some(TableHeaderSortBy::descending("size".into())),
+            &[("state", "a b")],
+        );
+        assert_eq!(
+            TableQueryState::from_query(&expected.to_query("?foo")),
+            expected
+        );
+    }
+}
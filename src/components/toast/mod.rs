@@ -1,5 +1,5 @@
 //! Toast notifications
-use crate::prelude::{Action, Alert, AlertGroup, AlertType};
+use crate::prelude::{Action, Alert, AlertGroup, AlertType, Id, Notifier};
 use chrono::{DateTime, Utc};
 use core::cmp::Reverse;
 use gloo_timers::callback::Timeout;
@@ -7,6 +7,12 @@ use std::fmt::Display;
 use std::{collections::BinaryHeap, time::Duration};
 use yew::{prelude::*, virtual_dom::VChild};
 
+mod queue;
+
+pub use queue::ToastEntry;
+
+use queue::ToastQueue;
+
 /// Toasts are small alerts that get shown on the top right corner of the page.
 ///
 /// A toast can be triggered by every component. The toast fill get sent to an agent, the Toaster.
@@ -34,7 +40,7 @@ use yew::{prelude::*, virtual_dom::VChild};
 ///   let toaster = use_toaster().expect("Must be nested under a ToastViewer component");
 ///   html!{
 ///     <div>
-///       <button onclick={move |_| toaster.toast("Toast Title")}>
+///       <button onclick={move |_| { toaster.toast("Toast Title"); }}>
 ///         { "Click me" }  
 ///       </button>
 ///     </div>
@@ -51,6 +57,11 @@ pub struct Toast {
     pub timeout: Option<Duration>,
     pub body: Html,
     pub actions: Vec<Action>,
+    /// A key, identifying the toast.
+    ///
+    /// Showing a toast with the same key as an already visible toast replaces the existing one,
+    /// instead of showing both.
+    pub key: Option<String>,
 }
 
 impl From<&str> for Toast {
@@ -67,6 +78,7 @@ impl From<String> for Toast {
             body: Default::default(),
             r#type: Default::default(),
             actions: Vec::new(),
+            key: None,
         }
     }
 }
@@ -99,7 +111,9 @@ pub enum ToasterRequest {
 
 #[doc(hidden)]
 pub enum ToastAction {
-    ShowToast(Toast),
+    ShowToast(Id, Toast),
+    UpdateToast(Id, Toast),
+    CloseToast(Id),
 }
 
 /// An agent for displaying toasts.
@@ -109,40 +123,85 @@ pub struct Toaster {
 }
 
 impl Toaster {
-    /// Request a toast from the toast viewer.
-    pub fn toast(&self, toast: impl Into<Toast>) {
-        self.callback.emit(ToastAction::ShowToast(toast.into()))
+    /// Request a toast from the toast viewer, returning a handle to it.
+    ///
+    /// The handle can be used to update or close the toast later on. When it isn't needed, e.g.
+    /// in a callback, the result can be discarded using a statement: `{ toaster.toast(toast); }`.
+    pub fn toast(&self, toast: impl Into<Toast>) -> ToastHandle {
+        let id = Id::new();
+        self.callback.emit(ToastAction::ShowToast(id, toast.into()));
+        ToastHandle {
+            id,
+            callback: self.callback.clone(),
+        }
+    }
+}
+
+/// A handle to a toast, created by [`Toaster::toast`].
+///
+/// Once the toast was closed, either by the user, a timeout, or being replaced by another toast
+/// with the same key, all operations become a no-op.
+#[derive(Clone, PartialEq)]
+pub struct ToastHandle {
+    id: Id,
+    callback: Callback<ToastAction>,
+}
+
+impl ToastHandle {
+    /// Replace the content of the toast.
+    ///
+    /// This also restarts the timeout of the toast, using the timeout of the new content.
+    pub fn update(&self, toast: impl Into<Toast>) {
+        self.callback
+            .emit(ToastAction::UpdateToast(self.id, toast.into()));
+    }
+
+    /// Close the toast.
+    pub fn close(&self) {
+        self.callback.emit(ToastAction::CloseToast(self.id));
     }
 }
 
 #[derive(Clone, PartialEq, Properties)]
 pub struct Props {
     pub children: Html,
-}
 
-pub struct ToastEntry {
-    id: usize,
-    alert: VChild<Alert>,
-    timeout: Option<DateTime<Utc>>,
+    /// Also record toasts in the history of a wrapping
+    /// [`NotificationViewer`](crate::prelude::NotificationViewer).
+    #[prop_or_default]
+    pub record: bool,
+
+    /// The maximum number of toasts to show at the same time.
+    ///
+    /// Additional toasts will be queued, and an overflow message will be shown instead.
+    #[prop_or_default]
+    pub limit: Option<usize>,
+
+    /// Called when the user clicks on the overflow message.
+    #[prop_or_default]
+    pub onoverflow: Callback<()>,
 }
 
 /// A component to view toast alerts.
 ///
 /// Exactly one instance is required in your page in order to actually show the toasts. The instance
 /// must be on the body level of the HTML document.
+///
+/// Timeouts of the toasts are paused while the user hovers over them, and only start once the
+/// toast becomes visible.
 pub struct ToastViewer {
     context: Toaster,
-    alerts: Vec<ToastEntry>,
-    counter: usize,
+    queue: ToastQueue,
 
-    task: Option<Timeout>,
+    task: Option<(DateTime<Utc>, Timeout)>,
     timeouts: BinaryHeap<Reverse<DateTime<Utc>>>,
 }
 
 pub enum ToastViewerMsg {
     Perform(ToastAction),
     Cleanup,
-    Close(usize),
+    Close(Id),
+    Pause(bool),
 }
 
 impl Component for ToastViewer {
@@ -155,8 +214,7 @@ impl Component for ToastViewer {
         };
         Self {
             context,
-            alerts: Vec::new(),
-            counter: 0,
+            queue: ToastQueue::default(),
             task: None,
             timeouts: BinaryHeap::new(),
         }
@@ -166,18 +224,41 @@ impl Component for ToastViewer {
         match msg {
             ToastViewerMsg::Perform(action) => self.perform(ctx, action),
             ToastViewerMsg::Cleanup => self.cleanup(ctx),
-            ToastViewerMsg::Close(id) => self.remove_toast(id),
+            ToastViewerMsg::Close(id) => self.remove_toast(ctx, id),
+            ToastViewerMsg::Pause(paused) => self.pause(ctx, paused),
         }
     }
 
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        // the limit might have changed, making more toasts visible
+        self.start_timers(ctx);
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let context = self.context.clone();
 
+        let limit = ctx.props().limit.unwrap_or(usize::MAX);
+        let overflow_message = match self.queue.overflow(limit) {
+            0 => None,
+            1 => Some("View 1 more alert".to_string()),
+            n => Some(format!("View {n} more alerts")),
+        };
+
+        let onmouseenter = ctx.link().callback(|_| ToastViewerMsg::Pause(true));
+        let onmouseleave = ctx.link().callback(|_| ToastViewerMsg::Pause(false));
+
         html! {
             <ContextProvider<Toaster> {context}>
-                <AlertGroup toast=true>
-                    { for self.alerts.iter().map(|entry|entry.alert.clone()) }
-                </AlertGroup>
+                <div {onmouseenter} {onmouseleave}>
+                    <AlertGroup
+                        toast=true
+                        {overflow_message}
+                        onoverflow={ctx.props().onoverflow.clone()}
+                    >
+                        { for self.queue.visible(limit).map(|entry| self.render_entry(ctx, entry)) }
+                    </AlertGroup>
+                </div>
                 { ctx.props().children.clone() }
             </ContextProvider<Toaster>>
         }
@@ -189,101 +270,121 @@ impl ToastViewer {
         Utc::now()
     }
 
+    fn render_entry(&self, ctx: &Context<Self>, entry: &ToastEntry) -> VChild<Alert> {
+        let id = entry.id;
+        let toast = &entry.toast;
+
+        let onclose = match toast.timeout {
+            None => Some(ctx.link().callback(move |_| ToastViewerMsg::Close(id))),
+            Some(_) => None,
+        };
+
+        html_nested! {
+            <Alert
+                key={id.to_string()}
+                r#type={toast.r#type}
+                title={toast.title.clone()}
+                {onclose}
+                actions={toast.actions.clone()}
+            >
+                { toast.body.clone() }
+            </Alert>
+        }
+    }
+
     fn perform(&mut self, ctx: &Context<Self>, action: ToastAction) -> bool {
         match action {
-            ToastAction::ShowToast(toast) => self.add_toast(ctx, toast),
+            ToastAction::ShowToast(id, toast) => self.add_toast(ctx, id, toast),
+            ToastAction::UpdateToast(id, toast) => self.update_toast(ctx, id, toast),
+            ToastAction::CloseToast(id) => return self.remove_toast(ctx, id),
         }
         true
     }
 
-    fn add_toast(&mut self, ctx: &Context<Self>, toast: Toast) {
-        let now = Self::now();
-        let timeout = toast
-            .timeout
-            .and_then(|timeout| chrono::Duration::from_std(timeout).ok())
-            .map(|timeout| now + timeout);
-
-        let id = self.counter;
-        self.counter += 1;
+    fn add_toast(&mut self, ctx: &Context<Self>, id: Id, toast: Toast) {
+        if ctx.props().record {
+            if let Some((notifier, _)) = ctx.link().context::<Notifier>(Callback::noop()) {
+                notifier.notify(toast.clone());
+            }
+        }
 
-        let onclose = match toast.timeout {
-            None => Some(ctx.link().callback(move |_| ToastViewerMsg::Close(id))),
-            Some(_) => None,
-        };
+        self.queue.add(id, toast);
+        self.start_timers(ctx);
+    }
 
-        self.alerts.push(ToastEntry {
-            id,
-            alert: html_nested! {
-                <Alert r#type={toast.r#type} title={toast.title} onclose={onclose} actions={toast.actions}>
-                    { toast.body }
-                </Alert>
-            },
-            timeout,
-        });
+    fn update_toast(&mut self, ctx: &Context<Self>, id: Id, toast: Toast) {
+        self.queue.update(id, toast);
+        self.start_timers(ctx);
+    }
 
-        if let Some(timeout) = timeout {
-            self.schedule_cleanup(ctx, timeout);
+    /// Start the timers of all visible toasts which don't have a running timer yet.
+    fn start_timers(&mut self, ctx: &Context<Self>) {
+        let limit = ctx.props().limit.unwrap_or(usize::MAX);
+        for timeout in self.queue.start_timers(Self::now(), limit) {
+            log::debug!("Schedule cleanup: {:?}", timeout);
+            self.timeouts.push(Reverse(timeout));
         }
+
+        self.trigger_next_cleanup(ctx);
     }
 
-    fn schedule_cleanup(&mut self, ctx: &Context<Self>, timeout: DateTime<Utc>) {
-        log::debug!("Schedule cleanup: {:?}", timeout);
+    fn pause(&mut self, ctx: &Context<Self>, paused: bool) -> bool {
+        if !self.queue.pause(paused, Self::now()) {
+            return false;
+        }
 
-        self.timeouts.push(Reverse(timeout));
-        self.trigger_next_cleanup(ctx);
+        if paused {
+            self.task = None;
+            self.timeouts.clear();
+        } else {
+            self.start_timers(ctx);
+        }
+
+        false
     }
 
     fn trigger_next_cleanup(&mut self, ctx: &Context<Self>) {
-        if self.task.is_some() {
-            log::debug!("Already have a task");
-            return;
-        }
+        let timeout = match self.timeouts.peek() {
+            Some(next) => next.0,
+            None => return,
+        };
+        log::debug!("Next timeout: {:?}", timeout);
 
-        // We poll timeouts from the heap until we find one that is in the future, or we run
-        // out of candidates.
-        while let Some(next) = self.timeouts.pop() {
-            let timeout = next.0;
-            log::debug!("Next timeout: {:?}", timeout);
-            let duration = timeout - Self::now();
-            let duration = duration.to_std();
-            log::debug!("Duration: {:?}", duration);
-            if let Ok(duration) = duration {
-                let link = ctx.link().clone();
-                self.task = Some(Timeout::new(duration.as_millis() as u32, move || {
-                    link.send_message(ToastViewerMsg::Cleanup);
-                }));
-                log::debug!("Scheduled cleanup: {:?}", duration);
-                break;
+        if let Some((scheduled, _)) = &self.task {
+            if *scheduled <= timeout {
+                log::debug!("Already have a task");
+                return;
             }
+            // the new timeout is earlier, re-queue the current one
+            self.timeouts.push(Reverse(*scheduled));
         }
+
+        self.timeouts.pop();
+
+        // a timeout in the past gets cleaned up right away
+        let duration = (timeout - Self::now()).to_std().unwrap_or_default();
+        let link = ctx.link().clone();
+        self.task = Some((
+            timeout,
+            Timeout::new(duration.as_millis() as u32, move || {
+                link.send_message(ToastViewerMsg::Cleanup);
+            }),
+        ));
+        log::debug!("Scheduled cleanup: {:?}", duration);
     }
 
-    fn remove_toast(&mut self, id: usize) -> bool {
-        self.retain_alert(|entry| entry.id != id)
+    fn remove_toast(&mut self, ctx: &Context<Self>, id: Id) -> bool {
+        let result = self.queue.remove(id);
+        // another toast might have become visible
+        self.start_timers(ctx);
+        result
     }
 
     fn cleanup(&mut self, ctx: &Context<Self>) -> bool {
-        let now = Self::now();
-
         self.task = None;
-        self.trigger_next_cleanup(ctx);
-
-        self.retain_alert(|alert| {
-            if let Some(timeout) = alert.timeout {
-                timeout > now
-            } else {
-                true
-            }
-        })
-    }
-
-    fn retain_alert<F>(&mut self, f: F) -> bool
-    where
-        F: Fn(&ToastEntry) -> bool,
-    {
-        let before = self.alerts.len();
-        self.alerts.retain(f);
-        before != self.alerts.len()
+        let result = self.queue.expire(Self::now());
+        self.start_timers(ctx);
+        result
     }
 }
 
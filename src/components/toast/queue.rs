@@ -0,0 +1,235 @@
+//! The state of the toasts, independent of the component.
+
+use super::Toast;
+use crate::prelude::Id;
+use chrono::{DateTime, Duration, Utc};
+
+/// The timer state of a toast.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Timer {
+    /// The toast has no timeout.
+    None,
+    /// The timer is not running, either because the toast isn't visible yet, or because the
+    /// viewer is paused. Holds the remaining duration.
+    Pending(Duration),
+    /// The timer is running, and the toast will be removed at the provided time.
+    Running(DateTime<Utc>),
+}
+
+pub struct ToastEntry {
+    pub(super) id: Id,
+    pub(super) toast: Toast,
+    pub(super) timer: Timer,
+}
+
+impl ToastEntry {
+    fn new(id: Id, toast: Toast) -> Self {
+        let timer = toast
+            .timeout
+            .and_then(|timeout| Duration::from_std(timeout).ok())
+            .map(Timer::Pending)
+            .unwrap_or(Timer::None);
+
+        Self { id, toast, timer }
+    }
+}
+
+/// The toasts of a viewer, in the order of their appearance.
+///
+/// Only the first `limit` toasts are visible. The timers of toasts only run while they are
+/// visible, and the queue isn't paused.
+#[derive(Default)]
+pub(super) struct ToastQueue {
+    entries: Vec<ToastEntry>,
+    paused: bool,
+}
+
+impl ToastQueue {
+    /// Add a toast, replacing an existing toast with the same key.
+    pub fn add(&mut self, id: Id, toast: Toast) {
+        let existing = toast.key.as_ref().and_then(|key| {
+            self.entries
+                .iter_mut()
+                .find(|entry| entry.toast.key.as_ref() == Some(key))
+        });
+
+        match existing {
+            Some(entry) => *entry = ToastEntry::new(id, toast),
+            None => self.entries.push(ToastEntry::new(id, toast)),
+        }
+    }
+
+    /// Replace the content of a toast, resetting its timer.
+    pub fn update(&mut self, id: Id, toast: Toast) -> bool {
+        match self.entries.iter_mut().find(|entry| entry.id == id) {
+            Some(entry) => {
+                *entry = ToastEntry::new(id, toast);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove a toast, returning if it was present.
+    pub fn remove(&mut self, id: Id) -> bool {
+        self.retain(|entry| entry.id != id)
+    }
+
+    /// Remove all toasts which timed out, returning if any was removed.
+    pub fn expire(&mut self, now: DateTime<Utc>) -> bool {
+        self.retain(|entry| match entry.timer {
+            Timer::Running(timeout) => timeout > now,
+            Timer::None | Timer::Pending(_) => true,
+        })
+    }
+
+    /// Start the timers of all visible toasts which don't have a running timer yet.
+    ///
+    /// Returns the timeouts of the started timers.
+    pub fn start_timers(&mut self, now: DateTime<Utc>, limit: usize) -> Vec<DateTime<Utc>> {
+        if self.paused {
+            return vec![];
+        }
+
+        let mut timeouts = vec![];
+        for entry in self.entries.iter_mut().take(limit) {
+            if let Timer::Pending(remaining) = entry.timer {
+                let timeout = now + remaining;
+                entry.timer = Timer::Running(timeout);
+                timeouts.push(timeout);
+            }
+        }
+        timeouts
+    }
+
+    /// Pause or resume the timers, returning if the state changed.
+    ///
+    /// Pausing stops all timers, remembering their remaining time. After resuming, the timers
+    /// need to be started again.
+    pub fn pause(&mut self, paused: bool, now: DateTime<Utc>) -> bool {
+        if self.paused == paused {
+            return false;
+        }
+
+        self.paused = paused;
+        if paused {
+            for entry in &mut self.entries {
+                if let Timer::Running(timeout) = entry.timer {
+                    entry.timer = Timer::Pending(timeout - now);
+                }
+            }
+        }
+        true
+    }
+
+    /// The visible toasts.
+    pub fn visible(&self, limit: usize) -> impl Iterator<Item = &ToastEntry> {
+        self.entries.iter().take(limit)
+    }
+
+    /// The number of toasts hidden by the limit.
+    pub fn overflow(&self, limit: usize) -> usize {
+        self.entries.len().saturating_sub(limit)
+    }
+
+    fn retain(&mut self, f: impl Fn(&ToastEntry) -> bool) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(f);
+        before != self.entries.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn toast(title: &str, timeout: Option<u64>, key: Option<&str>) -> Toast {
+        Toast {
+            title: title.to_string(),
+            timeout: timeout.map(std::time::Duration::from_secs),
+            key: key.map(ToString::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn titles(queue: &ToastQueue, limit: usize) -> Vec<String> {
+        queue
+            .visible(limit)
+            .map(|entry| entry.toast.title.clone())
+            .collect()
+    }
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn test_limit() {
+        let mut queue = ToastQueue::default();
+        for title in ["a", "b", "c"] {
+            queue.add(Id::new(), toast(title, None, None));
+        }
+
+        assert_eq!(titles(&queue, 2), vec!["a", "b"]);
+        assert_eq!(queue.overflow(2), 1);
+        assert_eq!(queue.overflow(usize::MAX), 0);
+    }
+
+    #[test]
+    fn test_dedupe() {
+        let mut queue = ToastQueue::default();
+        let first = Id::new();
+        queue.add(first, toast("a", None, Some("key")));
+        queue.add(Id::new(), toast("b", None, None));
+        let second = Id::new();
+        queue.add(second, toast("c", None, Some("key")));
+
+        // replaced in place
+        assert_eq!(titles(&queue, usize::MAX), vec!["c", "b"]);
+        // the replaced toast is gone
+        assert!(!queue.remove(first));
+        assert!(queue.remove(second));
+        assert_eq!(titles(&queue, usize::MAX), vec!["b"]);
+    }
+
+    #[test]
+    fn test_timers() {
+        let mut queue = ToastQueue::default();
+        queue.add(Id::new(), toast("a", Some(5), None));
+        queue.add(Id::new(), toast("b", None, None));
+        queue.add(Id::new(), toast("c", Some(5), None));
+
+        // only visible toasts get a timer
+        assert_eq!(queue.start_timers(at(0), 2), vec![at(5)]);
+        // running timers don't get started again
+        assert!(queue.start_timers(at(1), 2).is_empty());
+
+        assert!(!queue.expire(at(4)));
+        assert!(queue.expire(at(5)));
+        assert_eq!(titles(&queue, 2), vec!["b", "c"]);
+
+        // the timer starts once visible
+        assert_eq!(queue.start_timers(at(6), 2), vec![at(11)]);
+    }
+
+    #[test]
+    fn test_pause() {
+        let mut queue = ToastQueue::default();
+        let id = Id::new();
+        queue.add(id, toast("a", Some(5), None));
+        assert_eq!(queue.start_timers(at(0), 1), vec![at(5)]);
+
+        assert!(queue.pause(true, at(3)));
+        assert!(!queue.pause(true, at(3)));
+        assert!(queue.start_timers(at(4), 1).is_empty());
+        assert!(!queue.expire(at(10)));
+
+        // resumes with the remaining time
+        assert!(queue.pause(false, at(10)));
+        assert_eq!(queue.start_timers(at(10), 1), vec![at(12)]);
+
+        // updating restarts the timeout
+        assert!(queue.update(id, toast("b", Some(5), None)));
+        assert_eq!(queue.start_timers(at(11), 1), vec![at(16)]);
+    }
+}
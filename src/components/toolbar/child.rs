@@ -11,6 +11,9 @@ pub enum ToolbarChild {
     Item(Rc<<ToolbarItem as BaseComponent>::Properties>),
     Divider(Rc<<ToolbarDivider as BaseComponent>::Properties>),
     Group(Rc<<ToolbarGroup as BaseComponent>::Properties>),
+    ToggleGroup(Rc<<ToolbarToggleGroup as BaseComponent>::Properties>),
+    /// A [`ToolbarFilter`], which is generic and can't be stored by its properties.
+    Filter(Html),
 }
 
 impl From<ToolbarItemProperties> for ToolbarChild {
@@ -25,6 +28,21 @@ impl From<ToolbarGroupProperties> for ToolbarChild {
     }
 }
 
+impl From<ToolbarToggleGroupProperties> for ToolbarChild {
+    fn from(props: ToolbarToggleGroupProperties) -> Self {
+        ToolbarChild::ToggleGroup(Rc::new(props))
+    }
+}
+
+impl<K> From<ToolbarFilterProperties<K>> for ToolbarChild
+where
+    K: Clone + PartialEq + 'static,
+{
+    fn from(props: ToolbarFilterProperties<K>) -> Self {
+        ToolbarChild::Filter(VComp::new::<ToolbarFilter<K>>(Rc::new(props), None).into())
+    }
+}
+
 impl From<()> for ToolbarChild {
     fn from(_: ()) -> Self {
         ToolbarChild::Divider(Rc::new(()))
@@ -53,7 +71,11 @@ impl From<ToolbarChildVariant> for Html {
         match value.props {
             ToolbarChild::Item(props) => VComp::new::<ToolbarItem>(props, None).into(),
             ToolbarChild::Group(props) => VComp::new::<ToolbarGroup>(props, None).into(),
+            ToolbarChild::ToggleGroup(props) => {
+                VComp::new::<ToolbarToggleGroup>(props, None).into()
+            }
             ToolbarChild::Divider(props) => VComp::new::<ToolbarDivider>(props, None).into(),
+            ToolbarChild::Filter(html) => html,
         }
     }
 }
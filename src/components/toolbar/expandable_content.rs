@@ -0,0 +1,33 @@
+use yew::prelude::*;
+
+/// Properties for [`ToolbarExpandableContent`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct ToolbarExpandableContentProperties {
+    #[prop_or_default]
+    pub expanded: bool,
+
+    #[prop_or_default]
+    pub r#ref: NodeRef,
+
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// The expandable content of a toolbar.
+///
+/// This is rendered by the [`super::ToolbarContent`], and receives the content of collapsed
+/// [`super::ToolbarToggleGroup`]s when expanded.
+#[function_component(ToolbarExpandableContent)]
+pub fn toolbar_expandable_content(props: &ToolbarExpandableContentProperties) -> Html {
+    let mut class = classes!("pf-v5-c-toolbar__expandable-content");
+
+    if props.expanded {
+        class.push(classes!("pf-m-expanded"));
+    }
+
+    html!(
+        <div {class} ref={props.r#ref.clone()}>
+            { props.children.clone() }
+        </div>
+    )
+}
@@ -0,0 +1,326 @@
+//! Filter toolbar
+mod model;
+
+pub use model::*;
+
+use super::{
+    use_toolbar_content, GroupVariant, ToolbarContext, ToolbarGroup, ToolbarItem, ToolbarItemType,
+    ToolbarToggleGroup,
+};
+use crate::prelude::{
+    use_breakpoint, Breakpoint, Button, ButtonVariant, Chip, ChipGroup, DatePicker, Dropdown, Icon,
+    MenuAction, MenuCheckbox, SimpleSelect, TextInputGroup, TextInputGroupMain,
+};
+use chrono::NaiveDate;
+use yew::prelude::*;
+
+/// Properties for [`ToolbarFilter`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct ToolbarFilterProperties<K>
+where
+    K: Clone + PartialEq + 'static,
+{
+    /// The filter attributes, and their current values.
+    pub filters: FilterSet<K>,
+
+    /// Called with the new state, when the user changes a filter.
+    #[prop_or_default]
+    pub onchange: Callback<FilterSet<K>>,
+
+    /// The breakpoint below which the filters get collapsed into a toggle.
+    #[prop_or(Breakpoint::XLarge)]
+    pub breakpoint: Breakpoint,
+
+    #[prop_or(AttrValue::Static("Clear all filters"))]
+    pub clear_all_label: AttrValue,
+
+    #[prop_or(AttrValue::Static("Show filters"))]
+    pub toggle_aria_label: AttrValue,
+}
+
+enum FilterMsg<K> {
+    AddText(K, String),
+    Select(K, String),
+    SetDate(K, Option<NaiveDate>),
+    Remove(K, String),
+    Clear(K),
+    ClearAll,
+}
+
+/// Toolbar filter component
+///
+/// Renders a [`FilterSet`]: an attribute selector, the input for the selected attribute, and
+/// the active filters as chip groups below the toolbar content, with an option to clear all
+/// filters.
+///
+/// Below the `breakpoint`, the filters are collapsed into a [`ToolbarToggleGroup`].
+///
+/// ## Properties
+///
+/// Defined by [`ToolbarFilterProperties`].
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Column { Name, Status }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let filters = use_state_eq(|| {
+///     FilterSet::new()
+///       .with(Column::Name, "Name", Filter::text())
+///       .with(Column::Status, "Status", Filter::checkbox_select(["Running", "Stopped"]))
+///   });
+///   let onchange = use_callback(filters.clone(), |new, filters| filters.set(new));
+///
+///   html!(
+///     <Toolbar>
+///       <ToolbarContent>
+///         <ToolbarFilter<Column> filters={(*filters).clone()} {onchange} />
+///       </ToolbarContent>
+///     </Toolbar>
+///   )
+/// }
+/// ```
+#[function_component(ToolbarFilter)]
+pub fn toolbar_filter<K>(props: &ToolbarFilterProperties<K>) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let toolbar = use_context::<ToolbarContext>();
+    let content = use_toolbar_content();
+
+    // the same rule as used by the toggle group
+    let breakpoint = use_breakpoint();
+    let collapsed = content.is_some() && *breakpoint < props.breakpoint;
+    let expanded = content.map(|c| c.expanded()).unwrap_or_default();
+
+    let onmsg = use_callback(
+        (props.filters.clone(), props.onchange.clone()),
+        |msg, (filters, onchange)| {
+            let mut filters = filters.clone();
+            match msg {
+                FilterMsg::AddText(key, value) => {
+                    if !filters.add_text(&key, value) {
+                        return;
+                    }
+                }
+                FilterMsg::Select(key, value) => filters.select(&key, value),
+                FilterMsg::SetDate(key, value) => filters.set_date(&key, value),
+                FilterMsg::Remove(key, value) => filters.remove(&key, &value),
+                FilterMsg::Clear(key) => filters.clear(&key),
+                FilterMsg::ClearAll => filters.clear_all(),
+            }
+            onchange.emit(filters);
+        },
+    );
+
+    let attributes = props.filters.attributes();
+    let current = use_state_eq(|| 0usize);
+    let index = (*current).min(attributes.len().saturating_sub(1));
+
+    let filter_group = match attributes.get(index) {
+        Some(attribute) => html!(
+            <ToolbarGroup variant={GroupVariant::Filter}>
+                if attributes.len() > 1 {
+                    <ToolbarItem>
+                        <Dropdown icon={Icon::Filter} text={attribute.label.clone()}>
+                            { for attributes.iter().enumerate().map(|(n, attribute)| {
+                                let current = current.clone();
+                                html_nested!(
+                                    <MenuAction
+                                        onclick={move |()| current.set(n)}
+                                        selected={n == index}
+                                    >
+                                        { &attribute.label }
+                                    </MenuAction>
+                                )
+                            }) }
+                        </Dropdown>
+                    </ToolbarItem>
+                }
+                <ToolbarItem>
+                    { filter_input(attribute, index, &onmsg) }
+                </ToolbarItem>
+            </ToolbarGroup>
+        ),
+        None => html!(),
+    };
+
+    let chip_groups = html!(
+        { for attributes.iter().filter(|attribute| attribute.filter.is_active()).map(|attribute| {
+            let key = attribute.key.clone();
+            html!(
+                <ToolbarItem r#type={ToolbarItemType::ChipGroup}>
+                    <ChipGroup
+                        label={attribute.label.clone()}
+                        onclose={onmsg.reform(move |()| FilterMsg::Clear(key.clone()))}
+                    >
+                        { for attribute.filter.chips().into_iter().map(|chip| {
+                            let key = attribute.key.clone();
+                            let value = chip.value;
+                            html_nested!(
+                                <Chip
+                                    text={chip.label}
+                                    onclose={onmsg.reform(move |()| FilterMsg::Remove(key.clone(), value.clone()))}
+                                />
+                            )
+                        }) }
+                    </ChipGroup>
+                </ToolbarItem>
+            )
+        }) }
+    );
+
+    let clear_all = html!(
+        <ToolbarItem>
+            <Button
+                variant={ButtonVariant::Link}
+                label={props.clear_all_label.to_string()}
+                onclick={onmsg.reform(|_| FilterMsg::ClearAll)}
+            />
+        </ToolbarItem>
+    );
+
+    let active = props.filters.is_active();
+    let expandable = collapsed && expanded;
+
+    // the chip groups below the toolbar content, or just a summary when collapsed
+    let chip_container = match toolbar.and_then(|t| t.chip_container) {
+        Some(host) if active && !expandable => create_portal(
+            html!(
+                <div class="pf-v5-c-toolbar__content pf-m-chip-container">
+                    <ToolbarGroup>
+                        if collapsed {
+                            <ToolbarItem>
+                                { format!("{} filters applied", props.filters.active_count()) }
+                            </ToolbarItem>
+                        } else {
+                            { chip_groups.clone() }
+                        }
+                    </ToolbarGroup>
+                    { clear_all.clone() }
+                </div>
+            ),
+            host,
+        ),
+        _ => html!(),
+    };
+
+    html!(
+        <>
+            <ToolbarToggleGroup
+                breakpoint={props.breakpoint}
+                aria_label={&props.toggle_aria_label}
+            >
+                { filter_group }
+                // when collapsed and expanded, the chip groups are shown along with the filters
+                if active && expandable {
+                    <div class="pf-v5-c-toolbar__group pf-m-chip-container">
+                        { chip_groups }
+                        { clear_all }
+                    </div>
+                }
+            </ToolbarToggleGroup>
+            { chip_container }
+        </>
+    )
+}
+
+/// Render the input of a filter attribute.
+fn filter_input<K>(
+    attribute: &FilterAttribute<K>,
+    index: usize,
+    onmsg: &Callback<FilterMsg<K>>,
+) -> Html
+where
+    K: Clone + PartialEq + 'static,
+{
+    let key = attribute.key.clone();
+    let placeholder = format!("Filter by {}", attribute.label.to_lowercase());
+
+    match &attribute.filter {
+        Filter::Text {
+            placeholder: text_placeholder,
+            ..
+        } => html!(
+            <TextFilterInput
+                key={index}
+                placeholder={text_placeholder.clone().unwrap_or(placeholder)}
+                onsubmit={onmsg.reform(move |value| FilterMsg::AddText(key.clone(), value))}
+            />
+        ),
+        Filter::Select { options, value } => html!(
+            <SimpleSelect<FilterOption>
+                key={index}
+                {placeholder}
+                entries={options.clone()}
+                selected={options.iter().find(|option| Some(&option.value) == value.as_ref()).cloned()}
+                onselect={onmsg.reform(move |option: FilterOption| FilterMsg::Select(key.clone(), option.value))}
+            />
+        ),
+        Filter::CheckboxSelect { options, values } => html!(
+            <Dropdown key={index} text={placeholder}>
+                { for options.iter().map(|option| {
+                    let key = key.clone();
+                    let value = option.value.clone();
+                    html_nested!(
+                        <MenuCheckbox
+                            checked={values.contains(&option.value)}
+                            onchange={onmsg.reform(move |_| FilterMsg::Select(key.clone(), value.clone()))}
+                        >
+                            { &option.label }
+                        </MenuCheckbox>
+                    )
+                }) }
+            </Dropdown>
+        ),
+        Filter::Date { value } => html!(
+            // re-create the picker when the value changes, as it doesn't follow the property
+            <DatePicker
+                key={format!("{index}-{}", value.map(|date| date.to_string()).unwrap_or_default())}
+                value={*value}
+                onchange={onmsg.reform(move |date| FilterMsg::SetDate(key.clone(), Some(date)))}
+            />
+        ),
+    }
+}
+
+#[derive(Clone, PartialEq, Properties)]
+struct TextFilterInputProperties {
+    placeholder: String,
+    onsubmit: Callback<String>,
+}
+
+#[function_component(TextFilterInput)]
+fn text_filter_input(props: &TextFilterInputProperties) -> Html {
+    let value = use_state_eq(String::new);
+    let onchange = use_callback(value.clone(), |new_value, value| value.set(new_value));
+
+    let onsubmit = use_callback(
+        (value.clone(), props.onsubmit.clone()),
+        |e: SubmitEvent, (value, onsubmit)| {
+            e.prevent_default();
+            onsubmit.emit((**value).clone());
+            value.set(String::new());
+        },
+    );
+
+    html!(
+        <form {onsubmit}>
+            <TextInputGroup>
+                <TextInputGroupMain
+                    icon={Icon::Search}
+                    value={(*value).clone()}
+                    placeholder={props.placeholder.clone()}
+                    aria_label={props.placeholder.clone()}
+                    {onchange}
+                />
+            </TextInputGroup>
+        </form>
+    )
+}
@@ -0,0 +1,425 @@
+use chrono::NaiveDate;
+use std::fmt::{Display, Formatter};
+
+/// An option of a select filter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FilterOption {
+    /// The value, as reported by [`FilterSet::values`].
+    pub value: String,
+    /// The label shown to the user.
+    pub label: String,
+}
+
+impl FilterOption {
+    pub fn new(value: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+        }
+    }
+}
+
+impl From<&str> for FilterOption {
+    fn from(value: &str) -> Self {
+        Self::new(value, value)
+    }
+}
+
+impl From<String> for FilterOption {
+    fn from(value: String) -> Self {
+        Self::new(value.clone(), value)
+    }
+}
+
+impl Display for FilterOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.label)
+    }
+}
+
+/// A single chip of an active filter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FilterChip {
+    pub value: String,
+    pub label: String,
+}
+
+/// The kind of input of a filter attribute, along with its current value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Filter {
+    /// A free text filter, each entered term becomes a chip.
+    Text {
+        placeholder: Option<String>,
+        values: Vec<String>,
+    },
+    /// Select a single option.
+    Select {
+        options: Vec<FilterOption>,
+        value: Option<String>,
+    },
+    /// Select any number of options.
+    CheckboxSelect {
+        options: Vec<FilterOption>,
+        values: Vec<String>,
+    },
+    /// Pick a date.
+    Date { value: Option<NaiveDate> },
+}
+
+impl Filter {
+    pub fn text() -> Self {
+        Self::Text {
+            placeholder: None,
+            values: vec![],
+        }
+    }
+
+    pub fn select<I, O>(options: I) -> Self
+    where
+        I: IntoIterator<Item = O>,
+        O: Into<FilterOption>,
+    {
+        Self::Select {
+            options: options.into_iter().map(Into::into).collect(),
+            value: None,
+        }
+    }
+
+    pub fn checkbox_select<I, O>(options: I) -> Self
+    where
+        I: IntoIterator<Item = O>,
+        O: Into<FilterOption>,
+    {
+        Self::CheckboxSelect {
+            options: options.into_iter().map(Into::into).collect(),
+            values: vec![],
+        }
+    }
+
+    pub fn date() -> Self {
+        Self::Date { value: None }
+    }
+
+    /// Check if the filter has a value.
+    pub fn is_active(&self) -> bool {
+        match self {
+            Self::Text { values, .. } | Self::CheckboxSelect { values, .. } => !values.is_empty(),
+            Self::Select { value, .. } => value.is_some(),
+            Self::Date { value } => value.is_some(),
+        }
+    }
+
+    /// The current values of the filter.
+    ///
+    /// For select filters, these are the values of the selected options. Dates are formatted as
+    /// `YYYY-MM-DD`.
+    pub fn values(&self) -> Vec<String> {
+        match self {
+            Self::Text { values, .. } | Self::CheckboxSelect { values, .. } => values.clone(),
+            Self::Select { value, .. } => value.iter().cloned().collect(),
+            Self::Date { value } => value.iter().map(|date| date.to_string()).collect(),
+        }
+    }
+
+    /// The chips representing the current values.
+    pub fn chips(&self) -> Vec<FilterChip> {
+        let options = match self {
+            Self::Select { options, .. } | Self::CheckboxSelect { options, .. } => {
+                options.as_slice()
+            }
+            _ => &[],
+        };
+
+        self.values()
+            .into_iter()
+            .map(|value| {
+                let label = options
+                    .iter()
+                    .find(|option| option.value == value)
+                    .map(|option| option.label.clone())
+                    .unwrap_or_else(|| value.clone());
+                FilterChip { value, label }
+            })
+            .collect()
+    }
+
+    /// Remove a single value.
+    pub fn remove(&mut self, value: &str) {
+        match self {
+            Self::Text { values, .. } | Self::CheckboxSelect { values, .. } => {
+                values.retain(|v| v != value)
+            }
+            Self::Select { value: current, .. } => {
+                if current.as_deref() == Some(value) {
+                    *current = None;
+                }
+            }
+            Self::Date { value: current } => {
+                if current.map(|date| date.to_string()).as_deref() == Some(value) {
+                    *current = None;
+                }
+            }
+        }
+    }
+
+    /// Remove all values.
+    pub fn clear(&mut self) {
+        match self {
+            Self::Text { values, .. } | Self::CheckboxSelect { values, .. } => values.clear(),
+            Self::Select { value, .. } => *value = None,
+            Self::Date { value } => *value = None,
+        }
+    }
+}
+
+/// An attribute which can be filtered by.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FilterAttribute<K> {
+    pub key: K,
+    pub label: String,
+    pub filter: Filter,
+}
+
+/// A set of filter attributes, and their current values.
+///
+/// Attributes are identified by a key of type `K`, typically an enum.
+///
+/// ## Example
+///
+/// ```
+/// use patternfly_yew::prelude::*;
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Column { Name, Status }
+///
+/// let mut filters = FilterSet::new()
+///     .with(Column::Name, "Name", Filter::text())
+///     .with(Column::Status, "Status", Filter::checkbox_select(["Running", "Stopped"]));
+///
+/// filters.add_text(&Column::Name, "foo");
+/// filters.select(&Column::Status, "Running");
+///
+/// assert_eq!(filters.values(&Column::Status), vec!["Running".to_string()]);
+/// assert_eq!(filters.active_count(), 2);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct FilterSet<K> {
+    attributes: Vec<FilterAttribute<K>>,
+}
+
+impl<K> Default for FilterSet<K> {
+    fn default() -> Self {
+        Self { attributes: vec![] }
+    }
+}
+
+impl<K> FilterSet<K>
+where
+    K: PartialEq,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an attribute.
+    pub fn with(mut self, key: K, label: impl Into<String>, filter: Filter) -> Self {
+        self.attributes.push(FilterAttribute {
+            key,
+            label: label.into(),
+            filter,
+        });
+        self
+    }
+
+    pub fn attributes(&self) -> &[FilterAttribute<K>] {
+        &self.attributes
+    }
+
+    pub fn get(&self, key: &K) -> Option<&Filter> {
+        self.attributes
+            .iter()
+            .find(|attribute| &attribute.key == key)
+            .map(|attribute| &attribute.filter)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut Filter> {
+        self.attributes
+            .iter_mut()
+            .find(|attribute| &attribute.key == key)
+            .map(|attribute| &mut attribute.filter)
+    }
+
+    /// The current values of an attribute, see [`Filter::values`].
+    pub fn values(&self, key: &K) -> Vec<String> {
+        self.get(key).map(Filter::values).unwrap_or_default()
+    }
+
+    /// The current value of a date attribute.
+    pub fn date(&self, key: &K) -> Option<NaiveDate> {
+        match self.get(key) {
+            Some(Filter::Date { value }) => *value,
+            _ => None,
+        }
+    }
+
+    /// Add a term to a text attribute.
+    ///
+    /// Empty and duplicate terms are ignored. Returns `true` if the term was added.
+    pub fn add_text(&mut self, key: &K, value: impl Into<String>) -> bool {
+        let value = value.into();
+        let value = value.trim();
+        match self.get_mut(key) {
+            Some(Filter::Text { values, .. })
+                if !value.is_empty() && !values.iter().any(|v| v == value) =>
+            {
+                values.push(value.to_string());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Select an option.
+    ///
+    /// For [`Filter::Select`], this replaces the current value. For [`Filter::CheckboxSelect`],
+    /// this toggles the option.
+    pub fn select(&mut self, key: &K, value: impl Into<String>) {
+        let value = value.into();
+        match self.get_mut(key) {
+            Some(Filter::Select { value: current, .. }) => *current = Some(value),
+            Some(Filter::CheckboxSelect { values, .. }) => {
+                if values.contains(&value) {
+                    values.retain(|v| v != &value);
+                } else {
+                    values.push(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Set the value of a date attribute.
+    pub fn set_date(&mut self, key: &K, date: Option<NaiveDate>) {
+        if let Some(Filter::Date { value }) = self.get_mut(key) {
+            *value = date;
+        }
+    }
+
+    /// Remove a single value from an attribute.
+    pub fn remove(&mut self, key: &K, value: &str) {
+        if let Some(filter) = self.get_mut(key) {
+            filter.remove(value);
+        }
+    }
+
+    /// Remove all values from an attribute.
+    pub fn clear(&mut self, key: &K) {
+        if let Some(filter) = self.get_mut(key) {
+            filter.clear();
+        }
+    }
+
+    /// Remove all values from all attributes.
+    pub fn clear_all(&mut self) {
+        for attribute in &mut self.attributes {
+            attribute.filter.clear();
+        }
+    }
+
+    /// Check if any attribute has a value.
+    pub fn is_active(&self) -> bool {
+        self.attributes
+            .iter()
+            .any(|attribute| attribute.filter.is_active())
+    }
+
+    /// The number of active values, across all attributes.
+    pub fn active_count(&self) -> usize {
+        self.attributes
+            .iter()
+            .map(|attribute| attribute.filter.values().len())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Key {
+        Name,
+        Status,
+        Kind,
+        Created,
+    }
+
+    fn filters() -> FilterSet<Key> {
+        FilterSet::new()
+            .with(Key::Name, "Name", Filter::text())
+            .with(
+                Key::Status,
+                "Status",
+                Filter::checkbox_select([
+                    FilterOption::new("running", "Running"),
+                    FilterOption::new("stopped", "Stopped"),
+                ]),
+            )
+            .with(Key::Kind, "Kind", Filter::select(["a", "b"]))
+            .with(Key::Created, "Created", Filter::date())
+    }
+
+    #[test]
+    fn test_text() {
+        let mut filters = filters();
+        assert!(filters.add_text(&Key::Name, "foo"));
+        assert!(!filters.add_text(&Key::Name, " foo "));
+        assert!(!filters.add_text(&Key::Name, ""));
+        assert!(!filters.add_text(&Key::Status, "foo"));
+        assert_eq!(filters.values(&Key::Name), vec!["foo".to_string()]);
+    }
+
+    #[test]
+    fn test_select() {
+        let mut filters = filters();
+
+        filters.select(&Key::Kind, "a");
+        filters.select(&Key::Kind, "b");
+        assert_eq!(filters.values(&Key::Kind), vec!["b".to_string()]);
+
+        filters.select(&Key::Status, "running");
+        filters.select(&Key::Status, "stopped");
+        filters.select(&Key::Status, "running");
+        assert_eq!(filters.values(&Key::Status), vec!["stopped".to_string()]);
+        assert_eq!(
+            filters.get(&Key::Status).unwrap().chips(),
+            vec![FilterChip {
+                value: "stopped".into(),
+                label: "Stopped".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_remove_and_clear() {
+        let mut filters = filters();
+        let date = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+
+        filters.add_text(&Key::Name, "foo");
+        filters.add_text(&Key::Name, "bar");
+        filters.set_date(&Key::Created, Some(date));
+        filters.select(&Key::Kind, "a");
+        assert_eq!(filters.active_count(), 4);
+
+        filters.remove(&Key::Name, "foo");
+        filters.remove(&Key::Created, "2023-01-02");
+        assert_eq!(filters.values(&Key::Name), vec!["bar".to_string()]);
+        assert_eq!(filters.date(&Key::Created), None);
+
+        filters.clear(&Key::Kind);
+        assert_eq!(filters.active_count(), 1);
+
+        filters.clear_all();
+        assert!(!filters.is_active());
+    }
+}
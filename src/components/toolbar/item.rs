@@ -2,8 +2,9 @@ use crate::prelude::{AsClasses, ExtendClasses, ToolbarElementModifier, WithBreak
 use yew::prelude::*;
 
 /// Properties for [`ToolbarItem`]
-#[derive(Clone, Eq, PartialEq, Debug)]
+#[derive(Clone, Default, Eq, PartialEq, Debug)]
 pub enum ToolbarItemType {
+    #[default]
     None,
     BulkSelect,
     ChipGroup,
@@ -12,12 +13,6 @@ pub enum ToolbarItemType {
     SearchFilter,
 }
 
-impl Default for ToolbarItemType {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl AsClasses for ToolbarItemType {
     fn extend_classes(&self, classes: &mut Classes) {
         match self {
@@ -1,17 +1,24 @@
 //! Toolbar
 mod child;
 mod divider;
+mod expandable_content;
+mod filter;
 mod group;
 mod item;
+mod toggle_group;
 
 pub use child::*;
 pub use divider::*;
+pub use expandable_content::*;
+pub use filter::*;
 pub use group::*;
 pub use item::*;
+pub use toggle_group::*;
 
 use crate::ouia;
 use crate::prelude::{AsClasses, ExtendClasses, WithBreakpoints};
 use crate::utils::{Ouia, OuiaComponentType, OuiaSafe};
+use web_sys::Element;
 use yew::{html::ChildrenRenderer, prelude::*};
 
 const OUIA: Ouia = ouia!("Toolbar");
@@ -113,19 +120,78 @@ pub fn toolbar(props: &ToolbarProperties) -> Html {
         class.push("pf-m-full-height")
     }
 
+    // the host for the chip groups of filters, rendered below the content
+    let chip_container_ref = use_node_ref();
+    let context = ToolbarContext {
+        chip_container: use_element(chip_container_ref.clone()),
+    };
+
     html! (
-        <div
-            id={&props.id}
-            {class}
-            data-ouia-component-id={(*ouia_id).clone()}
-            data-ouia-component-type={props.ouia_type}
-            data-ouia-safe={props.ouia_safe}
-        >
-            { for props.children.iter() }
-        </div>
+        <ContextProvider<ToolbarContext> {context}>
+            <div
+                id={&props.id}
+                {class}
+                data-ouia-component-id={(*ouia_id).clone()}
+                data-ouia-component-type={props.ouia_type}
+                data-ouia-safe={props.ouia_safe}
+            >
+                { for props.children.iter() }
+                <div ref={chip_container_ref} style="display: contents;" />
+            </div>
+        </ContextProvider<ToolbarContext>>
     )
 }
 
+/// Context provided by [`Toolbar`] to its children.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ToolbarContext {
+    /// The element to render chip groups into, once mounted.
+    pub chip_container: Option<Element>,
+}
+
+/// The expansion state of a [`ToolbarContent`], shared by all its [`ToolbarToggleGroup`]s.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToolbarContentContext {
+    /// The element to render expandable content into, once mounted.
+    pub(crate) expandable: Option<Element>,
+    expanded: bool,
+    onexpand: Callback<bool>,
+}
+
+impl ToolbarContentContext {
+    /// Check if the expandable content is expanded.
+    pub fn expanded(&self) -> bool {
+        self.expanded
+    }
+
+    pub fn set_expanded(&self, expanded: bool) {
+        self.onexpand.emit(expanded);
+    }
+
+    pub fn toggle(&self) {
+        self.onexpand.emit(!self.expanded);
+    }
+}
+
+/// Access the expansion state of the wrapping [`ToolbarContent`].
+#[hook]
+pub fn use_toolbar_content() -> Option<ToolbarContentContext> {
+    use_context()
+}
+
+/// Get the element of a [`NodeRef`], once it got mounted.
+#[hook]
+fn use_element(node_ref: NodeRef) -> Option<Element> {
+    let element = use_state_eq(|| None);
+    {
+        let element = element.clone();
+        use_effect_with(node_ref, move |node_ref| {
+            element.set(node_ref.cast::<Element>());
+        });
+    }
+    (*element).clone()
+}
+
 /// Properties for [`Toolbar`]
 #[derive(Clone, PartialEq, Properties)]
 pub struct ToolbarContentProperties {
@@ -136,13 +202,37 @@ pub struct ToolbarContentProperties {
     pub id: AttrValue,
 }
 
+/// A row of content in a [`Toolbar`]
+///
+/// ## Properties
+///
+/// Defined by [`ToolbarContentProperties`].
+///
+/// ## Contexts
+///
+/// Provides the following contexts to its children:
+///
+/// * [`ToolbarContentContext`]
 #[function_component(ToolbarContent)]
 pub fn toolbar_content(props: &ToolbarContentProperties) -> Html {
+    let expanded = use_state_eq(|| false);
+    let onexpand = use_callback(expanded.clone(), |value, expanded| expanded.set(value));
+
+    let expandable_ref = use_node_ref();
+    let context = ToolbarContentContext {
+        expandable: use_element(expandable_ref.clone()),
+        expanded: *expanded,
+        onexpand,
+    };
+
     html! (
-        <div class="pf-v5-c-toolbar__content" id={&props.id}>
-            <div class="pf-v5-c-toolbar__content-section">
-                { for props.children.iter() }
+        <ContextProvider<ToolbarContentContext> {context}>
+            <div class="pf-v5-c-toolbar__content" id={&props.id}>
+                <div class="pf-v5-c-toolbar__content-section">
+                    { for props.children.iter() }
+                </div>
+                <ToolbarExpandableContent r#ref={expandable_ref} expanded={*expanded} />
             </div>
-        </div>
+        </ContextProvider<ToolbarContentContext>>
     )
 }
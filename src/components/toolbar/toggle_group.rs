@@ -0,0 +1,117 @@
+use super::use_toolbar_content;
+use crate::prelude::{use_breakpoint, Breakpoint, Button, ButtonVariant, Icon};
+use yew::prelude::*;
+
+/// Properties for [`ToolbarToggleGroup`]
+#[derive(Clone, PartialEq, Properties)]
+pub struct ToolbarToggleGroupProperties {
+    /// The breakpoint below which the content gets collapsed into the toggle.
+    pub breakpoint: Breakpoint,
+
+    #[prop_or_default]
+    pub children: Html,
+
+    /// The icon of the toggle button.
+    #[prop_or(Icon::Filter)]
+    pub icon: Icon,
+
+    #[prop_or(AttrValue::Static("Show filters"))]
+    pub aria_label: AttrValue,
+
+    /// Additional classes
+    #[prop_or_default]
+    pub class: Classes,
+}
+
+/// A toolbar group, which collapses into a toggle button below a breakpoint.
+///
+/// When collapsed, toggling the button shows the content in the
+/// [`super::ToolbarExpandableContent`] of the wrapping [`super::ToolbarContent`]. All toggle
+/// groups of the same toolbar content share the expansion state. Outside a toolbar content, the
+/// group never collapses.
+///
+/// ## Properties
+///
+/// Defined by [`ToolbarToggleGroupProperties`].
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   html!(
+///     <Toolbar>
+///       <ToolbarContent>
+///         <ToolbarToggleGroup breakpoint={Breakpoint::Large}>
+///           <ToolbarItem r#type={ToolbarItemType::SearchFilter}>
+///             <SearchInput />
+///           </ToolbarItem>
+///         </ToolbarToggleGroup>
+///       </ToolbarContent>
+///     </Toolbar>
+///   )
+/// }
+/// ```
+#[function_component(ToolbarToggleGroup)]
+pub fn toolbar_toggle_group(props: &ToolbarToggleGroupProperties) -> Html {
+    let content = use_toolbar_content();
+
+    let breakpoint = use_breakpoint();
+    let collapsed = content.is_some() && *breakpoint < props.breakpoint;
+    let expanded = content.as_ref().map(|c| c.expanded()).unwrap_or_default();
+
+    // reset the expanded state, once there is enough room again. Only on the transition of this
+    // group, as other groups might still be collapsed.
+    let was_collapsed = use_mut_ref(|| collapsed);
+    use_effect_with(collapsed, {
+        let content = content.clone();
+        move |collapsed| {
+            let uncollapsed = was_collapsed.replace(*collapsed) && !*collapsed;
+            if let Some(content) = content.filter(|content| uncollapsed && content.expanded()) {
+                content.set_expanded(false);
+            }
+        }
+    });
+
+    let ontoggle = use_callback(content.clone(), |_, content| {
+        if let Some(content) = content {
+            content.toggle();
+        }
+    });
+
+    let mut class = classes!("pf-v5-c-toolbar__group", "pf-m-toggle-group");
+    class.push(format!("pf-m-show{}", props.breakpoint));
+    class.extend(props.class.clone());
+
+    if expanded {
+        class.push(classes!("pf-m-expanded"));
+    }
+
+    let expandable_content = match content.and_then(|c| c.expandable) {
+        Some(host) if collapsed && expanded => create_portal(props.children.clone(), host),
+        _ => html!(),
+    };
+
+    html!(
+        <>
+            <div {class}>
+                <div class="pf-v5-c-toolbar__toggle">
+                    <Button
+                        variant={ButtonVariant::Plain}
+                        icon={props.icon}
+                        aria_label={&props.aria_label}
+                        aria_expanded={expanded.to_string()}
+                        onclick={ontoggle}
+                    />
+                </div>
+                if !collapsed {
+                    { props.children.clone() }
+                }
+            </div>
+            { expandable_content }
+        </>
+    )
+}
@@ -0,0 +1,234 @@
+//! Copying to the clipboard
+
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::prelude::*;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// Content to copy to the clipboard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClipboardContent {
+    Text(String),
+    /// Rich content, with a plain text alternative for targets not supporting HTML.
+    Html {
+        html: String,
+        text: String,
+    },
+}
+
+impl From<String> for ClipboardContent {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for ClipboardContent {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<AttrValue> for ClipboardContent {
+    fn from(text: AttrValue) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+/// The status of the last copy operation.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ClipboardStatus {
+    #[default]
+    Idle,
+    Copied,
+    Failed,
+}
+
+/// Options for [`use_clipboard`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClipboardOptions {
+    /// The message while idle.
+    pub text: AttrValue,
+    /// The message after copying succeeded.
+    pub copied_text: AttrValue,
+    /// The message after copying failed.
+    pub failed_text: AttrValue,
+    /// Milliseconds before the status is reset to [`ClipboardStatus::Idle`].
+    pub reset: u32,
+    /// Called after copying, with the error message when copying failed.
+    pub oncopy: Callback<Result<(), String>>,
+}
+
+impl Default for ClipboardOptions {
+    fn default() -> Self {
+        Self {
+            text: AttrValue::Static("Copy to clipboard"),
+            copied_text: AttrValue::Static("Copied!"),
+            failed_text: AttrValue::Static("Failed to copy"),
+            reset: 2_000,
+            oncopy: Default::default(),
+        }
+    }
+}
+
+/// The result of [`use_clipboard`].
+#[derive(Clone)]
+pub struct UseClipboard {
+    pub status: ClipboardStatus,
+    /// The message for the current status, e.g. for a tooltip.
+    pub message: AttrValue,
+    copy: Callback<ClipboardContent>,
+}
+
+impl UseClipboard {
+    /// Copy content to the clipboard.
+    pub fn copy(&self, content: impl Into<ClipboardContent>) {
+        self.copy.emit(content.into());
+    }
+
+    /// Copy the text of an element to the clipboard.
+    pub fn copy_node(&self, node: &NodeRef) {
+        if let Some(element) = node.cast::<Element>() {
+            self.copy(element.text_content().unwrap_or_default());
+        }
+    }
+
+    /// A callback, copying the content it is emitted with.
+    pub fn callback(&self) -> Callback<ClipboardContent> {
+        self.copy.clone()
+    }
+}
+
+/// Copy content to the clipboard, tracking the status of the last operation.
+///
+/// Uses the clipboard API where available, falling back to `document.execCommand` e.g. for
+/// insecure contexts.
+///
+/// ## Example
+///
+/// ```
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let clipboard = use_clipboard(ClipboardOptions::default());
+///   let onclick = {
+///     let clipboard = clipboard.clone();
+///     Callback::from(move |_| clipboard.copy("Hello World"))
+///   };
+///
+///   html!(
+///     <Tooltip text={clipboard.message.to_string()}>
+///       <Button icon={Icon::Copy} {onclick} />
+///     </Tooltip>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_clipboard(options: ClipboardOptions) -> UseClipboard {
+    let status = use_state_eq(ClipboardStatus::default);
+    let reset = use_mut_ref(|| None::<Timeout>);
+
+    let copy = {
+        let status = status.clone();
+        let options = options.clone();
+        Callback::from(move |content: ClipboardContent| {
+            let status = status.clone();
+            let options = options.clone();
+            let reset = reset.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let (text, html) = match content {
+                    ClipboardContent::Text(text) => (text, None),
+                    ClipboardContent::Html { html, text } => (text, Some(html)),
+                };
+                let result = copy_to_clipboard(text, html).await.map_err(|err| {
+                    err.as_string()
+                        .or_else(|| {
+                            err.dyn_ref::<js_sys::Error>()
+                                .map(|err| err.to_string().into())
+                        })
+                        .unwrap_or_else(|| format!("{err:?}"))
+                });
+
+                status.set(match result {
+                    Ok(()) => ClipboardStatus::Copied,
+                    Err(_) => ClipboardStatus::Failed,
+                });
+                options.oncopy.emit(result);
+
+                let status = status.clone();
+                *reset.borrow_mut() = Some(Timeout::new(options.reset, move || {
+                    status.set(ClipboardStatus::Idle)
+                }));
+            });
+        })
+    };
+
+    let message = match *status {
+        ClipboardStatus::Idle => options.text,
+        ClipboardStatus::Copied => options.copied_text,
+        ClipboardStatus::Failed => options.failed_text,
+    };
+
+    UseClipboard {
+        status: *status,
+        message,
+        copy,
+    }
+}
+
+#[wasm_bindgen(inline_js=r#"
+function fallback(text, html) {
+    const rich = html !== undefined;
+    const element = document.createElement(rich ? "div" : "textarea");
+    element.style.position = "fixed";
+    element.style.opacity = "0";
+    if (rich) {
+        element.innerHTML = html;
+    } else {
+        element.value = text;
+    }
+    document.body.appendChild(element);
+    try {
+        if (rich) {
+            const range = document.createRange();
+            range.selectNodeContents(element);
+            const selection = window.getSelection();
+            selection.removeAllRanges();
+            selection.addRange(range);
+        } else {
+            element.select();
+        }
+        if (!document.execCommand("copy")) {
+            throw new Error("Copying is not supported");
+        }
+    } finally {
+        document.body.removeChild(element);
+    }
+}
+
+export async function copy_to_clipboard(text, html) {
+    const clipboard = window.navigator.clipboard;
+    if (window.isSecureContext && clipboard) {
+        try {
+            if (html === undefined) {
+                await clipboard.writeText(text);
+            } else {
+                await clipboard.write([new ClipboardItem({
+                    "text/plain": new Blob([text], { type: "text/plain" }),
+                    "text/html": new Blob([html], { type: "text/html" }),
+                })]);
+            }
+            return;
+        } catch (e) {
+            console.debug("Failed to use the clipboard API, falling back", e);
+        }
+    }
+    fallback(text, html);
+}
+"#)]
+#[rustfmt::skip] // required to keep the "async" keyword
+extern "C" {
+    #[wasm_bindgen(catch)]
+    async fn copy_to_clipboard(text: String, html: Option<String>) -> Result<(), JsValue>;
+}
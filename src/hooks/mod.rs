@@ -1,5 +1,6 @@
 //! Hooks
 
+pub mod clipboard;
 pub mod enter;
 pub mod id;
 pub mod pagination;
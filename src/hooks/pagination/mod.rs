@@ -1,5 +1,11 @@
 //! Hooks for implementing pagination
 
+#[cfg(feature = "yew-nested-router")]
+mod router;
+
+#[cfg(feature = "yew-nested-router")]
+pub use router::*;
+
 use crate::prelude::Navigation;
 use std::ops::{Deref, DerefMut, Range};
 use std::rc::Rc;
@@ -103,7 +109,7 @@ impl PaginationState {
 
     pub fn total_pages(&self) -> Option<usize> {
         self.total
-            .map(|total| (total + self.control.per_page - 1) / self.control.per_page)
+            .map(|total| total.div_ceil(self.control.per_page))
     }
 }
 
@@ -153,6 +159,9 @@ impl DerefMut for PaginationState {
 /// The hook returns a struct to manage and track pagination state. It is intended to be used
 /// in combination with the [`crate::components::pagination::SimplePagination`] component.
 ///
+/// The state is lost when reloading the page. To keep it in the URL, use `use_router_pagination`
+/// instead.
+///
 /// ## Example
 ///
 /// Also see the quickstart project for a full example.
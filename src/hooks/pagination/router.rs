@@ -0,0 +1,166 @@
+use super::{use_pagination, PaginationControl, PaginationState, UsePagination};
+use crate::prelude::Navigation;
+use crate::utils::query;
+use yew::prelude::*;
+use yew_hooks::use_latest;
+use yew_nested_router::History;
+
+const PAGE: &str = "page";
+const PER_PAGE: &str = "per_page";
+
+/// Create a hook for managing pagination state, stored in the query of the URL.
+///
+/// This works like [`use_pagination`], but reads the page and the number of items per page from
+/// the `page` and `per_page` query parameters, and updates them when navigating. The page in the
+/// query is one-based. Values missing from the query are taken from the initializer function,
+/// pages out of range are ignored.
+///
+/// Navigating the history (like going back) updates the state as well.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let total = use_state_eq(||Some(123));
+///   let pagination = use_router_pagination(*total, Default::default);
+///
+///   html!(
+///     <>
+///       <SimplePagination
+///         pagination={pagination.clone()}
+///         total={*total}
+///       />
+///       // ... render content
+///       { format!("Showing items: {:?}", pagination.state.range()) }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_router_pagination<T>(total: Option<usize>, init: T) -> UsePagination
+where
+    T: FnOnce() -> PaginationControl,
+{
+    let initial = *use_memo((), |()| init());
+    let pagination = use_pagination(total, || from_query(&query::current(), initial, total));
+
+    // update the query from the state
+    use_effect_with(pagination.state.control, move |control| {
+        let search = query::current();
+        if parse_query(&search, initial) != *control {
+            query::replace(&update_query(&search, *control));
+        }
+    });
+
+    // update the state from the query
+    let latest = use_latest(pagination.state.clone());
+    use_effect_with((), move |()| {
+        let listener = History::listener(move || {
+            let state = latest.current();
+            state.set(PaginationState {
+                control: from_query(&query::current(), initial, state.total),
+                total: state.total,
+            });
+        });
+        move || drop(listener)
+    });
+
+    pagination
+}
+
+/// The pagination control from the query, clamped to the total number of items.
+fn from_query(search: &str, initial: PaginationControl, total: Option<usize>) -> PaginationControl {
+    let control = parse_query(search, initial);
+    PaginationState {
+        control: PaginationControl {
+            page: 0,
+            per_page: control.per_page,
+        },
+        total,
+    }
+    .navigate(Navigation::Page(control.page))
+    .control
+}
+
+/// The pagination control from the query, falling back to the initial values.
+fn parse_query(search: &str, initial: PaginationControl) -> PaginationControl {
+    let mut control = initial;
+    for (key, value) in query::params(search) {
+        match key.as_str() {
+            PAGE => {
+                if let Some(page) = value.parse::<usize>().ok().and_then(|p| p.checked_sub(1)) {
+                    control.page = page;
+                }
+            }
+            PER_PAGE => {
+                if let Some(per_page) = value.parse::<usize>().ok().filter(|p| *p > 0) {
+                    control.per_page = per_page;
+                }
+            }
+            _ => {}
+        }
+    }
+    control
+}
+
+/// Replace the pagination parameters of the query, keeping all others.
+fn update_query(search: &str, control: PaginationControl) -> String {
+    query::update(
+        search,
+        |key| matches!(key, PAGE | PER_PAGE),
+        [
+            (PAGE, (control.page + 1).to_string()),
+            (PER_PAGE, control.per_page.to_string()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn control(page: usize, per_page: usize) -> PaginationControl {
+        PaginationControl { page, per_page }
+    }
+
+    #[test]
+    fn test_parse_query() {
+        assert_eq!(parse_query("", control(0, 10)), control(0, 10));
+        assert_eq!(
+            parse_query("?page=3&per_page=20", control(0, 10)),
+            control(2, 20)
+        );
+        assert_eq!(
+            parse_query("?page=0&per_page=0&foo", control(1, 10)),
+            control(1, 10)
+        );
+        assert_eq!(parse_query("?page=x", control(1, 10)), control(1, 10));
+    }
+
+    #[test]
+    fn test_from_query() {
+        assert_eq!(
+            from_query("?page=3&per_page=20", control(0, 10), Some(100)),
+            control(2, 20)
+        );
+        // out of range
+        assert_eq!(
+            from_query("?page=6&per_page=20", control(0, 10), Some(100)),
+            control(0, 20)
+        );
+        assert_eq!(from_query("?page=6", control(0, 10), None), control(5, 10));
+    }
+
+    #[test]
+    fn test_update_query() {
+        assert_eq!(update_query("", control(0, 10)), "?page=1&per_page=10");
+        assert_eq!(
+            update_query("?foo=bar&page=3&baz&per_page=5", control(1, 20)),
+            "?foo=bar&baz&page=2&per_page=20"
+        );
+    }
+}
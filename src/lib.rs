@@ -21,6 +21,9 @@
 //! [PatternFly Yew Quickstart](https://github.com/ctron/patternfly-yew-quickstart) project.
 
 #![recursion_limit = "1024"]
+// the table model test implements a foreign trait for a foreign type inside its test function
+#![cfg_attr(test, allow(non_local_definitions))]
+
 mod icon;
 
 pub mod components;
@@ -7,6 +7,7 @@ pub use crate::validation::*;
 pub use crate::components::about::*;
 pub use crate::components::accordion::*;
 pub use crate::components::alert::*;
+pub use crate::components::app_launcher::*;
 pub use crate::components::avatar::*;
 pub use crate::components::backdrop::*;
 pub use crate::components::background::*;
@@ -22,7 +23,9 @@ pub use crate::components::clipboard::*;
 pub use crate::components::code_block::*;
 pub use crate::components::content::*;
 pub use crate::components::context_selector::*;
+pub use crate::components::data_list::*;
 pub use crate::components::date::*;
+pub use crate::components::dialog::*;
 pub use crate::components::divider::*;
 pub use crate::components::dl::*;
 pub use crate::components::drawer::*;
@@ -35,13 +38,17 @@ pub use crate::components::form::*;
 pub use crate::components::helper_text::*;
 pub use crate::components::hint::*;
 pub use crate::components::input_group::*;
+pub use crate::components::jump_links::*;
 pub use crate::components::label::*;
 pub use crate::components::list::*;
+pub use crate::components::log_viewer::*;
 pub use crate::components::login_page::*;
 pub use crate::components::menu::*;
 pub use crate::components::modal::*;
 pub use crate::components::nav::*;
+pub use crate::components::notification_drawer::*;
 pub use crate::components::number_input::*;
+pub use crate::components::overflow_menu::*;
 pub use crate::components::page::*;
 pub use crate::components::pagination::*;
 pub use crate::components::panel::*;
@@ -74,6 +81,7 @@ pub use crate::layouts::grid::*;
 pub use crate::layouts::split::*;
 pub use crate::layouts::stack::*;
 
+pub use crate::hooks::clipboard::*;
 pub use crate::hooks::enter::*;
 pub use crate::hooks::id::*;
 pub use crate::hooks::pagination::*;
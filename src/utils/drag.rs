@@ -0,0 +1,436 @@
+use crate::prelude::Icon;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::prelude::*;
+
+/// The attribute marking the element of a reorderable item, holding its position.
+const POSITION_ATTR: &str = "data-reorder-position";
+
+/// Move an item from one position to another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Reorder {
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Reorder {
+    /// Apply the reorder to a list of items.
+    ///
+    /// Positions out of range are ignored.
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        if self.from < items.len() && self.to < items.len() {
+            let item = items.remove(self.from);
+            items.insert(self.to, item);
+        }
+    }
+
+    /// The new position of an item, after applying the reorder.
+    pub fn position(&self, index: usize) -> usize {
+        if index == self.from {
+            self.to
+        } else if self.from < index && index <= self.to {
+            index - 1
+        } else if self.to <= index && index < self.from {
+            index + 1
+        } else {
+            index
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct DragState {
+    /// The position of the item being dragged
+    from: Option<usize>,
+    /// The position the item would be dropped at
+    over: usize,
+    /// If the item was grabbed using the keyboard
+    keyboard: bool,
+    announcement: String,
+}
+
+impl DragState {
+    fn start(&mut self, position: usize, keyboard: bool) {
+        self.from = Some(position);
+        self.over = position;
+        self.keyboard = keyboard;
+        self.announcement = format!("Dragging started for item {}.", position + 1);
+    }
+
+    fn over(&mut self, position: usize) -> bool {
+        if self.from.is_none() || self.over == position {
+            return false;
+        }
+        self.over = position;
+        self.announcement = format!("Dragging over position {}.", position + 1);
+        true
+    }
+
+    fn finish(&mut self) -> Option<Reorder> {
+        let from = self.from.take()?;
+        let to = self.over;
+        self.keyboard = false;
+        self.announcement = format!(
+            "Dragging finished, item moved from position {} to {}.",
+            from + 1,
+            to + 1
+        );
+        (from != to).then_some(Reorder { from, to })
+    }
+
+    fn cancel(&mut self) -> bool {
+        if self.from.take().is_none() {
+            return false;
+        }
+        self.keyboard = false;
+        self.announcement = "Dragging cancelled.".to_string();
+        true
+    }
+}
+
+/// Reorder a list of items using drag and drop.
+///
+/// Items can be dragged using the mouse, using touch on their [`ReorderHandle`], or using the
+/// keyboard: pressing Enter or Space on the handle grabs the item, the arrow keys move it, and
+/// pressing Enter or Space again drops it. Escape cancels dragging. Changes are announced to
+/// screen readers using a live region, see [`UseReorder::live_region`].
+///
+/// While dragging, items should be rendered in the order of [`UseReorder::order`], giving a
+/// preview of the result. Once dropped, the callback is called with the [`Reorder`] to apply.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use patternfly_yew::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let items = use_state_eq(|| vec!["Foo", "Bar", "Baz"]);
+///   let onreorder = use_callback(items.clone(), |reorder: Reorder, items| {
+///     let mut new_items = (**items).clone();
+///     reorder.apply(&mut new_items);
+///     items.set(new_items);
+///   });
+///   let reorder = use_reorder(items.len(), onreorder);
+///
+///   html!(
+///     <>
+///       <ul>
+///         { for reorder.order().into_iter().enumerate().map(|(position, index)| {
+///           let item = reorder.item(position);
+///           html!(
+///             <li
+///               key={items[index]}
+///               data-reorder-position={position.to_string()}
+///               draggable="true"
+///               ondragstart={item.ondragstart.clone()}
+///               ondragover={item.ondragover.clone()}
+///               ondrop={item.ondrop.clone()}
+///               ondragend={item.ondragend.clone()}
+///             >
+///               <ReorderHandle {item} />
+///               { items[index] }
+///             </li>
+///           )
+///         }) }
+///       </ul>
+///       { reorder.live_region() }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_reorder(len: usize, onreorder: Callback<Reorder>) -> UseReorder {
+    let state = use_mut_ref(DragState::default);
+    let trigger = use_force_update();
+
+    UseReorder {
+        len,
+        state,
+        onreorder,
+        update: Callback::from(move |()| trigger.force_update()),
+    }
+}
+
+/// The state of [`use_reorder`].
+#[derive(Clone)]
+pub struct UseReorder {
+    len: usize,
+    state: Rc<RefCell<DragState>>,
+    onreorder: Callback<Reorder>,
+    update: Callback<()>,
+}
+
+impl UseReorder {
+    /// Check if an item is currently being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.state.borrow().from.is_some()
+    }
+
+    /// The indexes of the items, in the order they should be displayed.
+    ///
+    /// While dragging, the dragged item is shown at the position it would be dropped at.
+    pub fn order(&self) -> Vec<usize> {
+        let mut order = (0..self.len).collect::<Vec<_>>();
+        let state = self.state.borrow();
+        if let Some(from) = state.from {
+            Reorder {
+                from,
+                to: state.over,
+            }
+            .apply(&mut order);
+        }
+        order
+    }
+
+    /// A visually hidden region, announcing the changes to screen readers.
+    pub fn live_region(&self) -> Html {
+        html!(
+            <div class="pf-v5-screen-reader" aria-live="assertive">
+                { self.state.borrow().announcement.clone() }
+            </div>
+        )
+    }
+
+    /// The handlers of the item displayed at a position.
+    pub fn item(&self, position: usize) -> ReorderItem {
+        let state = self.state.borrow();
+        let dragging = state.from.is_some() && state.over == position;
+        let grabbed = dragging && state.keyboard;
+        let last = self.len.saturating_sub(1);
+
+        ReorderItem {
+            position,
+            dragging,
+            grabbed,
+            ondragstart: self.handler(move |state, e: DragEvent| {
+                if let Some(transfer) = e.data_transfer() {
+                    transfer.set_effect_allowed("move");
+                    // required by some browsers to start dragging
+                    let _ = transfer.set_data("text/plain", "");
+                }
+                state.start(position, false);
+                Action::Update
+            }),
+            ondragover: self.handler(move |state, e: DragEvent| {
+                if state.from.is_none() || state.keyboard {
+                    return Action::None;
+                }
+                e.prevent_default();
+                state.over(position).into()
+            }),
+            ondrop: self.handler(|state, e: DragEvent| {
+                e.prevent_default();
+                Action::Finish(state.finish())
+            }),
+            ondragend: self.handler(|state, _: DragEvent| state.cancel().into()),
+            ontouchstart: self.handler(move |state, _: TouchEvent| {
+                state.start(position, false);
+                Action::Update
+            }),
+            ontouchmove: self.handler(|state, e: TouchEvent| match touch_position(&e) {
+                Some(position) => state.over(position).into(),
+                None => Action::None,
+            }),
+            ontouchend: self.handler(|state, _: TouchEvent| Action::Finish(state.finish())),
+            onkeydown: self.handler(move |state, e: KeyboardEvent| {
+                let grabbed = state.from.is_some() && state.keyboard;
+                match e.key().as_str() {
+                    "Enter" | " " if grabbed => {
+                        e.prevent_default();
+                        Action::Finish(state.finish())
+                    }
+                    "Enter" | " " => {
+                        e.prevent_default();
+                        state.start(position, true);
+                        Action::Update
+                    }
+                    "ArrowUp" if grabbed => {
+                        e.prevent_default();
+                        state.over(state.over.saturating_sub(1)).into()
+                    }
+                    "ArrowDown" if grabbed => {
+                        e.prevent_default();
+                        state.over((state.over + 1).min(last)).into()
+                    }
+                    "Escape" if grabbed => {
+                        e.prevent_default();
+                        state.cancel().into()
+                    }
+                    _ => Action::None,
+                }
+            }),
+        }
+    }
+
+    fn handler<E, F>(&self, f: F) -> Callback<E>
+    where
+        E: 'static,
+        F: Fn(&mut DragState, E) -> Action + 'static,
+    {
+        let state = self.state.clone();
+        let onreorder = self.onreorder.clone();
+        let update = self.update.clone();
+        Callback::from(move |e| {
+            let action = f(&mut state.borrow_mut(), e);
+            match action {
+                Action::None => {}
+                Action::Update => update.emit(()),
+                Action::Finish(reorder) => {
+                    update.emit(());
+                    if let Some(reorder) = reorder {
+                        onreorder.emit(reorder);
+                    }
+                }
+            }
+        })
+    }
+}
+
+enum Action {
+    None,
+    Update,
+    Finish(Option<Reorder>),
+}
+
+impl From<bool> for Action {
+    fn from(value: bool) -> Self {
+        match value {
+            true => Self::Update,
+            false => Self::None,
+        }
+    }
+}
+
+/// The position of the item under the touch point, if it belongs to the same list as the
+/// item being dragged.
+fn touch_position(e: &TouchEvent) -> Option<usize> {
+    let touch = e.touches().get(0)?;
+    let target = gloo_utils::document()
+        .element_from_point(touch.client_x() as f32, touch.client_y() as f32)?;
+    let item = target.closest(&format!("[{POSITION_ATTR}]")).ok()??;
+
+    let source = e
+        .target()?
+        .dyn_into::<Element>()
+        .ok()?
+        .closest(&format!("[{POSITION_ATTR}]"))
+        .ok()??;
+    if item.parent_element() != source.parent_element() {
+        return None;
+    }
+
+    item.get_attribute(POSITION_ATTR)?.parse().ok()
+}
+
+/// The handlers of a reorderable item, see [`use_reorder`].
+///
+/// The element of the item must have the `data-reorder-position` attribute set to the
+/// `position`, and use the drag handlers. The touch and keyboard handlers are used by the
+/// [`ReorderHandle`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReorderItem {
+    /// The position the item is displayed at
+    pub position: usize,
+    /// The item is currently being dragged
+    pub dragging: bool,
+    /// The item was grabbed using the keyboard
+    pub grabbed: bool,
+    pub ondragstart: Callback<DragEvent>,
+    pub ondragover: Callback<DragEvent>,
+    pub ondrop: Callback<DragEvent>,
+    pub ondragend: Callback<DragEvent>,
+    pub ontouchstart: Callback<TouchEvent>,
+    pub ontouchmove: Callback<TouchEvent>,
+    pub ontouchend: Callback<TouchEvent>,
+    pub onkeydown: Callback<KeyboardEvent>,
+}
+
+/// Properties for [`ReorderHandle`]
+#[derive(Clone, Debug, PartialEq, Properties)]
+pub struct ReorderHandleProperties {
+    pub item: ReorderItem,
+
+    #[prop_or(AttrValue::Static("Drag to reorder"))]
+    pub aria_label: AttrValue,
+}
+
+/// The handle of a reorderable item, for dragging it using touch or the keyboard.
+#[function_component(ReorderHandle)]
+pub fn reorder_handle(props: &ReorderHandleProperties) -> Html {
+    let item = &props.item;
+
+    html!(
+        <button
+            class="pf-v5-c-button pf-m-plain"
+            type="button"
+            style="touch-action: none;"
+            aria-label={&props.aria_label}
+            aria-pressed={item.grabbed.to_string()}
+            ontouchstart={item.ontouchstart.clone()}
+            ontouchmove={item.ontouchmove.clone()}
+            ontouchend={item.ontouchend.clone()}
+            onkeydown={item.onkeydown.clone()}
+            // don't trigger the item itself, e.g. selecting it
+            onclick={|e: MouseEvent| e.stop_propagation()}
+        >
+            { Icon::GripVertical }
+        </button>
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut items = vec!['a', 'b', 'c', 'd'];
+        Reorder { from: 0, to: 2 }.apply(&mut items);
+        assert_eq!(items, vec!['b', 'c', 'a', 'd']);
+
+        Reorder { from: 3, to: 0 }.apply(&mut items);
+        assert_eq!(items, vec!['d', 'b', 'c', 'a']);
+
+        Reorder { from: 1, to: 4 }.apply(&mut items);
+        assert_eq!(items, vec!['d', 'b', 'c', 'a']);
+    }
+
+    #[test]
+    fn test_position() {
+        let reorder = Reorder { from: 1, to: 3 };
+        let mut items = vec![0, 1, 2, 3, 4];
+        reorder.apply(&mut items);
+        for (position, index) in items.into_iter().enumerate() {
+            assert_eq!(reorder.position(index), position);
+        }
+
+        let reorder = Reorder { from: 3, to: 0 };
+        let mut items = vec![0, 1, 2, 3, 4];
+        reorder.apply(&mut items);
+        for (position, index) in items.into_iter().enumerate() {
+            assert_eq!(reorder.position(index), position);
+        }
+    }
+
+    #[test]
+    fn test_state() {
+        let mut state = DragState::default();
+        assert!(!state.over(2));
+        assert_eq!(state.finish(), None);
+
+        state.start(1, true);
+        assert!(state.over(3));
+        assert!(!state.over(3));
+        assert_eq!(state.finish(), Some(Reorder { from: 1, to: 3 }));
+        assert_eq!(state.from, None);
+
+        state.start(2, false);
+        assert!(state.cancel());
+        assert!(!state.cancel());
+        assert_eq!(state.finish(), None);
+    }
+}
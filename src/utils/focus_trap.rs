@@ -0,0 +1,294 @@
+use gloo_events::{EventListener, EventListenerOptions};
+use gloo_utils::document;
+use std::cell::{Cell, RefCell};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent};
+use web_tools::iter::IterableNodeList;
+use yew::prelude::*;
+
+const TABBABLE: &str = "a[href], area[href], button:not([disabled]), \
+    input:not([disabled]):not([type=hidden]), select:not([disabled]), \
+    textarea:not([disabled]), iframe, [tabindex], [contenteditable=true]";
+
+/// The number of recently focused elements to remember.
+const LAST_FOCUS_LEN: usize = 8;
+
+thread_local! {
+    /// The stack of active focus traps, only the last one is handling focus.
+    static TRAPS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    static COUNTER: Cell<usize> = const { Cell::new(0) };
+    /// The elements which last received focus, the most recent one last.
+    static LAST_FOCUS: RefCell<Vec<HtmlElement>> = const { RefCell::new(Vec::new()) };
+    static TRACKING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The element to focus when a [`FocusTrap`] gets activated.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum InitialFocus {
+    /// Focus the first tabbable element, or the container if there is none.
+    #[default]
+    First,
+    /// Focus the container element itself.
+    Container,
+    /// Focus the first element matching the CSS selector, inside the container.
+    Selector(AttrValue),
+    /// Focus the referenced element.
+    Ref(NodeRef),
+    /// Don't change the focus.
+    None,
+}
+
+/// Keep the keyboard focus inside an element.
+///
+/// When created, the trap moves the focus into the element referenced by the [`NodeRef`],
+/// according to the [`InitialFocus`], unless an element inside already has the focus. While it
+/// exists, using the Tab key will cycle through the tabbable elements inside the element. When
+/// the instance is dropped, the focus gets restored to the element which was focused before.
+///
+/// Focus traps can be nested (e.g. for stacked modals), in which case only the one created last
+/// is active.
+///
+/// For use in function components, see [`use_focus_trap`].
+pub struct FocusTrap {
+    id: usize,
+    node_ref: NodeRef,
+    previous: Option<HtmlElement>,
+    _listener: EventListener,
+}
+
+impl FocusTrap {
+    pub fn new(node_ref: NodeRef, initial_focus: InitialFocus) -> Self {
+        track_focus();
+
+        let id = COUNTER.with(|counter| {
+            let id = counter.get();
+            counter.set(id + 1);
+            id
+        });
+        TRAPS.with(|traps| traps.borrow_mut().push(id));
+
+        let container = node_ref.cast::<Element>();
+        let inside = |element: &HtmlElement| {
+            container
+                .as_ref()
+                .map(|container| container.contains(Some(element)))
+                .unwrap_or_default()
+        };
+
+        // buttons drop the focus when being clicked, so fall back to the last focused element.
+        // The content might already have focused one of its elements (e.g. using `autofocus`),
+        // which isn't what we want to restore to either.
+        let active = document()
+            .active_element()
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+            .filter(|element| Some(element) != document().body().as_ref());
+        let previous = active
+            .clone()
+            .filter(|element| !inside(element))
+            .or_else(|| {
+                LAST_FOCUS.with(|last| {
+                    last.borrow()
+                        .iter()
+                        .rev()
+                        .find(|element| element.is_connected() && !inside(element))
+                        .cloned()
+                })
+            });
+
+        // keep the focus of an element of the content
+        if !active.as_ref().map(inside).unwrap_or_default() {
+            focus_initial(&node_ref, &initial_focus);
+        }
+
+        let listener = {
+            let node_ref = node_ref.clone();
+            EventListener::new_with_options(
+                &document(),
+                "keydown",
+                EventListenerOptions::enable_prevent_default(),
+                move |e| {
+                    let active = TRAPS.with(|traps| traps.borrow().last() == Some(&id));
+                    if !active {
+                        return;
+                    }
+                    if let Some(e) = e.dyn_ref::<KeyboardEvent>() {
+                        if e.key() == "Tab" {
+                            handle_tab(&node_ref, e);
+                        }
+                    }
+                },
+            )
+        };
+
+        Self {
+            id,
+            node_ref,
+            previous,
+            _listener: listener,
+        }
+    }
+}
+
+impl Drop for FocusTrap {
+    fn drop(&mut self) {
+        TRAPS.with(|traps| traps.borrow_mut().retain(|id| *id != self.id));
+
+        // only restore the focus if it isn't somewhere else already, e.g. when closing a
+        // popover by clicking on another element
+        let active = document().active_element();
+        let lost = match &active {
+            None => true,
+            Some(active) => {
+                document().body().as_deref() == Some(active)
+                    || self
+                        .node_ref
+                        .cast::<Element>()
+                        .map(|container| container.contains(Some(active)))
+                        .unwrap_or_default()
+            }
+        };
+
+        if let Some(previous) = self.previous.take() {
+            if lost && previous.is_connected() {
+                let _ = previous.focus();
+            }
+        }
+    }
+}
+
+/// Trap the focus inside an element, while `active` is `true`.
+///
+/// See [`FocusTrap`] for more information.
+#[hook]
+pub fn use_focus_trap(node_ref: NodeRef, active: bool, initial_focus: InitialFocus) {
+    // start tracking early, so that we know what to restore to
+    use_effect_with((), |()| track_focus());
+
+    use_effect_with(
+        (node_ref, active, initial_focus),
+        |(node_ref, active, initial_focus)| {
+            let trap = active.then(|| FocusTrap::new(node_ref.clone(), initial_focus.clone()));
+            move || drop(trap)
+        },
+    );
+}
+
+/// Start tracking the last focused element.
+///
+/// This is required as some elements (like the [`crate::prelude::Button`]) drop the focus when
+/// being clicked, which leaves us with nothing to restore the focus to.
+pub(crate) fn track_focus() {
+    if TRACKING.with(|tracking| tracking.replace(true)) {
+        return;
+    }
+
+    EventListener::new(&document(), "focusin", |e| {
+        if let Some(element) = e
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+        {
+            LAST_FOCUS.with(|last| {
+                let mut last = last.borrow_mut();
+                last.retain(|e| *e != element);
+                if last.len() >= LAST_FOCUS_LEN {
+                    last.remove(0);
+                }
+                last.push(element);
+            });
+        }
+    })
+    .forget();
+}
+
+/// Get all tabbable elements inside the element.
+fn tabbable(container: &Element) -> Vec<HtmlElement> {
+    let elements = match container.query_selector_all(TABBABLE) {
+        Ok(elements) => elements,
+        Err(_) => return vec![],
+    };
+
+    IterableNodeList(&elements)
+        .into_iter()
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .filter(|element| element.tab_index() >= 0)
+        // skip elements which are not rendered
+        .filter(|element| element.offset_width() > 0 || element.offset_height() > 0)
+        .collect()
+}
+
+fn focus_container(container: &HtmlElement) {
+    if !container.has_attribute("tabindex") {
+        container.set_tab_index(-1);
+    }
+    let _ = container.focus();
+}
+
+fn focus_initial(node_ref: &NodeRef, initial_focus: &InitialFocus) {
+    let container = match node_ref.cast::<HtmlElement>() {
+        Some(container) => container,
+        None => return,
+    };
+
+    let target = match initial_focus {
+        InitialFocus::None => return,
+        InitialFocus::Container => None,
+        InitialFocus::First => tabbable(&container).into_iter().next(),
+        InitialFocus::Selector(selector) => container
+            .query_selector(selector)
+            .ok()
+            .flatten()
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok()),
+        InitialFocus::Ref(r#ref) => r#ref.cast::<HtmlElement>(),
+    };
+
+    match target {
+        Some(target) => {
+            let _ = target.focus();
+        }
+        None => focus_container(&container),
+    }
+}
+
+fn handle_tab(node_ref: &NodeRef, e: &KeyboardEvent) {
+    let container = match node_ref.cast::<HtmlElement>() {
+        Some(container) => container,
+        None => return,
+    };
+
+    let elements = tabbable(&container);
+    let active = document()
+        .active_element()
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+
+    let (first, last) = match (elements.first(), elements.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => {
+            // nothing to tab to, keep the focus on the container
+            e.prevent_default();
+            focus_container(&container);
+            return;
+        }
+    };
+
+    let inside = active
+        .as_ref()
+        .map(|active| container.contains(Some(active)))
+        .unwrap_or_default();
+
+    let next = if !inside {
+        Some(first)
+    } else if e.shift_key()
+        && (active.as_ref() == Some(first) || active.as_ref() == Some(&container))
+    {
+        Some(last)
+    } else if !e.shift_key() && active.as_ref() == Some(last) {
+        Some(first)
+    } else {
+        None
+    };
+
+    if let Some(next) = next {
+        e.prevent_default();
+        let _ = next.focus();
+    }
+}
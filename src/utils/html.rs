@@ -1,5 +1,5 @@
 use web_sys::HtmlInputElement;
-use yew::NodeRef;
+use yew::{virtual_dom::VNode, Html, NodeRef};
 
 // TODO: remove this in the next version
 pub use web_tools::{
@@ -24,3 +24,45 @@ pub fn value(node_ref: &NodeRef) -> Option<String> {
         .cast::<HtmlInputElement>()
         .map(|input| input.value())
 }
+
+/// Get the text content of some HTML, without rendering it.
+///
+/// Only text of plain elements is collected, the content of components can't be inspected.
+pub fn text_content(html: &Html) -> String {
+    fn collect(node: &VNode, text: &mut String) {
+        match node {
+            VNode::VText(vtext) => text.push_str(&vtext.text),
+            VNode::VTag(vtag) => {
+                if let Some(children) = vtag.children() {
+                    collect(children, text);
+                }
+            }
+            VNode::VList(vlist) => {
+                for child in vlist.iter() {
+                    collect(child, text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut text = String::new();
+    collect(html, &mut text);
+    text
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use yew::html;
+
+    #[test]
+    fn test_text_content() {
+        assert_eq!(text_content(&html!("Foo")), "Foo");
+        assert_eq!(
+            text_content(&html!(<><b>{ "Foo" }</b>{ " " }<span><i>{ "Bar" }</i></span></>)),
+            "Foo Bar"
+        );
+        assert_eq!(text_content(&html!()), "");
+    }
+}
@@ -3,17 +3,23 @@
 mod action;
 mod attr_value;
 mod context;
+mod drag;
+mod focus_trap;
 mod global_close;
 mod html;
 mod ouia;
 mod props;
 mod raw;
 mod styled;
+#[cfg(feature = "yew-nested-router")]
+pub(crate) mod query;
 pub(crate) mod wrap;
 
 pub use action::*;
 pub use attr_value::*;
 pub use context::*;
+pub use drag::*;
+pub use focus_trap::*;
 pub use global_close::*;
 pub use html::*;
 pub use ouia::*;
@@ -0,0 +1,134 @@
+//! Reading and writing the query of the URL.
+
+use wasm_bindgen::JsValue;
+use yew_nested_router::History;
+
+/// The query of the current location, including the leading `?`.
+pub(crate) fn current() -> String {
+    gloo_utils::window().location().search().unwrap_or_default()
+}
+
+/// Replace the query of the current location, without adding an entry to the history.
+pub(crate) fn replace(query: &str) {
+    let location = gloo_utils::window().location();
+    let url = format!(
+        "{}{}{}",
+        location.pathname().unwrap_or_default(),
+        query,
+        location.hash().unwrap_or_default()
+    );
+    let state = gloo_utils::history().state().unwrap_or(JsValue::NULL);
+    if let Err(err) = History::replace_state(state, &url) {
+        log::warn!("Failed to update the query: {err:?}");
+    }
+}
+
+/// The decoded parameters of a query.
+pub(crate) fn params(search: &str) -> impl Iterator<Item = (String, String)> + '_ {
+    raw_params(search).map(|(key, value)| (decode(key), decode(value)))
+}
+
+/// Replace the parameters of a query matching the predicate, keeping all others.
+///
+/// The new parameters get encoded and appended.
+pub(crate) fn update<'a>(
+    search: &str,
+    replaced: impl Fn(&str) -> bool,
+    params: impl IntoIterator<Item = (&'a str, String)>,
+) -> String {
+    let query = raw_params(search)
+        .filter(|(key, _)| !replaced(&decode(key)))
+        .map(|(key, value)| match value.is_empty() {
+            true => key.to_string(),
+            false => format!("{key}={value}"),
+        })
+        .chain(
+            params
+                .into_iter()
+                .map(|(key, value)| format!("{}={}", encode(key), encode(&value))),
+        )
+        .collect::<Vec<_>>();
+
+    match query.is_empty() {
+        true => String::new(),
+        false => format!("?{}", query.join("&")),
+    }
+}
+
+fn raw_params(search: &str) -> impl Iterator<Item = (&str, &str)> {
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| param.split_once('=').unwrap_or((param, "")))
+}
+
+/// Percent-encode a query component.
+fn encode(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                result.push(byte as char)
+            }
+            _ => result.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    result
+}
+
+/// Decode a percent-encoded query component, including `+` for spaces.
+fn decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'+' => result.push(b' '),
+            b'%' => {
+                if let Some(byte) = value
+                    .get(index + 1..index + 3)
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    result.push(byte);
+                    index += 3;
+                    continue;
+                }
+                result.push(b'%');
+            }
+            byte => result.push(byte),
+        }
+        index += 1;
+    }
+
+    String::from_utf8_lossy(&result).into_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_encoding() {
+        assert_eq!(encode("a b&c=ä"), "a%20b%26c%3D%C3%A4");
+        assert_eq!(decode("a%20b%26c%3D%C3%A4"), "a b&c=ä");
+        assert_eq!(decode("a+b%2"), "a b%2");
+    }
+
+    #[test]
+    fn test_update() {
+        assert_eq!(update("", |_| false, []), "");
+        assert_eq!(
+            update("?a=1&b&c=2", |key| key == "c", [("c", "x y".to_string())]),
+            "?a=1&b&c=x%20y"
+        );
+        assert_eq!(
+            params("?a%20b=c+d&e").collect::<Vec<_>>(),
+            vec![
+                ("a b".to_string(), "c d".to_string()),
+                ("e".to_string(), String::new())
+            ]
+        );
+    }
+}
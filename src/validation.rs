@@ -54,6 +54,8 @@ impl<T, S> Validator<T, S> {
     }
 }
 
+// deriving would add a `Default` bound on `T` and `S`
+#[allow(clippy::derivable_impls)]
 impl<T, S> Default for Validator<T, S> {
     fn default() -> Self {
         Self::None